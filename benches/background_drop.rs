@@ -0,0 +1,71 @@
+//! Latency of the current thread when dropping a 1M-node list inline versus sending it to a
+//! `BackgroundDropQueue`.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        BackgroundDropQueue,
+        DeepSafeDrop,
+        drop_boxed,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct List
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+#[allow(clippy::unnecessary_box_returns)] // The link type is what is sent.
+fn make_list(len: usize) -> Box<List>
+{
+    let list = (1 .. len).fold(List { next: None }, |acc, _| List { next: Some(Box::new(acc)) });
+    Box::new(list)
+}
+
+
+fn background_drop(criterion: &mut Criterion)
+{
+    const SIZE: usize = 1_000_000;
+
+    let mut group = criterion.benchmark_group("list_1m_latency");
+    let _inline = group.sample_size(10).bench_function("inline", |bencher| {
+        bencher.iter_batched(
+            || make_list(SIZE),
+            |list| drop(black_box(list)),
+            BatchSize::PerIteration,
+        );
+    });
+    let queue = BackgroundDropQueue::<_, List>::new();
+    let _background = group.bench_function("background", |bencher| {
+        bencher.iter_batched(
+            || make_list(SIZE),
+            |list| queue.send(black_box(list)).map_err(drop),
+            BatchSize::PerIteration,
+        );
+    });
+    drop(queue.finish());
+    group.finish();
+}
+
+
+criterion_group!(benches, background_drop);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Dropping a complete binary tree of 1M nodes, whose height is 20, by `deep_safe_drop_bfs`,
+//! which queues each level, versus by `deep_safe_drop`, which traverses depth-first by
+//! link-reversal.  The peak size of the queue of the former is the width of the bottom level,
+//! i.e. 2^19 links, whereas the latter needs no memory, neither heap nor stack, beyond the tree
+//! itself.  The former has also been measured as slower, by about a half.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        deep_safe_drop_bfs,
+        drop_boxed,
+    },
+};
+
+
+const HEIGHT: usize = 20;
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+fn make_complete(height: usize) -> Node
+{
+    let sub = || (height > 1).then(|| Box::new(make_complete(height.saturating_sub(1))));
+    Node { left: sub(), right: sub() }
+}
+
+
+fn bfs_drop(criterion: &mut Criterion)
+{
+    let peak = deep_safe_drop_bfs::<_, Node>(Box::new(make_complete(HEIGHT)));
+    assert_eq!(peak, 1 << (HEIGHT - 1), "peak queue size is the width");
+
+    let mut group = criterion.benchmark_group("binary_tree_1m_bfs");
+    let _group = group.sample_size(10).throughput(Throughput::Elements((1 << HEIGHT) - 1));
+    let _bfs = group.bench_function("deep_safe_drop_bfs", |bencher| {
+        bencher.iter_batched(
+            || Box::new(make_complete(HEIGHT)),
+            |tree| deep_safe_drop_bfs::<_, Node>(black_box(tree)),
+            BatchSize::PerIteration,
+        );
+    });
+    let _dfs = group.bench_function("deep_safe_drop", |bencher| {
+        bencher.iter_batched(
+            || make_complete(HEIGHT),
+            |tree| drop(black_box(tree)),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, bfs_drop);
+criterion_main!(benches);
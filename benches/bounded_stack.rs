@@ -0,0 +1,74 @@
+//! Dropping a complete binary tree of 1M nodes, whose height is 20, by `bounded_stack_drop`,
+//! which records the ancestors in a fixed-size array, versus by `deep_safe_drop`, which records
+//! them by link-reversal.  Which is faster depends on the node type and the machine, and, for
+//! this node type, the former has been measured as somewhat slower, despite not reversing links.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        bounded_stack_drop,
+        drop_boxed,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+fn make_complete(height: usize) -> Node
+{
+    let sub = || (height > 1).then(|| Box::new(make_complete(height.saturating_sub(1))));
+    Node { left: sub(), right: sub() }
+}
+
+
+fn bounded_stack(criterion: &mut Criterion)
+{
+    const HEIGHT: usize = 20;
+
+    let mut group = criterion.benchmark_group("binary_tree_1m_shallow");
+    let _group = group.sample_size(10).throughput(Throughput::Elements((1 << HEIGHT) - 1));
+    let _bounded = group.bench_function("bounded_stack_drop", |bencher| {
+        bencher.iter_batched(
+            || Box::new(make_complete(HEIGHT)),
+            |tree| bounded_stack_drop::<_, Node, 32>(black_box(tree)),
+            BatchSize::PerIteration,
+        );
+    });
+    let _reversal = group.bench_function("deep_safe_drop", |bencher| {
+        bencher.iter_batched(
+            || make_complete(HEIGHT),
+            |tree| drop(black_box(tree)),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, bounded_stack);
+criterion_main!(benches);
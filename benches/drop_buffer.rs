@@ -0,0 +1,79 @@
+//! Throughput of dropping 1000 separate 1000-node trees by flushing a `DropBuffer` versus by
+//! dropping each individually, interleaved with making them.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        DropBuffer,
+        drop_boxed,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct BinaryTree
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for BinaryTree
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+/// About 1000 nodes, of varying depth.
+#[allow(clippy::unnecessary_box_returns)] // The link type is what is pushed.
+fn make_tree() -> Box<BinaryTree>
+{
+    let leaf = || Some(Box::new(BinaryTree { left: None, right: None }));
+    let tree = (1 .. 500).fold(BinaryTree { left: None, right: None }, |acc, _| BinaryTree {
+        left:  Some(Box::new(acc)),
+        right: leaf(),
+    });
+    Box::new(tree)
+}
+
+
+fn drop_buffer(criterion: &mut Criterion)
+{
+    const TREES: usize = 1000;
+
+    let mut group = criterion.benchmark_group("many_small_trees");
+    let _group = group.sample_size(10).throughput(Throughput::Elements(1000 * 1000));
+    let _individual = group.bench_function("individual", |bencher| {
+        bencher.iter(|| {
+            for _ in 0 .. TREES {
+                drop(black_box(make_tree()));
+            }
+        });
+    });
+    let mut buffer = DropBuffer::<_, BinaryTree>::with_capacity(TREES);
+    let _buffered = group.bench_function("buffered", |bencher| {
+        bencher.iter(|| {
+            for _ in 0 .. TREES {
+                buffer.push(black_box(make_tree()));
+            }
+            buffer.flush();
+        });
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, drop_buffer);
+criterion_main!(benches);
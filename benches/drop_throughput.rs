@@ -0,0 +1,172 @@
+//! Throughput of dropping trees of four canonical shapes, and of a random shape, each of about 1M
+//! nodes.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        Link,
+        RandomTreeBuilder,
+        count_nodes,
+        deep_safe_drop,
+        drop_boxed,
+        helpers::NaryNode,
+    },
+    rand::SeedableRng as _,
+    rand_xorshift::XorShiftRng,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct List
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+#[derive(DeepSafeDrop)]
+struct BinaryTree
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for BinaryTree
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+struct NaryBox(Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl From<NaryNode<Self>> for NaryBox
+{
+    fn from(node: NaryNode<Self>) -> Self
+    {
+        Self(Box::new(node))
+    }
+}
+
+impl core::borrow::Borrow<NaryNode<Self>> for NaryBox
+{
+    fn borrow(&self) -> &NaryNode<Self>
+    {
+        &self.0
+    }
+}
+
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+
+fn make_list(len: usize) -> List
+{
+    (1 .. len).fold(List { next: None }, |acc, _| List { next: Some(Box::new(acc)) })
+}
+
+/// A binary tree where every right child is `None`.
+fn make_right_skewed(len: usize) -> BinaryTree
+{
+    (1 .. len).fold(BinaryTree { left: None, right: None }, |acc, _| BinaryTree {
+        left:  Some(Box::new(acc)),
+        right: None,
+    })
+}
+
+fn make_balanced(depth: usize) -> BinaryTree
+{
+    let sub = || (depth > 1).then(|| Box::new(make_balanced(depth.saturating_sub(1))));
+    BinaryTree { left: sub(), right: sub() }
+}
+
+fn make_fan(
+    degree: usize,
+    levels: usize,
+) -> NaryNode<NaryBox>
+{
+    let children = (levels > 0).then(|| {
+        core::iter::repeat_with(|| NaryBox(Box::new(make_fan(degree, levels.saturating_sub(1)))))
+            .take(degree)
+            .collect()
+    });
+    NaryNode::new(children.unwrap_or_default())
+}
+
+/// The same tree every time, of varying arity and depth.
+fn make_random(size: usize) -> NaryBox
+{
+    let builder = RandomTreeBuilder::new(size, 4, size);
+    NaryBox::from(builder.build_random(&mut XorShiftRng::seed_from_u64(0)))
+}
+
+
+fn bench_shape<T>(
+    criterion: &mut Criterion,
+    name: &str,
+    size: usize,
+    make: impl Fn() -> T,
+)
+{
+    let mut group = criterion.benchmark_group(name);
+    // Constructing is done by the setup, which is not measured.
+    let _group = group
+        .sample_size(10)
+        .throughput(Throughput::Elements(u64::try_from(size).unwrap_or(u64::MAX)))
+        .bench_function("drop", |bencher| {
+            bencher.iter_batched(&make, |tree| drop(black_box(tree)), BatchSize::PerIteration);
+        });
+    group.finish();
+}
+
+fn drop_throughput(criterion: &mut Criterion)
+{
+    const SIZE: usize = 1_000_000;
+    const DEPTH: usize = 20;
+    const DEGREE: usize = 100;
+    const FAN_SIZE: usize = 1 + DEGREE + DEGREE * DEGREE + DEGREE * DEGREE * DEGREE;
+
+    bench_shape(criterion, "list_1m", SIZE, || make_list(SIZE));
+    bench_shape(criterion, "balanced_binary_20", (1 << DEPTH) - 1, || make_balanced(DEPTH));
+    bench_shape(criterion, "right_skewed", SIZE, || make_right_skewed(SIZE));
+    bench_shape(criterion, "fan_wide", FAN_SIZE, || make_fan(DEGREE, 3));
+    let random_size = count_nodes::<_, NaryNode<NaryBox>>(&make_random(SIZE));
+    bench_shape(criterion, "random", random_size, || make_random(SIZE));
+}
+
+
+criterion_group!(benches, drop_throughput);
+criterion_main!(benches);
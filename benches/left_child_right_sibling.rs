@@ -0,0 +1,127 @@
+//! Dropping a tree of about 1.1M nodes, of 10 children per internal node, in the
+//! left-child right-sibling representation, versus as `NaryNode`s, and the heap memory of each.
+//!
+//! The memory is computed from the sizes of the nodes and of the `Vec` buffers of the children,
+//! not including the overhead of the allocator, which also favors the former because it makes
+//! only one allocation per node.  On 64-bit targets, it is 24 bytes per node for the former,
+//! versus 32 bytes per node plus 8 bytes per child for the latter, i.e. about 40 bytes per node,
+//! which is asserted.  But the former has been measured as about twice as slow to drop, because
+//! each sibling is another level of the traversal, whereas the latter pops the children of a
+//! `Vec`.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::{
+        hint::black_box,
+        mem::size_of,
+    },
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        Link,
+        deep_safe_drop,
+        helpers::{
+            LeftChildRightSiblingNode,
+            NaryNode,
+        },
+    },
+};
+
+
+const DEGREE: usize = 10;
+const LEVELS: u32 = 6;
+
+
+struct NaryBox(Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+type Lcrs = LeftChildRightSiblingNode<LcrsBox>;
+
+struct LcrsBox(Box<Lcrs>);
+
+impl Link<Lcrs> for LcrsBox
+{
+    fn get_mut(&mut self) -> &mut Lcrs
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for LcrsBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Lcrs, Self, Lcrs>(&mut *self.0);
+    }
+}
+
+
+fn make_fan(levels: u32) -> NaryNode<NaryBox>
+{
+    let children = (levels > 0).then(|| {
+        core::iter::repeat_with(|| NaryBox(Box::new(make_fan(levels.saturating_sub(1)))))
+            .take(DEGREE)
+            .collect()
+    });
+    NaryNode::new(children.unwrap_or_default())
+}
+
+fn make_lcrs() -> LcrsBox
+{
+    let nary = NaryBox(Box::new(make_fan(LEVELS)));
+    LcrsBox(Box::new(Lcrs::from_nary(nary, |node| LcrsBox(Box::new(node)))))
+}
+
+
+fn left_child_right_sibling(criterion: &mut Criterion)
+{
+    let internal = (0 .. LEVELS).map(|level| DEGREE.pow(level)).sum::<usize>();
+    let nodes = internal.saturating_mul(DEGREE).saturating_add(1);
+
+    let lcrs_bytes = nodes.saturating_mul(size_of::<Lcrs>());
+    let nary_bytes = nodes
+        .saturating_mul(size_of::<NaryNode<NaryBox>>())
+        .saturating_add(internal.saturating_mul(DEGREE).saturating_mul(size_of::<NaryBox>()));
+    assert!(lcrs_bytes < nary_bytes, "less memory");
+
+    let mut group = criterion.benchmark_group("fan_10_1m_representation");
+    let _group = group
+        .sample_size(10)
+        .throughput(Throughput::Elements(u64::try_from(nodes).unwrap_or(u64::MAX)));
+    let _lcrs = group.bench_function("left_child_right_sibling", |bencher| {
+        bencher.iter_batched(make_lcrs, |tree| drop(black_box(tree)), BatchSize::PerIteration);
+    });
+    let _nary = group.bench_function("nary", |bencher| {
+        bencher.iter_batched(
+            || make_fan(LEVELS),
+            |tree| drop(black_box(tree)),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, left_child_right_sibling);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//! Throughput of dropping a 4-way fan tree of about 1M nodes in parallel versus sequentially.
+//!
+//! With only a single CPU available, the parallel dropping has no gain, only the overhead of its
+//! tasks.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        Link,
+        deep_safe_drop,
+        deep_safe_drop_parallel,
+        helpers::NaryNode,
+    },
+};
+
+
+struct NaryBox(Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+
+fn make_fan(
+    degree: usize,
+    levels: usize,
+) -> NaryNode<NaryBox>
+{
+    let children = (levels > 0).then(|| {
+        core::iter::repeat_with(|| NaryBox(Box::new(make_fan(degree, levels.saturating_sub(1)))))
+            .take(degree)
+            .collect()
+    });
+    NaryNode::new(children.unwrap_or_default())
+}
+
+
+fn parallel_drop(criterion: &mut Criterion)
+{
+    const DEGREE: usize = 4;
+    const LEVELS: usize = 10;
+    // 1 + 4 + 4^2 + ... + 4^10
+    const SIZE: u64 = 1_398_101;
+
+    let make = || make_fan(DEGREE, LEVELS);
+    let mut group = criterion.benchmark_group("fan_4_way");
+    let _sample = group.sample_size(10).throughput(Throughput::Elements(SIZE));
+    let _sequential = group.bench_function("sequential", |bencher| {
+        bencher.iter_batched(
+            make,
+            |mut root| deep_safe_drop::<_, NaryBox, NaryNode<NaryBox>>(&mut root),
+            BatchSize::PerIteration,
+        );
+    });
+    let _parallel = group.bench_function("parallel", |bencher| {
+        bencher.iter_batched(
+            make,
+            |mut root| deep_safe_drop_parallel::<_, NaryBox, NaryNode<NaryBox>>(&mut root),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, parallel_drop);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+//! Dropping a rope of 1M single-character pieces, built by repeated concatenation and so 1M
+//! levels deep, versus dropping a flat `String` of the same text.  The rope's dropping frees
+//! about 3M allocations, of each boxed node and of the `String` of each piece, whereas the
+//! `String` is a single allocation, and so this shows the cost of the rope's shape, which no
+//! traversal can avoid.  The former has been measured as about 400 ms, versus less than 1 µs for
+//! the latter.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        Link,
+        deep_safe_drop,
+        helpers::RopeNode,
+    },
+};
+
+
+struct RopeBox(Box<RopeNode<Self>>);
+
+impl Link<RopeNode<Self>> for RopeBox
+{
+    fn get_mut(&mut self) -> &mut RopeNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl core::borrow::Borrow<RopeNode<Self>> for RopeBox
+{
+    fn borrow(&self) -> &RopeNode<Self>
+    {
+        &self.0
+    }
+}
+
+impl Drop for RopeBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<RopeNode<Self>, Self, RopeNode<Self>>(&mut *self.0);
+    }
+}
+
+fn leaf() -> RopeBox
+{
+    RopeBox(Box::new(RopeNode::Leaf("a".into())))
+}
+
+
+fn rope_drop(criterion: &mut Criterion)
+{
+    const LEN: usize = 1_000_000;
+
+    let mut group = criterion.benchmark_group("rope_1m");
+    let _group = group
+        .sample_size(10)
+        .throughput(Throughput::Elements(u64::try_from(LEN).unwrap_or(u64::MAX)));
+    let _rope = group.bench_function("rope", |bencher| {
+        bencher.iter_batched(
+            || {
+                (1 .. LEN)
+                    .fold(leaf(), |rope, _| RopeBox(Box::new(RopeNode::concat(leaf(), rope))))
+            },
+            |rope| drop(black_box(rope)),
+            BatchSize::PerIteration,
+        );
+    });
+    let _string = group.bench_function("string", |bencher| {
+        bencher.iter_batched(
+            || "a".repeat(LEN),
+            |string| drop(black_box(string)),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, rope_drop);
+criterion_main!(benches);
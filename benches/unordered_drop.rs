@@ -0,0 +1,86 @@
+//! Dropping a 1M-node list by `deep_safe_drop_unordered`, which drops each node before the rest
+//! of the list in a single pass, versus by `deep_safe_drop`, which descends to the end and then
+//! ascends back, to drop in post-order.  The former has been measured as about 2.5 times faster.
+
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use {
+    core::hint::black_box,
+    criterion::{
+        BatchSize,
+        Criterion,
+        Throughput,
+        criterion_group,
+        criterion_main,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        deep_safe_drop_unordered,
+        drop_boxed,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct List
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+#[derive(DeepSafeDrop)]
+struct UnorderedList
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+}
+
+impl Drop for UnorderedList
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop_unordered::<_, Box<Self>, Self>(self);
+    }
+}
+
+
+fn unordered_drop(criterion: &mut Criterion)
+{
+    const SIZE: usize = 1_000_000;
+
+    let mut group = criterion.benchmark_group("list_1m_unordered");
+    let _group = group
+        .sample_size(10)
+        .throughput(Throughput::Elements(u64::try_from(SIZE).unwrap_or(u64::MAX)));
+    let _unordered = group.bench_function("deep_safe_drop_unordered", |bencher| {
+        bencher.iter_batched(
+            || {
+                (1 .. SIZE).fold(UnorderedList { next: None }, |acc, _| UnorderedList {
+                    next: Some(Box::new(acc)),
+                })
+            },
+            |list| drop(black_box(list)),
+            BatchSize::PerIteration,
+        );
+    });
+    let _ordered = group.bench_function("deep_safe_drop", |bencher| {
+        bencher.iter_batched(
+            || (1 .. SIZE).fold(List { next: None }, |acc, _| List { next: Some(Box::new(acc)) }),
+            |list| drop(black_box(list)),
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, unordered_drop);
+criterion_main!(benches);
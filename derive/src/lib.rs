@@ -0,0 +1,499 @@
+//! Derive macro for the `DeepSafeDrop` trait of the [`deep_safe_drop`] crate.
+//!
+//! Fields of `Option<Link>` type are tagged with `#[child(index = N)]` to make them the links
+//! to the children of the node.  The field with index 0 is the one that is reused to link to the
+//! parent.  The fields with indices greater than 0 are supplied, in ascending order of index, by
+//! `take_next_child_at_pos_index`, and they are exchanged with index 0 by their index, by
+//! `swap_child_at_index_0_with`.  Fields without a `#[child]` attribute are ignored.
+//!
+//! ```
+//! use deep_safe_drop::{drop_boxed, DeepSafeDrop};
+//!
+//! #[derive(DeepSafeDrop)]
+//! struct BinaryTree
+//! {
+//!     #[child(index = 0)]
+//!     left:  Option<Box<Self>>,
+//!     #[child(index = 1)]
+//!     right: Option<Box<Self>>,
+//!     data:  u64,
+//! }
+//!
+//! impl Drop for BinaryTree
+//! {
+//!     fn drop(&mut self)
+//!     {
+//!         drop_boxed(self);
+//!     }
+//! }
+//! # let _tree = BinaryTree { left: None, right: None, data: 0 };
+//! ```
+//!
+//! Tuple structs are also supported:
+//!
+//! ```
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! struct List<L>(#[child(index = 0)] Option<L>, u8);
+//! # let _list = List::<Box<()>>(None, 0);
+//! ```
+//!
+//! Enums are also supported, with the `#[child]` fields of each variant handled like those of a
+//! struct.  Variants without any `#[child]` fields are leaves.  All variants must have the same
+//! link type:
+//!
+//! ```
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! enum Expr
+//! {
+//!     Num(f64),
+//!     Add(#[child(index = 0)] Option<Box<Self>>, #[child(index = 1)] Option<Box<Self>>),
+//!     Neg(#[child(index = 0)] Option<Box<Self>>),
+//! }
+//! # let _expr = Expr::Neg(Some(Box::new(Expr::Num(1.0))));
+//! ```
+//!
+//! It is an error if there is no field with index 0:
+//!
+//! ```compile_fail
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! struct Node
+//! {
+//!     #[child(index = 1)]
+//!     next: Option<Box<Self>>,
+//! }
+//! ```
+//!
+//! Or if two fields have the same index:
+//!
+//! ```compile_fail
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! struct Node
+//! {
+//!     #[child(index = 0)]
+//!     a: Option<Box<Self>>,
+//!     #[child(index = 0)]
+//!     b: Option<Box<Self>>,
+//! }
+//! ```
+//!
+//! Or if two fields of a variant have the same index:
+//!
+//! ```compile_fail
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! enum Node
+//! {
+//!     Leaf,
+//!     Pair(#[child(index = 0)] Option<Box<Self>>, #[child(index = 0)] Option<Box<Self>>),
+//! }
+//! ```
+//!
+//! Or if a field's type is not `Option<Link>`:
+//!
+//! ```compile_fail
+//! use deep_safe_drop::DeepSafeDrop;
+//!
+//! #[derive(DeepSafeDrop)]
+//! struct Node
+//! {
+//!     #[child(index = 0)]
+//!     next: Box<Self>,
+//! }
+//! ```
+
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+
+use {
+    proc_macro::TokenStream,
+    proc_macro2::{
+        Literal,
+        Span,
+        TokenStream as TokenStream2,
+    },
+    quote::{
+        ToTokens as _,
+        quote,
+    },
+    syn::{
+        Data,
+        DataEnum,
+        DataStruct,
+        DeriveInput,
+        Error,
+        Fields,
+        GenericArgument,
+        Ident,
+        LitInt,
+        Member,
+        PathArguments,
+        Type,
+        parse_macro_input,
+        spanned::Spanned as _,
+    },
+};
+
+
+/// Derive an implementation of `DeepSafeDrop<Link>`, where `Link` is the type inside the
+/// `Option` of the fields tagged with `#[child(index = N)]`.
+///
+/// See the [crate-level documentation](crate) for details.
+#[proc_macro_derive(DeepSafeDrop, attributes(child))]
+#[inline]
+pub fn derive_deep_safe_drop(input: TokenStream) -> TokenStream
+{
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+
+/// A field tagged with `#[child(index = N)]`.
+struct Child
+{
+    index:  usize,
+    member: Member,
+    link:   Type,
+    span:   Span,
+}
+
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2>
+{
+    let methods = match &input.data {
+        Data::Struct(data) => expand_struct(input, data)?,
+        Data::Enum(data) => expand_enum(input, data)?,
+        Data::Union(_) =>
+            return Err(Error::new_spanned(
+                input,
+                "`DeepSafeDrop` can only be derived for structs and enums",
+            )),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Methods { link, bodies: Bodies { set_parent, take_child0, take_pos, swap } } = methods;
+
+    Ok(quote! {
+        impl #impl_generics ::deep_safe_drop::DeepSafeDrop<#link> for #name #ty_generics
+        #where_clause
+        {
+            fn set_parent_at_index_0(
+                &mut self,
+                parent: #link,
+            ) -> ::deep_safe_drop::SetParent<#link>
+            {
+                #set_parent
+            }
+
+            fn take_child_at_index_0(&mut self) -> ::core::option::Option<#link>
+            {
+                #take_child0
+            }
+
+            fn take_next_child_at_pos_index(&mut self) -> ::core::option::Option<#link>
+            {
+                #take_pos
+            }
+
+            fn swap_child_at_index_0_with(
+                &mut self,
+                i: usize,
+            ) -> bool
+            {
+                #swap
+            }
+        }
+    })
+}
+
+
+fn expand_struct(
+    input: &DeriveInput,
+    data: &DataStruct,
+) -> syn::Result<Methods>
+{
+    let children = sorted_children(&data.fields)?;
+    let Some((child0, pos_children)) = split_child0(&children)
+    else {
+        return Err(Error::new_spanned(input, "missing a field with `#[child(index = 0)]`"));
+    };
+    check_same_link_types(&child0.link, pos_children)?;
+
+    let place = |child: &Child| {
+        let member = &child.member;
+        (child.index, quote!(self.#member))
+    };
+    let bodies = Bodies::new(&place(child0).1, pos_children.iter().map(place));
+    Ok(Methods { link: child0.link.clone(), bodies })
+}
+
+
+/// Each variant is matched, with its `#[child]` fields bound by reference.  Variants without any
+/// are leaves.
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+) -> syn::Result<Methods>
+{
+    let mut link = None;
+    let mut arms = Bodies::default();
+
+    for variant in &data.variants {
+        let children = sorted_children(&variant.fields)?;
+        let ident = &variant.ident;
+
+        let Some((child0, pos_children)) = split_child0(&children)
+        else {
+            if let Some(child) = children.first() {
+                return Err(Error::new(
+                    child.span,
+                    "missing a field with `#[child(index = 0)]` in this variant",
+                ));
+            }
+            arms.push(&quote!(Self::#ident { .. }), Bodies::leaf());
+            continue;
+        };
+        let link0 = link.get_or_insert_with(|| child0.link.clone());
+        check_same_link_types(link0, &children)?;
+
+        let binding =
+            |child: &Child| Ident::new(&format!("__child{}", child.index), Span::mixed_site());
+        let members = children.iter().map(|child| &child.member);
+        let bindings = children.iter().map(binding);
+        let pattern = quote!(Self::#ident { #(#members: #bindings,)* .. });
+        let place = |child: &Child| {
+            let binding = binding(child);
+            (child.index, quote!((*#binding)))
+        };
+        arms.push(&pattern, Bodies::new(&place(child0).1, pos_children.iter().map(place)));
+    }
+
+    let Some(link) = link
+    else {
+        return Err(Error::new_spanned(input, "missing a field with `#[child(index = 0)]`"));
+    };
+    let Bodies { set_parent, take_child0, take_pos, swap } = arms;
+    let bodies = Bodies {
+        set_parent:  quote!(match self { #set_parent }),
+        take_child0: quote!(match self { #take_child0 }),
+        take_pos:    quote!(match self { #take_pos }),
+        swap:        quote!(match self { #swap }),
+    };
+    Ok(Methods { link, bodies })
+}
+
+
+/// The parts of the generated `impl`.
+struct Methods
+{
+    link:   Type,
+    bodies: Bodies,
+}
+
+
+/// The bodies of the generated methods, or the accumulated `match` arms of them.
+#[derive(Default)]
+struct Bodies
+{
+    set_parent:  TokenStream2,
+    take_child0: TokenStream2,
+    take_pos:    TokenStream2,
+    swap:        TokenStream2,
+}
+
+impl Bodies
+{
+    /// The bodies for the given places of the `Option<Link>` of the child at index 0 and of the
+    /// children at positive indices, with those indices, in ascending order of index.
+    fn new(
+        place0: &TokenStream2,
+        pos_places: impl Iterator<Item = (usize, TokenStream2)>,
+    ) -> Self
+    {
+        let (pos_indices, pos_places): (Vec<_>, Vec<_>) =
+            pos_places.map(|(index, place)| (Literal::usize_unsuffixed(index), place)).unzip();
+        Self {
+            set_parent:  quote! {
+                match ::core::option::Option::replace(&mut #place0, parent) {
+                    ::core::option::Option::Some(child0) =>
+                        ::deep_safe_drop::SetParent::YesReplacedChild { child0 },
+                    ::core::option::Option::None => ::deep_safe_drop::SetParent::Yes,
+                }
+            },
+            take_child0: quote!(::core::option::Option::take(&mut #place0)),
+            take_pos:    quote! {
+                #(
+                    if let ::core::option::Option::Some(child) =
+                        ::core::option::Option::take(&mut #pos_places)
+                    {
+                        return ::core::option::Option::Some(child);
+                    }
+                )*
+                ::core::option::Option::None
+            },
+            swap:        quote! {
+                match i {
+                    0 => true,
+                    #(
+                        #pos_indices => {
+                            ::core::mem::swap(&mut #place0, &mut #pos_places);
+                            true
+                        },
+                    )*
+                    _ => false,
+                }
+            },
+        }
+    }
+
+    /// The bodies for a node without any children.
+    fn leaf() -> Self
+    {
+        Self {
+            set_parent:  quote!(::deep_safe_drop::SetParent::No { returned_parent: parent }),
+            take_child0: quote!(::core::option::Option::None),
+            take_pos:    quote!(::core::option::Option::None),
+            swap:        quote!(false),
+        }
+    }
+
+    /// Accumulate `bodies` as `match` arms for `pattern`.
+    fn push(
+        &mut self,
+        pattern: &TokenStream2,
+        bodies: Self,
+    )
+    {
+        let Self { set_parent, take_child0, take_pos, swap } = bodies;
+        self.set_parent.extend(quote!(#pattern => { #set_parent },));
+        self.take_child0.extend(quote!(#pattern => { #take_child0 },));
+        self.take_pos.extend(quote!(#pattern => { #take_pos },));
+        self.swap.extend(quote!(#pattern => { #swap },));
+    }
+}
+
+
+/// The `#[child]` fields, sorted by index, which are checked to be unique.
+fn sorted_children(fields: &Fields) -> syn::Result<Vec<Child>>
+{
+    let mut children = children(fields)?;
+    children.sort_by_key(|child| child.index);
+    check_unique_indices(&children)?;
+    Ok(children)
+}
+
+
+/// Requires that `children` is already sorted by index.
+fn split_child0(children: &[Child]) -> Option<(&Child, &[Child])>
+{
+    children.split_first().filter(|(child0, _)| child0.index == 0)
+}
+
+
+/// Collect the fields tagged with `#[child(index = N)]`, in declaration order.
+fn children(fields: &Fields) -> syn::Result<Vec<Child>>
+{
+    let mut children = Vec::new();
+
+    for (position, field) in fields.iter().enumerate() {
+        let mut index = None;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("child")) {
+            if index.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate `#[child]` attribute"));
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("index") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    index = Some(lit.base10_parse::<usize>()?);
+                    Ok(())
+                }
+                else {
+                    Err(meta.error("expected `index = N`"))
+                }
+            })?;
+            if index.is_none() {
+                return Err(Error::new_spanned(attr, "expected `#[child(index = N)]`"));
+            }
+        }
+
+        if let Some(index) = index {
+            let member = field.ident.clone().map_or_else(|| Member::from(position), Member::from);
+            let link = option_inner_type(&field.ty).cloned().ok_or_else(|| {
+                Error::new_spanned(&field.ty, "a `#[child]` field must be of type `Option<Link>`")
+            })?;
+            children.push(Child { index, member, link, span: field.span() });
+        }
+    }
+
+    Ok(children)
+}
+
+
+/// Requires that `children` is already sorted by index.
+fn check_unique_indices(children: &[Child]) -> syn::Result<()>
+{
+    for pair in children.windows(2) {
+        if let [prev, next] = pair {
+            if prev.index == next.index {
+                return Err(Error::new(
+                    next.span,
+                    format!("duplicate `#[child(index = {})]`", next.index),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+
+fn check_same_link_types(
+    link0: &Type,
+    children: &[Child],
+) -> syn::Result<()>
+{
+    let link0 = link0.to_token_stream().to_string();
+
+    for child in children {
+        if child.link.to_token_stream().to_string() != link0 {
+            return Err(Error::new_spanned(
+                &child.link,
+                "all `#[child]` fields must have the same link type as the index 0 field",
+            ));
+        }
+    }
+    Ok(())
+}
+
+
+/// The `T` of an `Option<T>` type.
+fn option_inner_type(ty: &Type) -> Option<&Type>
+{
+    let Type::Path(path) = ty
+    else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let last = path.path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &last.arguments
+    else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>().as_slice() {
+        [GenericArgument::Type(inner)] => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,121 @@
+//! Safe dropping of a deep tree whose nodes are allocated in a `bumpalo::Bump` arena, where
+//! dropping a node does not free its memory, but does do some cleanup, which must all be done
+//! before the arena is reset.
+//!
+//! Run with `cargo run --example bumpalo_bump`, which exits with failure if the cleanup of some
+//! node was not done.
+//!
+//! The links are `bumpalo::boxed::Box`, which runs the `Drop` of its value when dropped, but
+//! which does not free the memory, because `Bump` cannot free individual allocations.  (This is
+//! on stable Rust, unlike `Box<T, &Bump>`, which needs the unstable `allocator_api`.)  So,
+//! `deep_safe_drop` is used for running the cleanup of all the nodes, in post-order, without
+//! stack overflow, and then all the memory of the tree is freed by a single `Bump::reset`, rather
+//! than node by node, which is much faster than freeing each node individually.
+
+// The dev-dependencies are also given to the examples, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+#![allow(clippy::print_stdout)] // It is a program that reports its results.
+
+use {
+    bumpalo::{
+        Bump,
+        boxed::Box as BumpBox,
+    },
+    core::cell::Cell,
+    deep_safe_drop::{
+        DeepSafeDrop,
+        Link,
+        SetParent,
+        deep_safe_drop,
+    },
+    std::process::ExitCode,
+};
+
+
+const NODES: usize = 100_000;
+
+
+/// A node of a list, which counts how many nodes are live.
+struct Node<'b>
+{
+    next: Option<BumpBox<'b, Self>>,
+    live: &'b Cell<usize>,
+}
+
+impl<'b> Node<'b>
+{
+    fn new(
+        next: Option<BumpBox<'b, Self>>,
+        live: &'b Cell<usize>,
+    ) -> Self
+    {
+        live.set(live.get().saturating_add(1));
+        Self { next, live }
+    }
+}
+
+impl<'b> Link<Node<'b>> for BumpBox<'b, Node<'b>>
+{
+    #[inline]
+    fn get_mut(&mut self) -> &mut Node<'b>
+    {
+        self
+    }
+}
+
+impl<'b> DeepSafeDrop<BumpBox<'b, Self>> for Node<'b>
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: BumpBox<'b, Self>,
+    ) -> SetParent<BumpBox<'b, Self>>
+    {
+        match self.next.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<BumpBox<'b, Self>>
+    {
+        self.next.take()
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<BumpBox<'b, Self>>
+    {
+        None
+    }
+}
+
+/// The cleanup, which does not free any memory.
+impl Drop for Node<'_>
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Self, BumpBox<'_, Self>, Self>(self);
+        self.live.set(self.live.get().saturating_sub(1));
+    }
+}
+
+
+fn main() -> ExitCode
+{
+    let live = Cell::new(0);
+    let mut bump = Bump::new();
+
+    {
+        let list = (1 .. NODES).fold(Node::new(None, &live), |next, _| {
+            Node::new(Some(BumpBox::new_in(next, &bump)), &live)
+        });
+        println!("{} nodes live in {} bytes of the arena", live.get(), bump.allocated_bytes());
+
+        // Runs the cleanup of all the nodes, via `deep_safe_drop`, by `Node::drop`.
+        drop(list);
+        println!("{} nodes live after dropping the tree", live.get());
+    }
+
+    // Frees all the memory of the tree at once.
+    bump.reset();
+
+    if live.get() == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
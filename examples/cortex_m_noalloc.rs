@@ -0,0 +1,145 @@
+//! Safe dropping, without any heap allocation, of a list stored in a fixed-size arena, on a
+//! Cortex-M system with only about 4KB of stack.
+//!
+//! From `examples/cortex_m_noalloc/`, whose `.cargo/config.toml` sets the target to
+//! `thumbv7m-none-eabi`, build with `cargo build --example cortex_m_noalloc`, and run in QEMU
+//! with `cargo run --example cortex_m_noalloc`, which exits with failure if the list was not
+//! entirely dropped.  On other targets, this is a normal program.
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+// The dev-dependencies are also given to the examples, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+
+use {
+    core::sync::atomic::{
+        AtomicUsize,
+        Ordering::Relaxed,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        Link,
+        SetParent,
+        deep_safe_drop_with,
+    },
+};
+
+
+const ARENA_LEN: usize = 1024;
+
+/// The arena of all nodes, which is static because it would not fit in the stack.
+static ARENA: [Node; ARENA_LEN] = [Node::EMPTY; ARENA_LEN];
+
+
+/// A node of the list, whose link to the next node is an index into the arena.
+struct Node
+{
+    next: AtomicUsize,
+}
+
+impl Node
+{
+    #[allow(clippy::declare_interior_mutable_const)] // Only for initializing the arena.
+    const EMPTY: Self = Self { next: AtomicUsize::new(Self::NONE) };
+    /// The index that is not a link.
+    const NONE: usize = usize::MAX;
+}
+
+
+/// A link to a node in the arena, by its index, which is also the node type of the traversal,
+/// like `RcLink` is.
+struct ArenaLink(usize);
+
+impl ArenaLink
+{
+    /// Replace the index of the next node, and return the previous one.
+    fn swap_next(
+        &self,
+        next: usize,
+    ) -> Option<Self>
+    {
+        let prev = ARENA.get(self.0).map_or(Node::NONE, |node| node.next.swap(next, Relaxed));
+        (prev != Node::NONE).then_some(Self(prev))
+    }
+}
+
+impl Link<Self> for ArenaLink
+{
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+impl DeepSafeDrop<Self> for ArenaLink
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        if ARENA.get(self.0).is_some_and(|node| node.next.load(Relaxed) != Node::NONE) {
+            match self.swap_next(parent.0) {
+                Some(child0) => SetParent::YesReplacedChild { child0 },
+                None => SetParent::Yes,
+            }
+        }
+        else {
+            SetParent::No { returned_parent: parent }
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        self.swap_next(Node::NONE)
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        None
+    }
+}
+
+
+/// Link all the nodes of the arena into a list, drop it, and return whether all of its nodes
+/// were dropped.
+fn run() -> bool
+{
+    for (index, node) in ARENA.iter().enumerate() {
+        let next = index.saturating_add(1);
+        node.next.store(if next < ARENA_LEN { next } else { Node::NONE }, Relaxed);
+    }
+
+    let mut head = ArenaLink(0);
+    // The head is dropped by this, but it is not given to the visitor.
+    let mut dropped: usize = 1;
+    deep_safe_drop_with::<_, ArenaLink, ArenaLink, _>(&mut head, |_| {
+        dropped = dropped.saturating_add(1);
+    });
+    dropped == ARENA_LEN
+}
+
+
+#[cfg(target_os = "none")]
+mod cortex_m_main
+{
+    use {
+        cortex_m_rt::entry,
+        cortex_m_semihosting::debug,
+        panic_halt as _,
+    };
+
+    #[entry]
+    fn main() -> !
+    {
+        debug::exit(if super::run() { debug::EXIT_SUCCESS } else { debug::EXIT_FAILURE });
+        #[allow(clippy::empty_loop)] // Only reached when not in QEMU.
+        loop {}
+    }
+}
+
+
+#[cfg(not(target_os = "none"))]
+fn main()
+{
+    assert!(run(), "all nodes must be dropped");
+}
@@ -0,0 +1,7 @@
+/* The LM3S6965, as emulated by QEMU, but with only enough RAM for the arena (8KB) and about 4KB
+   of stack. */
+MEMORY
+{
+  FLASH : ORIGIN = 0x00000000, LENGTH = 256K
+  RAM : ORIGIN = 0x20000000, LENGTH = 12K
+}
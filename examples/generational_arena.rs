@@ -0,0 +1,177 @@
+//! Safe dropping of a deep binary tree whose nodes are stored in a `generational_arena::Arena`,
+//! and whose links are indices into it, where dropping a link removes its node from the arena.
+//!
+//! Run with `cargo run --example generational_arena`, which exits with failure if the arena is
+//! not empty after the tree is dropped.
+//!
+//! Storing the nodes in an arena places them contiguously in a single allocation, instead of
+//! each in its own allocation as with `Box`, which improves locality and avoids the overhead of
+//! allocating and freeing each node, and indices can be smaller than pointers.  The generation
+//! of each index prevents a stale index from accessing a node that later reuses the same slot.
+//!
+//! This crate forbids `unsafe` code in all of its targets, so each link shares the arena via
+//! `Rc<RefCell<_>>`, rather than via a raw pointer, and the link type is also the node type of
+//! the traversal, like `RcLink` is, which accesses the nodes in the arena via the link.
+
+// The dev-dependencies are also given to the examples, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+#![allow(clippy::print_stdout)] // It is a program that reports its results.
+
+extern crate alloc;
+
+use {
+    alloc::rc::Rc,
+    core::cell::RefCell,
+    deep_safe_drop::{
+        DeepSafeDrop,
+        Link,
+        SetParent,
+        deep_safe_drop,
+    },
+    generational_arena::{
+        Arena,
+        Index,
+    },
+    std::process::ExitCode,
+};
+
+
+/// The amount of nodes along the left spine, each of which also has a right child that is a
+/// leaf, so that the tree has twice as many nodes, i.e. 100 000, and is as deep as the spine.
+const SPINE_LEN: usize = 50_000;
+
+
+struct ArenaNode
+{
+    left:  Option<Index>,
+    right: Option<Index>,
+    data:  u64,
+}
+
+type SharedArena = Rc<RefCell<Arena<ArenaNode>>>;
+
+
+/// A link to a node in the arena, which owns it, and so removes it when dropped.
+struct ArenaLink
+{
+    /// Only absent after `into_index`, which disowns the node.
+    index: Option<Index>,
+    arena: SharedArena,
+}
+
+impl ArenaLink
+{
+    fn new(
+        arena: &SharedArena,
+        node: ArenaNode,
+    ) -> Self
+    {
+        let index = arena.borrow_mut().insert(node);
+        Self { index: Some(index), arena: Rc::clone(arena) }
+    }
+
+    /// Make a link that owns the node at `index`, in the same arena.
+    fn link_to(
+        &self,
+        index: Index,
+    ) -> Self
+    {
+        Self { index: Some(index), arena: Rc::clone(&self.arena) }
+    }
+
+    /// Disown the node, without removing it, e.g. for storing the index in another node.
+    fn into_index(mut self) -> Option<Index>
+    {
+        self.index.take()
+    }
+
+    /// Access the node, if it is owned and still in the arena.
+    fn with_node<R>(
+        &self,
+        f: impl FnOnce(&mut ArenaNode) -> R,
+    ) -> Option<R>
+    {
+        let index = self.index?;
+        self.arena.borrow_mut().get_mut(index).map(f)
+    }
+}
+
+impl Link<Self> for ArenaLink
+{
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+impl DeepSafeDrop<Self> for ArenaLink
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        let parent_index = parent.into_index();
+        match self.with_node(|node| core::mem::replace(&mut node.left, parent_index)) {
+            Some(Some(child0)) => SetParent::YesReplacedChild { child0: self.link_to(child0) },
+            Some(None) => SetParent::Yes,
+            // Not reached, because every owned node is in the arena.
+            None => SetParent::No {
+                returned_parent: Self { index: parent_index, arena: Rc::clone(&self.arena) },
+            },
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        let child = self.with_node(|node| node.left.take()).flatten()?;
+        Some(self.link_to(child))
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        let child = self.with_node(|node| node.right.take()).flatten()?;
+        Some(self.link_to(child))
+    }
+}
+
+impl Drop for ArenaLink
+{
+    fn drop(&mut self)
+    {
+        if let Some(index) = self.index {
+            deep_safe_drop::<Self, Self, Self>(self);
+            let _: Option<ArenaNode> = self.arena.borrow_mut().remove(index);
+        }
+    }
+}
+
+
+/// Build the tree bottom-up, so that no recursion is needed here either.
+fn build(arena: &SharedArena) -> ArenaLink
+{
+    let spine = |left, data| {
+        let right =
+            ArenaLink::new(arena, ArenaNode { left: None, right: None, data }).into_index();
+        ArenaLink::new(arena, ArenaNode { left, right, data })
+    };
+    (1 .. SPINE_LEN).fold(spine(None, 0), |below, i| {
+        spine(below.into_index(), u64::try_from(i).unwrap_or(u64::MAX))
+    })
+}
+
+
+fn main() -> ExitCode
+{
+    let arena: SharedArena = Rc::new(RefCell::new(Arena::new()));
+    let root = build(&arena);
+    println!("built a tree of {} nodes", arena.borrow().len());
+    let sum: u64 = arena.borrow().iter().map(|(_, node)| node.data).sum();
+    println!("the sum of their data is {sum}");
+
+    drop(root);
+
+    let remaining = arena.borrow().len();
+    println!("{remaining} nodes remain in the arena after dropping the tree");
+    if remaining == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
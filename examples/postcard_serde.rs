@@ -0,0 +1,120 @@
+//! Serializing a tree of `NaryNode`s to the compact binary format of `postcard`, deserializing it
+//! back, and dropping the deserialized tree safely, with only the `alloc` feature of `postcard`
+//! and of this crate, as in a `no_std` system with a heap.
+//!
+//! Run with `cargo run --example postcard_serde`, which exits with failure if the deserialized
+//! tree does not match the original.
+//!
+//! Like all of serde, serializing and deserializing are recursive, and so the tree here is wide
+//! rather than deep, but dropping it, and comparing it, are done without recursion.
+
+// The dev-dependencies are also given to the examples, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+#![allow(clippy::print_stdout)] // It is a program that reports its results.
+
+use {
+    deep_safe_drop::{
+        Link,
+        deep_safe_drop,
+        deep_safe_eq_by,
+        helpers::NaryNode,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    std::process::ExitCode,
+};
+
+
+const NODES: usize = 10_000;
+const BRANCHING: usize = 10;
+
+
+/// The link type, which is needed because `Box<NaryNode<Box<NaryNode<...>>>>` is infinite.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct NaryBox(Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl core::borrow::Borrow<NaryNode<Self>> for NaryBox
+{
+    fn borrow(&self) -> &NaryNode<Self>
+    {
+        &self.0
+    }
+}
+
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+
+/// Build a complete tree of `NODES` nodes, where each node has up to `BRANCHING` children, from
+/// the bottom level up, so that its depth is only logarithmic.
+fn build() -> NaryBox
+{
+    // The nodes in level-order, where the children of the node at `i` start at
+    // `i * BRANCHING + 1`.
+    let mut nodes: Vec<Option<NaryNode<NaryBox>>> =
+        core::iter::repeat_with(|| Some(NaryNode::default())).take(NODES).collect();
+    for child in (1 .. NODES).rev() {
+        let parent = child.saturating_sub(1).div_euclid(BRANCHING);
+        let link =
+            nodes.get_mut(child).and_then(Option::take).map(|node| NaryBox(Box::new(node)));
+        if let (Some(Some(parent)), Some(link)) = (nodes.get_mut(parent), link) {
+            // Reversed below, because the children are added from last to first.
+            parent.children_mut().push(link);
+        }
+    }
+    for node in nodes.iter_mut().flatten() {
+        node.children_mut().reverse();
+    }
+    NaryBox(Box::new(nodes.swap_remove(0).unwrap_or_default()))
+}
+
+
+fn main() -> ExitCode
+{
+    let tree = build();
+
+    let Ok(bytes) = postcard::to_allocvec(&tree)
+    else {
+        return ExitCode::FAILURE;
+    };
+    // Each node is only its amount of children, which is a single byte as a varint.
+    println!("{NODES} nodes serialized to {} bytes", bytes.len());
+
+    let Ok(deserialized) = postcard::from_bytes::<NaryBox>(&bytes)
+    else {
+        return ExitCode::FAILURE;
+    };
+    let same = deep_safe_eq_by::<_, _, NaryNode<NaryBox>, NaryNode<NaryBox>, _>(
+        &tree,
+        &deserialized,
+        |n1, n2| n1.children().len() == n2.children().len(),
+    );
+
+    // Both are dropped via `deep_safe_drop`, by `NaryBox::drop`.
+    drop(deserialized);
+    drop(tree);
+
+    if same {
+        println!("the deserialized tree matches the original");
+        ExitCode::SUCCESS
+    }
+    else {
+        ExitCode::FAILURE
+    }
+}
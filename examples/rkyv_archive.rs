@@ -0,0 +1,158 @@
+//! Archiving a binary tree with `rkyv`, accessing the archived tree without copying it,
+//! deserializing it back, and dropping the deserialized tree safely.
+//!
+//! Run with `cargo run --example rkyv_archive`, which exits with failure if the archived or the
+//! deserialized tree does not have the same shape as the original.
+//!
+//! Like serde, `rkyv` serializes and deserializes recursively, and so the tree here is balanced
+//! rather than deep, but dropping it, and comparing it, are done without recursion.
+//!
+//! The archived nodes are not dropped node by node, because they are immutable data in the bytes
+//! of the archive, which are freed all at once.  So, `ArchivedNode` only implements
+//! `ReadableChildren`, for the traversals that do not modify a tree, where the links are the
+//! relative pointers of `ArchivedBox`, but not `DeepSafeDrop`, which needs to take children.
+
+// The dev-dependencies are also given to the examples, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+#![allow(clippy::print_stdout)] // It is a program that reports its results.
+
+use {
+    core::{
+        iter::Chain,
+        option,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        ReadableChildren,
+        deep_safe_eq_by,
+        drop_boxed,
+    },
+    rkyv::{
+        Archive,
+        Deserialize,
+        Serialize,
+        boxed::ArchivedBox,
+        rancor,
+    },
+    std::process::ExitCode,
+};
+
+
+const NODES: usize = 10_000;
+
+
+#[derive(Archive, Serialize, Deserialize, DeepSafeDrop)]
+#[rkyv(
+    serialize_bounds(
+        __S: rkyv::ser::Writer + rkyv::ser::Allocator,
+        __S::Error: rancor::Source,
+    ),
+    deserialize_bounds(__D::Error: rancor::Source),
+    bytecheck(bounds(__C: rkyv::validation::ArchiveContext, __C::Error: rancor::Source)),
+)]
+struct Node
+{
+    #[child(index = 0)]
+    #[rkyv(omit_bounds)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    #[rkyv(omit_bounds)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+impl ReadableChildren<Box<Self>> for Node
+{
+    type Children<'n> = Chain<option::Iter<'n, Box<Self>>, option::Iter<'n, Box<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
+
+impl ReadableChildren<ArchivedBox<Self>> for ArchivedNode
+{
+    type Children<'n> =
+        Chain<option::IntoIter<&'n ArchivedBox<Self>>, option::IntoIter<&'n ArchivedBox<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.as_ref().into_iter().chain(self.right.as_ref())
+    }
+}
+
+
+/// Build a complete binary tree of `NODES` nodes, from the bottom level up, so that its depth is
+/// only logarithmic.
+fn build() -> Node
+{
+    // The nodes in level-order, where the children of the node at `i` are at `2 * i + 1` and
+    // `2 * i + 2`.
+    let mut nodes: Vec<Option<Node>> =
+        core::iter::repeat_with(|| Some(Node { left: None, right: None })).take(NODES).collect();
+    for child in (1 .. NODES).rev() {
+        let parent = child.saturating_sub(1).div_euclid(2);
+        let link = nodes.get_mut(child).and_then(Option::take).map(Box::new);
+        if let Some(Some(parent)) = nodes.get_mut(parent) {
+            if child & 1 == 1 {
+                parent.left = link;
+            }
+            else {
+                parent.right = link;
+            }
+        }
+    }
+    nodes.swap_remove(0).unwrap_or(Node { left: None, right: None })
+}
+
+
+fn main() -> ExitCode
+{
+    let tree = Box::new(build());
+
+    let Ok(bytes) = rkyv::to_bytes::<rancor::Error>(&tree)
+    else {
+        return ExitCode::FAILURE;
+    };
+    println!("{NODES} nodes archived to {} bytes", bytes.len());
+
+    // Validates the archive, and then accesses it in place.
+    let Ok(archived) = rkyv::access::<ArchivedBox<ArchivedNode>, rancor::Error>(&bytes)
+    else {
+        return ExitCode::FAILURE;
+    };
+    let same_shape = |n1: &Node, n2: &ArchivedNode| {
+        n1.left.is_some() == n2.left.is_some() && n1.right.is_some() == n2.right.is_some()
+    };
+    let archived_same =
+        deep_safe_eq_by::<_, _, Node, ArchivedNode, _>(&tree, archived, same_shape);
+
+    let Ok(deserialized) = rkyv::deserialize::<Box<Node>, rancor::Error>(archived)
+    else {
+        return ExitCode::FAILURE;
+    };
+    let deserialized_same =
+        deep_safe_eq_by::<_, _, Node, Node, _>(&tree, &deserialized, |n1, n2| {
+            n1.left.is_some() == n2.left.is_some() && n1.right.is_some() == n2.right.is_some()
+        });
+
+    // Both are dropped via `deep_safe_drop`, by `Node::drop`.
+    drop(deserialized);
+    drop(tree);
+
+    if archived_same && deserialized_same {
+        println!("the archived and the deserialized trees have the same shape as the original");
+        ExitCode::SUCCESS
+    }
+    else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,78 @@
+//! Builds a tree from the input and drops it.  Run with, e.g.:
+//! `cargo +nightly fuzz run fuzz_drop -- -max_total_time=60`
+//!
+//! The input is decoded as a sequence of `(branching_factor, data)` byte pairs, one per node, in
+//! pre-order: each node is the next child of the nearest preceding node that still has fewer
+//! children than its branching factor.  Any trailing odd byte is ignored, and children that the
+//! input ends before are omitted, so that every input is a valid tree, e.g. the empty input is
+//! the trivial tree of only the root.  `NaryNode` has no data, so that byte only varies the
+//! input.
+
+#![no_main]
+
+use {
+    deep_safe_drop::{
+        deep_safe_drop,
+        helpers::NaryNode,
+        Link,
+    },
+    libfuzzer_sys::fuzz_target,
+};
+
+
+struct NaryBox(Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+
+/// Iterative, so that deep inputs do not overflow the stack here either.
+fn decode(input: &[u8]) -> NaryNode<NaryBox>
+{
+    // Each node that is still being built, with the amount of children it still needs.
+    let mut stack = vec![(NaryNode::default(), usize::MAX)];
+
+    for pair in input.chunks_exact(2) {
+        let &[branching_factor, _data] = pair else { continue };
+        while stack.len() > 1 && stack.last().is_some_and(|&(_, needed)| needed == 0) {
+            finish_top(&mut stack);
+        }
+        if let Some((_, needed)) = stack.last_mut() {
+            *needed = needed.saturating_sub(1);
+        }
+        stack.push((NaryNode::default(), usize::from(branching_factor)));
+    }
+
+    while stack.len() > 1 {
+        finish_top(&mut stack);
+    }
+    stack.pop().map(|(root, _)| root).unwrap_or_default()
+}
+
+fn finish_top(stack: &mut Vec<(NaryNode<NaryBox>, usize)>)
+{
+    if let Some((node, _)) = stack.pop() {
+        if let Some((parent, _)) = stack.last_mut() {
+            parent.children_mut().push(NaryBox(Box::new(node)));
+        }
+    }
+}
+
+
+fuzz_target!(|input: &[u8]| {
+    let mut root = decode(input);
+    deep_safe_drop::<NaryNode<NaryBox>, NaryBox, NaryNode<NaryBox>>(&mut root);
+});
@@ -0,0 +1,144 @@
+//! Builds a tree, whose nodes give all the variants of `SetParent`, from the input and drops it.
+//! Run with, e.g.:
+//! `cargo +nightly fuzz run fuzz_set_parent -- -max_total_time=60`
+//!
+//! The input is decoded as a sequence of `(kind, branching_factor)` byte pairs, one per node, in
+//! pre-order, like for `fuzz_drop`.  The `kind` modulo 3 selects: 0 for a node that has no links,
+//! which gives `SetParent::No`; 1 for a node whose children are all at positive indices, which
+//! gives `SetParent::Yes`; and 2 for a node whose first child is at index 0, which gives
+//! `SetParent::YesReplacedChild` when it has any children.  The `branching_factor` of a node that
+//! has no links is ignored.
+
+#![no_main]
+
+use {
+    deep_safe_drop::{
+        deep_safe_drop,
+        deep_safe_drop_stats,
+        DeepSafeDrop,
+        Link,
+        SetParent,
+    },
+    libfuzzer_sys::fuzz_target,
+};
+
+
+enum Node<L>
+{
+    /// Has no links, not even for reuse as the parent link.
+    Leaf,
+    Branch
+    {
+        first: Option<L>,
+        rest:  Vec<L>,
+    },
+}
+
+impl<L> DeepSafeDrop<L> for Node<L>
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self {
+            Self::Leaf => SetParent::No { returned_parent: parent },
+            Self::Branch { first, .. } => match first.replace(parent) {
+                Some(child0) => SetParent::YesReplacedChild { child0 },
+                None => SetParent::Yes,
+            },
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        match self {
+            Self::Leaf => None,
+            Self::Branch { first, .. } => first.take(),
+        }
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        match self {
+            Self::Leaf => None,
+            Self::Branch { rest, .. } => rest.pop(),
+        }
+    }
+}
+
+struct NodeBox(Box<Node<Self>>);
+
+impl Link<Node<Self>> for NodeBox
+{
+    fn get_mut(&mut self) -> &mut Node<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for NodeBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Node<Self>, Self, Node<Self>>(&mut *self.0);
+    }
+}
+
+
+/// Whether the first child of a node is stored at index 0.
+type UsesIndex0 = bool;
+
+/// Iterative, so that deep inputs do not overflow the stack here either.  Also returns the amount
+/// of nodes, including the root.
+fn decode(input: &[u8]) -> (Node<NodeBox>, usize)
+{
+    // Each node that is still being built, with the amount of children it still needs.
+    let root = Node::Branch { first: None, rest: Vec::new() };
+    let mut stack: Vec<(Node<NodeBox>, UsesIndex0, usize)> = vec![(root, false, usize::MAX)];
+    let mut count: usize = 1;
+
+    for pair in input.chunks_exact(2) {
+        let &[kind, branching_factor] = pair else { continue };
+        while stack.len() > 1 && stack.last().is_some_and(|&(_, _, needed)| needed == 0) {
+            finish_top(&mut stack);
+        }
+        if let Some((_, _, needed)) = stack.last_mut() {
+            *needed = needed.saturating_sub(1);
+        }
+        let branch = || Node::Branch { first: None, rest: Vec::new() };
+        stack.push(match kind % 3 {
+            0 => (Node::Leaf, false, 0),
+            1 => (branch(), false, usize::from(branching_factor)),
+            _ => (branch(), true, usize::from(branching_factor)),
+        });
+        count = count.saturating_add(1);
+    }
+
+    while stack.len() > 1 {
+        finish_top(&mut stack);
+    }
+    (stack.pop().map_or(Node::Leaf, |(root, _, _)| root), count)
+}
+
+fn finish_top(stack: &mut Vec<(Node<NodeBox>, UsesIndex0, usize)>)
+{
+    if let Some((node, _, _)) = stack.pop() {
+        if let Some((Node::Branch { first, rest }, uses_index_0, _)) = stack.last_mut() {
+            let child = NodeBox(Box::new(node));
+            if *uses_index_0 && first.is_none() {
+                *first = Some(child);
+            }
+            else {
+                rest.push(child);
+            }
+        }
+    }
+}
+
+
+fuzz_target!(|input: &[u8]| {
+    let (mut root, count) = decode(input);
+    let stats = deep_safe_drop_stats::<Node<NodeBox>, NodeBox, Node<NodeBox>>(&mut root);
+    assert_eq!(stats.nodes_dropped, count - 1, "every node except the root must be dropped");
+});
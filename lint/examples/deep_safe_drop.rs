@@ -0,0 +1,45 @@
+//! Not linted, because the `Drop` implementations call functions of `deep_safe_drop`.
+
+use deep_safe_drop::{
+    DeepSafeDrop,
+    deep_safe_drop,
+    drop_boxed,
+};
+
+#[derive(DeepSafeDrop)]
+struct List
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+#[derive(DeepSafeDrop)]
+struct Tree
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+}
+
+impl Drop for Tree
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<_, Box<Self>, _>(self);
+    }
+}
+
+fn main()
+{
+    let _list = List { next: Some(Box::new(List { next: None })) };
+    let _tree = Tree { left: None, right: None };
+}
@@ -0,0 +1,198 @@
+//! Lint for `Drop` implementations that may cause stack overflow, which the [`deep_safe_drop`]
+//! crate avoids.
+//!
+//! This is a [Dylint](https://github.com/trailofbits/dylint) library, which is loaded by
+//! `cargo dylint`, and so it requires the nightly toolchain of `rust-toolchain.toml`.
+//!
+//! [`deep_safe_drop`]: https://docs.rs/deep_safe_drop
+
+#![feature(rustc_private)]
+
+extern crate rustc_hir;
+extern crate rustc_middle;
+extern crate rustc_span;
+
+use {
+    clippy_utils::{
+        diagnostics::span_lint_and_note,
+        source::snippet,
+        visitors::for_each_expr,
+    },
+    core::ops::ControlFlow,
+    rustc_hir::{
+        ExprKind,
+        Impl,
+        ImplItemKind,
+        Item,
+        ItemKind,
+    },
+    rustc_lint::{
+        LateContext,
+        LateLintPass,
+    },
+    rustc_middle::ty::{
+        self,
+        AdtDef,
+        GenericArgKind,
+    },
+    rustc_span::Symbol,
+};
+
+
+dylint_linting::declare_late_lint! {
+    /// ### What it does
+    ///
+    /// Checks for `Drop` implementations of types that contain `Box<Self>`, i.e. nodes of trees or
+    /// lists, that do not call any function of the `deep_safe_drop` crate.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// After your `Drop::drop`, the fields are dropped by the compiler-added dropping, which
+    /// recurses through each `Box<Self>`, and so deep trees, e.g. long lists, overflow the stack.
+    ///
+    /// ### Known problems
+    ///
+    /// Only `Box<Self>` in the types of the fields is recognized, not other link types.  Types
+    /// without a `Drop` implementation, whose dropping also recurses, are not linted.  A
+    /// `Drop::drop` that avoids the recursion by its own loop is linted anyway.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// struct List
+    /// {
+    ///     next: Option<Box<Self>>,
+    /// }
+    ///
+    /// impl Drop for List
+    /// {
+    ///     fn drop(&mut self) {}
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust,ignore
+    /// #[derive(DeepSafeDrop)]
+    /// struct List
+    /// {
+    ///     #[child(index = 0)]
+    ///     next: Option<Box<Self>>,
+    /// }
+    ///
+    /// impl Drop for List
+    /// {
+    ///     fn drop(&mut self)
+    ///     {
+    ///         deep_safe_drop::drop_boxed(self);
+    ///     }
+    /// }
+    /// ```
+    pub DEEP_RECURSIVE_DROP,
+    Warn,
+    "a `Drop` implementation of a type that contains `Box<Self>` which may cause stack overflow"
+}
+
+impl<'tcx> LateLintPass<'tcx> for DeepRecursiveDrop
+{
+    fn check_item(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        item: &'tcx Item<'tcx>,
+    )
+    {
+        if let ItemKind::Impl(impl_) = item.kind
+            && let Some(of_trait) = impl_.of_trait
+            && of_trait.trait_ref.trait_def_id() == cx.tcx.lang_items().drop_trait()
+            && let ty::Adt(adt, _) = cx.tcx.type_of(item.owner_id).instantiate_identity().skip_norm_wip().kind()
+            && let Some(field_span) = box_self_field(cx, *adt)
+            && !calls_deep_safe_drop(cx, &impl_)
+        {
+            span_lint_and_note(
+                cx,
+                DEEP_RECURSIVE_DROP,
+                cx.tcx.def_span(item.owner_id),
+                "this type contains `Box<Self>` and its `Drop` implementation may cause stack \
+                 overflow for deep trees; consider using `deep_safe_drop`",
+                Some(field_span),
+                format!("the dropping of `{}` recurses", snippet(cx, field_span, "..")),
+            );
+        }
+    }
+}
+
+
+/// The span of the first field of `adt` whose type contains `Box<Self>`, if any.
+fn box_self_field(
+    cx: &LateContext<'_>,
+    adt: AdtDef<'_>,
+) -> Option<rustc_span::Span>
+{
+    let is_box_self = |arg: ty::GenericArg<'_>| {
+        if let GenericArgKind::Type(ty) = arg.kind()
+            && let Some(boxed) = ty.boxed_ty()
+            && let ty::Adt(boxed_adt, _) = boxed.kind()
+        {
+            boxed_adt.did() == adt.did()
+        }
+        else {
+            false
+        }
+    };
+
+    adt.all_fields()
+        .find(|field| {
+            let ty = cx.tcx.type_of(field.did).instantiate_identity().skip_norm_wip();
+            ty.walk().any(is_box_self)
+        })
+        .map(|field| cx.tcx.def_span(field.did))
+}
+
+
+/// Whether any method of `impl_` calls any function of the `deep_safe_drop` crate.
+fn calls_deep_safe_drop(
+    cx: &LateContext<'_>,
+    impl_: &Impl<'_>,
+) -> bool
+{
+    let crate_name = Symbol::intern("deep_safe_drop");
+
+    impl_.items.iter().any(|&id| {
+        let ImplItemKind::Fn(_, body_id) = cx.tcx.hir_impl_item(id).kind
+        else {
+            return false;
+        };
+        // This is not within a body, and so its type-checking results must be gotten.
+        let typeck = cx.tcx.typeck_body(body_id);
+        for_each_expr(cx, cx.tcx.hir_body(body_id).value, |expr| {
+            let callee = match expr.kind {
+                ExprKind::Call(func, _) => match func.kind {
+                    ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, func.hir_id).opt_def_id(),
+                    _ => None,
+                },
+                ExprKind::MethodCall(..) => typeck.type_dependent_def_id(expr.hir_id),
+                _ => None,
+            };
+            match callee {
+                Some(callee) if cx.tcx.crate_name(callee.krate) == crate_name =>
+                    ControlFlow::Break(()),
+                _ => ControlFlow::Continue(()),
+            }
+        })
+        .is_some()
+    })
+}
+
+
+#[test]
+fn ui()
+{
+    dylint_testing::ui_test(env!("CARGO_CRATE_NAME"), "ui");
+}
+
+
+#[test]
+fn examples()
+{
+    dylint_testing::ui_test_examples(env!("CARGO_CRATE_NAME"));
+}
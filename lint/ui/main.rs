@@ -0,0 +1,64 @@
+// Linted, because the fields are dropped recursively after `drop`.
+struct List
+{
+    next: Option<Box<Self>>,
+    data: u64,
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        self.data = 0;
+    }
+}
+
+// Linted, also for enums and for `Box<Self>` that is nested in other types.
+enum Tree
+{
+    Leaf,
+    Branch(Vec<Box<Tree>>),
+}
+
+impl Drop for Tree
+{
+    fn drop(&mut self) {}
+}
+
+// Linted, also for generic types.
+struct Node<T>
+{
+    data:     T,
+    children: [Option<Box<Node<T>>>; 2],
+}
+
+impl<T> Drop for Node<T>
+{
+    fn drop(&mut self) {}
+}
+
+// Not linted, because it does not contain `Box<Self>`.
+struct Leaf
+{
+    data: Box<u64>,
+}
+
+impl Drop for Leaf
+{
+    fn drop(&mut self) {}
+}
+
+// Not linted, because it does not implement `Drop`.
+struct Plain
+{
+    next: Option<Box<Self>>,
+}
+
+fn main()
+{
+    let _list = List { next: None, data: 1 };
+    let _tree = Tree::Branch(vec![Box::new(Tree::Leaf)]);
+    let _node = Node { data: (), children: [None, None] };
+    let _leaf = Leaf { data: Box::new(1) };
+    let _plain = Plain { next: None };
+}
@@ -0,0 +1,118 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        main_deep_safe_drop,
+    },
+    core::marker::PhantomData,
+    std::{
+        sync::mpsc::{
+            self,
+            SendError,
+            Sender,
+        },
+        thread::{
+            self,
+            JoinHandle,
+        },
+    },
+};
+
+
+/// Drops trees, safely, in a background thread, with the `std` feature, so that dropping large
+/// trees does not stall the current thread.
+///
+/// The worker thread is spawned when this is made, and it drops each tree given to
+/// [`send`](Self::send) in the order they were sent.  Dropping this without
+/// [`finish`](Self::finish) also waits for the remaining trees to be dropped.
+#[derive(Debug)]
+pub struct BackgroundDropQueue<L, N>
+where
+    L: Link<N> + Send + 'static,
+    N: DeepSafeDrop<L> + ?Sized + 'static,
+{
+    sender: Option<Sender<L>>,
+    worker: Option<JoinHandle<()>>,
+    _node:  PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> BackgroundDropQueue<L, N>
+where
+    L: Link<N> + Send + 'static,
+    N: DeepSafeDrop<L> + ?Sized + 'static,
+{
+    /// Spawn the worker thread.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        let (sender, receiver) = mpsc::channel::<L>();
+        let worker = thread::spawn(move || {
+            for link in receiver {
+                main_deep_safe_drop(link, &mut |_: &mut N| {});
+            }
+        });
+        Self { sender: Some(sender), worker: Some(worker), _node: PhantomData }
+    }
+
+    /// Transfer ownership of the tree of `root` to the worker thread, which drops it.
+    ///
+    /// # Errors
+    ///
+    /// If the worker thread has exited, because the dropping of a previous tree panicked, in
+    /// which case `root` is returned back.
+    #[inline]
+    pub fn send(
+        &self,
+        root: L,
+    ) -> Result<(), L>
+    {
+        match &self.sender {
+            Some(sender) => sender.send(root).map_err(|SendError(root)| root),
+            None => Err(root),
+        }
+    }
+
+    /// Wait until the worker thread has dropped all the sent trees, and then it exits.
+    ///
+    /// # Errors
+    ///
+    /// If the dropping of a tree panicked, the payload of that panic.
+    #[inline]
+    pub fn finish(mut self) -> thread::Result<()>
+    {
+        self.join()
+    }
+
+    fn join(&mut self) -> thread::Result<()>
+    {
+        // Disconnecting makes the worker's loop end after it drains the channel.
+        drop(self.sender.take());
+        self.worker.take().map_or(Ok(()), JoinHandle::join)
+    }
+}
+
+impl<L, N> Default for BackgroundDropQueue<L, N>
+where
+    L: Link<N> + Send + 'static,
+    N: DeepSafeDrop<L> + ?Sized + 'static,
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<L, N> Drop for BackgroundDropQueue<L, N>
+where
+    L: Link<N> + Send + 'static,
+    N: DeepSafeDrop<L> + ?Sized + 'static,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        // A panic of the worker is only reported by `finish`.
+        drop(self.join());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    DeepSafeDrop,
+    Link,
+};
+
+
+/// Drop a tree by a depth-first traversal whose stack of ancestors is a fixed-size array of
+/// `STACK_SIZE` links, instead of by the link-reversal of
+/// [`deep_safe_drop`](fn@crate::deep_safe_drop), for trees whose depth is known to be bounded,
+/// e.g. B-trees.
+///
+/// This does not use [`DeepSafeDrop::set_parent_at_index_0`], because the parent of each node is
+/// recorded in the array, and so only the taking of children is needed, which is simpler to
+/// implement.  It is not necessarily faster, because each step indexes into the array instead.
+/// The array is on the call-stack, and so it does not allocate, but its size must be affordable
+/// there.
+///
+/// The `root` is at depth 1, and so `STACK_SIZE` must be at least the height of the tree.  Each
+/// node is dropped after all its children have been taken from it, i.e. in post-order.
+///
+/// # Panics
+///
+/// If the depth of the tree exceeds `STACK_SIZE`, in which case the nodes that are in the array
+/// are dropped during the unwinding, which might overflow the stack when the remaining tree is
+/// deep.
+#[inline]
+pub fn bounded_stack_drop<L, N, const STACK_SIZE: usize>(root: L)
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    let mut stack: [Option<L>; STACK_SIZE] = core::array::from_fn(|_| None);
+    let mut len: usize = 0;
+
+    push(&mut stack, &mut len, root);
+
+    while let Some(top) = len.checked_sub(1).and_then(|top| stack.get_mut(top)) {
+        let child =
+            top.as_mut().and_then(|parent| parent.get_mut().take_next_child_at_any_index());
+        if let Some(child) = child {
+            push(&mut stack, &mut len, child);
+        }
+        else {
+            // All its children have been taken, i.e. it is a leaf now.
+            drop(top.take());
+            len = len.saturating_sub(1);
+        }
+    }
+}
+
+
+/// Put `link` on the top of the `stack` of `len` links.
+#[inline]
+fn push<L>(
+    stack: &mut [Option<L>],
+    len: &mut usize,
+    link: L,
+)
+{
+    if let Some(slot) = stack.get_mut(*len) {
+        *slot = Some(link);
+        *len = len.saturating_add(1);
+    }
+    else {
+        depth_limit_exceeded();
+    }
+}
+
+
+#[cold]
+#[inline(never)]
+fn depth_limit_exceeded() -> !
+{
+    // This is the failure of an assertion.
+    #[allow(clippy::panic)]
+    {
+        panic!(
+            "depth limit exceeded; increase STACK_SIZE or use deep_safe_drop with link-reversal"
+        );
+    }
+}
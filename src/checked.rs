@@ -0,0 +1,161 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        SetParent,
+    },
+    core::ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+
+/// Wraps your node type to check, in debug builds, that its [`DeepSafeDrop`] implementation
+/// behaves as the traversal expects, for debugging such implementations during development.
+///
+/// Use this as the node type of your links, e.g. `Box<CheckedNode<Node>>`, with `Node`
+/// implementing `DeepSafeDrop` for that link type.  The methods delegate to your node and panic,
+/// via `debug_assert!`, when:
+///
+/// - `set_parent_at_index_0` is called again before `take_child_at_index_0` has taken that
+///   parent, i.e. the node is descended into twice, as by a cycle.
+/// - `take_child_at_index_0` returns a link again without an intervening `set_parent_at_index_0`
+///   or `store_child_at_index_0`, i.e. it does not replace the link it takes with a non-link.
+/// - `take_next_child_at_pos_index` returns a child after it has returned `None`, i.e. after all
+///   the children have been taken.
+///
+/// Calling these again after they have returned `None` is not a bug, and the traversal does so
+/// in debug builds to check that they keep returning `None`.  The state of the checking is reset
+/// by any mutable access to your node, via [`DerefMut`], e.g. for adding children to it.  In
+/// release builds, the state is still recorded but is not checked.
+#[doc(alias = "CheckedLink")]
+#[derive(Debug, Default)]
+pub struct CheckedNode<N: ?Sized>
+{
+    parent_set:    bool,
+    index0_taken:  bool,
+    pos_exhausted: bool,
+    inner:         N,
+}
+
+impl<N> CheckedNode<N>
+{
+    /// Wrap `inner`.
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: N) -> Self
+    {
+        Self { parent_set: false, index0_taken: false, pos_exhausted: false, inner }
+    }
+
+    /// Unwrap the node.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> N
+    {
+        self.inner
+    }
+}
+
+impl<N: ?Sized> Deref for CheckedNode<N>
+{
+    type Target = N;
+
+    #[inline]
+    fn deref(&self) -> &N
+    {
+        &self.inner
+    }
+}
+
+impl<N: ?Sized> DerefMut for CheckedNode<N>
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut N
+    {
+        self.parent_set = false;
+        self.index0_taken = false;
+        self.pos_exhausted = false;
+        &mut self.inner
+    }
+}
+
+impl<L, N> DeepSafeDrop<L> for CheckedNode<N>
+where N: DeepSafeDrop<L> + ?Sized
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        debug_assert!(
+            !self.parent_set,
+            "set_parent_at_index_0 called twice without take_child_at_index_0"
+        );
+        let set_parent = self.inner.set_parent_at_index_0(parent);
+        if !matches!(set_parent, SetParent::No { .. }) {
+            self.parent_set = true;
+            self.index0_taken = false;
+        }
+        set_parent
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        let child0 = self.inner.take_child_at_index_0();
+        if child0.is_some() {
+            debug_assert!(
+                !self.index0_taken,
+                "take_child_at_index_0 returned a link twice without set_parent_at_index_0"
+            );
+            self.index0_taken = true;
+            self.parent_set = false;
+        }
+        child0
+    }
+
+    /// Delegates to your node's, without checking that as `set_parent_at_index_0`, because this
+    /// stores a child, not a parent.
+    #[inline]
+    fn store_child_at_index_0(
+        &mut self,
+        child: L,
+    ) -> Option<L>
+    {
+        self.index0_taken = false;
+        self.inner.store_child_at_index_0(child)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        let child = self.inner.take_next_child_at_pos_index();
+        if child.is_some() {
+            debug_assert!(
+                !self.pos_exhausted,
+                "take_next_child_at_pos_index returned a child after all children were taken"
+            );
+        }
+        else {
+            self.pos_exhausted = true;
+        }
+        child
+    }
+
+    #[inline]
+    fn child_count_hint(&self) -> Option<usize>
+    {
+        self.inner.child_count_hint()
+    }
+
+    #[inline]
+    fn swap_child_at_index_0_with(
+        &mut self,
+        i: usize,
+    ) -> bool
+    {
+        self.inner.swap_child_at_index_0_with(i)
+    }
+}
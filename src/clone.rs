@@ -0,0 +1,79 @@
+use {
+    crate::ReadableChildren,
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+};
+
+
+/// Implement this for your tree node type, in addition to [`ReadableChildren`], to enable
+/// [`deep_safe_clone`].
+pub trait DeepSafeClone<Link>: ReadableChildren<Link>
+{
+    /// Clone `self` but without any of its children, i.e. as a leaf.
+    #[must_use]
+    fn clone_without_children(&self) -> Self;
+
+    /// Give the clones of the children to a clone made by `clone_without_children`, in the same
+    /// order as [`ReadableChildren::children_of`] gave the originals.
+    fn attach_children(
+        &mut self,
+        children: impl Iterator<Item = Link>,
+    );
+}
+
+
+/// Clone a deep tree without stack overflow, and without modifying the original tree, with the
+/// `alloc` feature.
+///
+/// Like [`pre_order`](crate::pre_order), this uses explicit storage instead of recursive calls:
+/// first the nodes are cloned, without their children, in pre-order, and then they are
+/// assembled, in reverse, by attaching the already-assembled clones of their children.  This
+/// storage grows to the amount of nodes.
+#[inline]
+#[must_use]
+pub fn deep_safe_clone<L, N>(root: &L) -> L
+where
+    L: Borrow<N> + From<N>,
+    N: DeepSafeClone<L>,
+{
+    // Phase 1: clone each node without children, with the amount of its children, in pre-order.
+    let root: &N = root.borrow();
+    let mut stack = Vec::new();
+    let _count = push_children(&mut stack, root);
+    let mut clones: Vec<(N, usize)> = Vec::new();
+    while let Some(link) = stack.pop() {
+        let node = link.borrow();
+        let count = push_children(&mut stack, node);
+        clones.push((node.clone_without_children(), count));
+    }
+
+    // Phase 2: in reverse, the clones of the children of a node are the last ones assembled, with
+    // its first child on top.
+    let mut assembled: Vec<L> = Vec::new();
+    while let Some((mut clone, count)) = clones.pop() {
+        let children = assembled.len().saturating_sub(count);
+        clone.attach_children(assembled.drain(children ..).rev());
+        assembled.push(L::from(clone));
+    }
+    // The rest are the children of the root.
+    let mut clone = root.clone_without_children();
+    clone.attach_children(assembled.into_iter().rev());
+    L::from(clone)
+}
+
+
+/// Push the children of `node` so that the first is on top, and return the amount of them.
+fn push_children<'n, L, N>(
+    stack: &mut Vec<&'n L>,
+    node: &'n N,
+) -> usize
+where
+    N: ReadableChildren<L> + ?Sized,
+{
+    let start = stack.len();
+    stack.extend(node.children_of());
+    if let Some(children) = stack.get_mut(start ..) {
+        children.reverse();
+    }
+    stack.len().saturating_sub(start)
+}
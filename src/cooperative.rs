@@ -0,0 +1,100 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        partial::drop_some,
+    },
+    core::{
+        future::Future,
+        marker::PhantomData,
+        pin::Pin,
+        task::{
+            Context,
+            Poll,
+        },
+    },
+};
+
+
+/// A [`Future`] that owns a tree and drops it in steps of a bounded amount of nodes per poll,
+/// like [`RealTimeDrop`](crate::RealTimeDrop), with the `std` feature, so that dropping a large
+/// tree in an async task does not block the executor's thread for long.
+///
+/// Each poll that does not complete wakes its own task, via [`Waker::wake_by_ref`], before
+/// returning [`Poll::Pending`], so that the executor reschedules it after running other tasks.
+/// The output is the amount of nodes dropped, including the root.  Unlike `RealTimeDrop`, this
+/// is `Send` when your link type is, so that it can be spawned on multi-threaded executors.
+///
+/// If this is dropped before it is complete, the rest of the tree is dropped at once, via the
+/// `Drop` of your link type.
+///
+/// [`Waker::wake_by_ref`]: core::task::Waker::wake_by_ref
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct CooperativeDrop<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// The root, which is dropped by the poll that completes, or `None` after that.
+    root:            Option<L>,
+    /// The rest of the traversal of a child of the root, where the previous poll stopped.
+    rest:            Option<L>,
+    budget_per_poll: usize,
+    dropped:         usize,
+    _node:           PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> CooperativeDrop<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of the tree at `root`, without dropping any of it yet, to drop at most
+    /// `budget_per_poll` nodes per poll, which is at least 1 so that it completes.
+    #[inline]
+    pub fn new(
+        root: L,
+        budget_per_poll: usize,
+    ) -> Self
+    {
+        Self {
+            root:            Some(root),
+            rest:            None,
+            budget_per_poll: budget_per_poll.max(1),
+            dropped:         0,
+            _node:           PhantomData,
+        }
+    }
+}
+
+impl<L, N> Future for CooperativeDrop<L, N>
+where
+    L: Link<N> + Unpin,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    type Output = usize;
+
+    #[inline]
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<usize>
+    {
+        let this = self.get_mut();
+        if let Some(root) = &mut this.root {
+            let dropped = drop_some(root.get_mut(), &mut this.rest, this.budget_per_poll);
+            this.dropped = this.dropped.saturating_add(dropped);
+            if dropped < this.budget_per_poll {
+                // The root has no children remaining.
+                this.root = None;
+                this.dropped = this.dropped.saturating_add(1);
+            }
+            else {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+        Poll::Ready(this.dropped)
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        main_deep_safe_drop,
+        take_parent,
+    },
+    core::{
+        borrow::Borrow,
+        marker::PhantomData,
+    },
+};
+
+
+/// Navigates a tree interactively, by reversing the links along the path from the root to the
+/// current node, like the traversal of [`deep_safe_drop`](fn@crate::deep_safe_drop) does, and so
+/// it does not recurse.
+///
+/// Each node along that path, except the root, has its link at index 0 reused to link to its
+/// parent, and so, to move down to a child at another index, via [`TreeCursor::move_down`], that
+/// child is first exchanged with the child at index 0, via
+/// [`DeepSafeDrop::swap_child_at_index_0_with`], and they are exchanged back when moving up.
+/// Those other indices are recorded, which needs the `alloc` feature, and so only the moves to
+/// them allocate.  Moving up restores the links, and so does dropping the cursor, which then
+/// drops the whole tree safely, via `deep_safe_drop`, like
+/// [`SafeDropGuard`](crate::SafeDropGuard).  [`TreeCursor::into_root`] relinquishes the tree,
+/// with its links restored, without dropping it.
+#[derive(Debug)]
+pub struct TreeCursor<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Only absent after `into_root` consumes `self`.
+    current:      Option<L>,
+    /// The parent of `current`, which links to its own parent, and so on up to the root.
+    parent_chain: Option<L>,
+    depth:        usize,
+    /// The depths of the parents, along the path, whose child at index 0 was exchanged with the
+    /// child at the index, in ascending order of depth.
+    #[cfg(feature = "alloc")]
+    swapped:      Vec<(usize, usize)>,
+    _node:        PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> TreeCursor<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of the tree at `root`, with the cursor at the root.
+    #[inline]
+    #[must_use]
+    pub fn new(root: L) -> Self
+    {
+        #[cfg(feature = "alloc")]
+        let swapped = Vec::new();
+        Self {
+            current: Some(root),
+            parent_chain: None,
+            depth: 0,
+            #[cfg(feature = "alloc")]
+            swapped,
+            _node: PhantomData,
+        }
+    }
+
+    /// Move the cursor to the child at `child_index` of the current node.  Return whether it
+    /// moved, which it does not when there is no such child, or when `child_index` is not 0 and
+    /// the node cannot exchange that child with the child at index 0, or without the `alloc`
+    /// feature.
+    #[inline]
+    pub fn move_down(
+        &mut self,
+        child_index: usize,
+    ) -> bool
+    {
+        if child_index == 0 {
+            return self.move_down_at_index_0();
+        }
+        #[cfg(feature = "alloc")]
+        {
+            if !self.current_node_mut().swap_child_at_index_0_with(child_index) {
+                return false;
+            }
+            if self.move_down_at_index_0() {
+                self.swapped.push((self.depth.saturating_sub(1), child_index));
+                return true;
+            }
+            // There is no such child, and so exchanging back restores the child at index 0.
+            let swapped_back = self.current_node_mut().swap_child_at_index_0_with(child_index);
+            debug_assert!(swapped_back, "must exchange back");
+        }
+        false
+    }
+
+    fn move_down_at_index_0(&mut self) -> bool
+    {
+        let Some(child) = self.current_link_mut().get_mut().take_child_at_index_0()
+        else {
+            return false;
+        };
+        let mut parent = self.current_link_take();
+        if let Some(grandparent) = self.parent_chain.take() {
+            let replaced = parent.get_mut().store_child_at_index_0(grandparent);
+            // The child was just taken from index 0, so it has the link to store to and nothing
+            // was replaced.
+            debug_assert!(replaced.is_none(), "must have stored");
+        }
+        self.parent_chain = Some(parent);
+        self.current = Some(child);
+        self.depth = self.depth.saturating_add(1);
+        true
+    }
+
+    /// Move the cursor to the parent of the current node, restoring the link to the current
+    /// node.  Return whether it moved, which it does not when at the root.
+    #[inline]
+    pub fn move_up(&mut self) -> bool
+    {
+        let Some(mut parent) = self.parent_chain.take()
+        else {
+            return false;
+        };
+        // The root has no parent, which leaves `parent_chain` as `None`.
+        self.parent_chain = take_parent(parent.get_mut());
+        let child = self.current_link_take();
+        let replaced = parent.get_mut().store_child_at_index_0(child);
+        debug_assert!(replaced.is_none(), "must have stored");
+        self.current = Some(parent);
+        self.depth = self.depth.saturating_sub(1);
+        #[cfg(feature = "alloc")]
+        if matches!(self.swapped.last(), Some(&(depth, _)) if depth == self.depth) {
+            if let Some((_, child_index)) = self.swapped.pop() {
+                let swapped_back =
+                    self.current_node_mut().swap_child_at_index_0_with(child_index);
+                debug_assert!(swapped_back, "must exchange back");
+            }
+        }
+        true
+    }
+
+    /// Whether the cursor is at the root.
+    #[inline]
+    #[must_use]
+    pub fn is_root(&self) -> bool
+    {
+        self.parent_chain.is_none()
+    }
+
+    /// How many times the cursor has moved down from the root, net of moving up.
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> usize
+    {
+        self.depth
+    }
+
+    /// The node at the cursor.
+    #[inline]
+    #[must_use]
+    pub fn current_node(&self) -> &N
+    where L: Borrow<N>
+    {
+        self.current_link().borrow()
+    }
+
+    /// The node at the cursor, for mutating it, including its links, which are its own children
+    /// because only the links of the nodes above it are reversed.
+    #[inline]
+    pub fn current_node_mut(&mut self) -> &mut N
+    {
+        self.current_link_mut().get_mut()
+    }
+
+    /// Move the cursor up to the root, restoring all the links, and relinquish the tree without
+    /// dropping it.
+    #[inline]
+    #[must_use]
+    pub fn into_root(mut self) -> L
+    {
+        while self.move_up() {}
+        self.current_link_take()
+    }
+
+    // The current link is only absent temporarily, within the movements, and after `into_root`
+    // consumes `self`.
+    #[allow(clippy::expect_used)]
+    fn current_link_take(&mut self) -> L
+    {
+        self.current.take().expect("present until consumed")
+    }
+
+    #[allow(clippy::expect_used)]
+    fn current_link(&self) -> &L
+    {
+        self.current.as_ref().expect("present until consumed")
+    }
+
+    #[allow(clippy::expect_used)]
+    fn current_link_mut(&mut self) -> &mut L
+    {
+        self.current.as_mut().expect("present until consumed")
+    }
+}
+
+impl<L, N> Drop for TreeCursor<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        if self.current.is_some() {
+            while self.move_up() {}
+            main_deep_safe_drop(self.current_link_take(), &mut |_: &mut N| {});
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use {
+    crate::ReadableChildren,
+    alloc::{
+        collections::BTreeMap,
+        string::String,
+        vec::Vec,
+    },
+    core::{
+        borrow::Borrow,
+        fmt::{
+            self,
+            Write as _,
+        },
+    },
+};
+
+
+/// Export a tree in the DOT format of Graphviz, without modifying it, with the `debug-viz`
+/// feature, e.g. for inspecting the result of your `DeepSafeDrop` implementations via
+/// `dot -Tpng`.
+///
+/// Each node is labeled by `label` and is numbered in pre-order, where the `root` is `n0`.  A
+/// node that is reached via multiple links, e.g. in a DAG of shared links, is only numbered and
+/// labeled once, but has an edge from each.  Like [`pre_order`](crate::pre_order), this uses an
+/// explicit stack, which grows by the amount of children of each node along the deepest path.
+#[inline]
+pub fn to_dot<L, N, F>(
+    root: &L,
+    label: F,
+) -> String
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+    F: FnMut(&N) -> String,
+{
+    // Writing to a `String` cannot fail.
+    write_dot(root, label).unwrap_or_default()
+}
+
+fn write_dot<L, N, F>(
+    root: &L,
+    mut label: F,
+) -> Result<String, fmt::Error>
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+    F: FnMut(&N) -> String,
+{
+    let mut ids = BTreeMap::new();
+    let mut out = String::from("digraph {\n");
+    let mut stack = Vec::from([(root, 0_usize)]);
+    let _: Option<usize> = ids.insert(addr(root.borrow()), 0);
+
+    while let Some((link, id)) = stack.pop() {
+        let node = link.borrow();
+        writeln!(out, "    n{id} [label=\"{}\"];", escape(&label(node)))?;
+
+        let start = stack.len();
+        for child in node.children_of() {
+            let next_id = ids.len();
+            let child_id = *ids.entry(addr(child.borrow())).or_insert_with(|| {
+                stack.push((child, next_id));
+                next_id
+            });
+            writeln!(out, "    n{id} -> n{child_id};")?;
+        }
+        // So that the first child is popped next.
+        if let Some(children) = stack.get_mut(start ..) {
+            children.reverse();
+        }
+    }
+    out.push('}');
+    Ok(out)
+}
+
+fn addr<N: ?Sized>(node: &N) -> *const ()
+{
+    let ptr: *const N = node;
+    ptr.cast()
+}
+
+/// For a DOT quoted string.
+fn escape(label: &str) -> String
+{
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
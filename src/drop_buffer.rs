@@ -0,0 +1,110 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        forest::Remaining,
+    },
+    alloc::vec::Vec,
+    core::marker::PhantomData,
+};
+
+
+/// Accumulates trees, to be dropped safely, via `deep_safe_drop`, all together in a batch by
+/// [`flush`](Self::flush), with the `alloc` feature.  Any that are still pending are flushed when
+/// the buffer is dropped.
+///
+/// This is for many small trees, e.g. that become garbage at a high rate, whose dropping might
+/// have better throughput when done together than when interleaved with other work, because then
+/// the code and the data of the traversal stay in the CPU caches.  Unlike
+/// [`Forest`](crate::Forest), the buffer remains usable after flushing.  Like with it, the trees
+/// are dropped in the reverse order that they were pushed, and, if dropping one panics, the
+/// remaining pending ones are still dropped safely, during the unwinding.
+#[derive(Debug)]
+pub struct DropBuffer<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    pending: Vec<L>,
+    _node:   PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> DropBuffer<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Make an empty buffer.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self { pending: Vec::new(), _node: PhantomData }
+    }
+
+    /// Make an empty buffer that can hold `capacity` trees without reallocating.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self
+    {
+        Self { pending: Vec::with_capacity(capacity), _node: PhantomData }
+    }
+
+    /// Add a tree, without dropping it yet.  This is amortized O(1).
+    #[inline]
+    pub fn push(
+        &mut self,
+        root: L,
+    )
+    {
+        self.pending.push(root);
+    }
+
+    /// Drop all the pending trees.  The capacity is retained for reuse.
+    #[inline]
+    pub fn flush(&mut self)
+    {
+        let mut rest = Remaining::new(&mut self.pending);
+        rest.drop_all();
+    }
+
+    /// The amount of pending trees.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.pending.len()
+    }
+
+    /// Whether there are no pending trees.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.pending.is_empty()
+    }
+}
+
+impl<L, N> Default for DropBuffer<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<L, N> Drop for DropBuffer<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        self.flush();
+    }
+}
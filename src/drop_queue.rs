@@ -0,0 +1,94 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        SafeDropGuard,
+    },
+    alloc::{
+        boxed::Box,
+        vec::Vec,
+    },
+    core::{
+        any::Any,
+        cell::RefCell,
+    },
+};
+
+
+std::thread_local! {
+    /// The deferred trees of the current thread, each owned by a [`SafeDropGuard`], type-erased
+    /// because a `static` cannot be generic.
+    static QUEUE: RefCell<Vec<Box<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+
+/// Drops, safely, the trees that are deferred, via [`DropQueueGuard::defer`], on the current
+/// thread, during the scope of this guard, when it is dropped, with the `std` feature.
+///
+/// This allows a type's [`Drop`] implementation to defer the dropping of its tree to the end of
+/// the user's scope, without spawning threads.  The deferred trees are dropped in LIFO order,
+/// including any that are deferred while doing so.  Guards may be nested, in which case each only
+/// drops the trees deferred during its own scope.  Trees deferred without any guard are dropped,
+/// still safely, when the next outer guard is dropped, or else when the thread exits.
+#[derive(Debug)]
+#[must_use]
+pub struct DropQueueGuard
+{
+    /// The length of the queue when this guard was made.
+    mark: usize,
+}
+
+impl DropQueueGuard
+{
+    /// Begin a scope of deferring.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self { mark: Self::pending() }
+    }
+
+    /// Defer the dropping of the tree of `link` to the end of the scope of the current innermost
+    /// guard.
+    #[inline]
+    pub fn defer<L, N>(link: L)
+    where
+        L: Link<N> + 'static,
+        N: DeepSafeDrop<L> + ?Sized + 'static,
+    {
+        let guard: Box<dyn Any> = Box::new(SafeDropGuard::new(link));
+        QUEUE.with(|queue| queue.borrow_mut().push(guard));
+    }
+
+    /// The amount of trees that are deferred on the current thread and not dropped yet.
+    #[inline]
+    #[must_use]
+    pub fn pending() -> usize
+    {
+        QUEUE.with(|queue| queue.borrow().len())
+    }
+}
+
+impl Default for DropQueueGuard
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl Drop for DropQueueGuard
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        // One at a time, without the queue borrowed while dropping, because that might defer
+        // more.
+        while let Some(deferred) = QUEUE.with(|queue| {
+            let mut queue = queue.borrow_mut();
+            if queue.len() > self.mark { queue.pop() } else { None }
+        }) {
+            drop(deferred);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use {
+    crate::{
+        DropStatistics,
+        ReadableChildren,
+    },
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+};
+
+
+/// Collect the same metrics as [`deep_safe_drop_stats`](crate::deep_safe_drop_stats) would, but
+/// without modifying nor dropping the tree, with the `alloc` feature.
+///
+/// Useful for profiling a tree before deciding whether to drop it all at once or via
+/// [`deep_safe_drop_partial`](crate::deep_safe_drop_partial).  Like
+/// [`pre_order`](crate::pre_order), this uses an explicit stack, because the link-reversal is
+/// inherently destructive.
+#[inline]
+pub fn dry_run_deep_safe_drop<RootNode, Link, Node>(root: &RootNode) -> DropStatistics
+where
+    RootNode: ReadableChildren<Link> + ?Sized,
+    Link: Borrow<Node>,
+    Node: ReadableChildren<Link> + ?Sized,
+{
+    let mut stats = DropStatistics::default();
+    let mut stack: Vec<(&Link, usize)> = root.children_of().map(|child| (child, 1)).collect();
+
+    while let Some((link, depth)) = stack.pop() {
+        let start = stack.len();
+        let child_depth = depth.saturating_add(1);
+        stack.extend(link.borrow().children_of().map(|child| (child, child_depth)));
+        stats.count_node(stack.len() > start);
+        stats.nodes_dropped = stats.nodes_dropped.saturating_add(1);
+        stats.max_depth_reached = stats.max_depth_reached.max(depth);
+    }
+    stats
+}
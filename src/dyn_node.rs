@@ -0,0 +1,92 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        SetParent,
+        main_deep_safe_drop,
+    },
+    alloc::boxed::Box,
+};
+
+
+/// A [`DeepSafeDrop`] with its link type fixed to `Box<dyn DynDeepSafeDrop>`, with the `alloc`
+/// feature, for trees of heterogeneous node types behind trait objects.
+///
+/// This is implemented for all your node types that implement `DeepSafeDrop<Box<dyn
+/// DynDeepSafeDrop>>`, and `dyn DynDeepSafeDrop` implements that too, by delegating to this, and
+/// so it can be the `Node` type with `Box<dyn DynDeepSafeDrop>` as the `Link` type, without any
+/// wrapper type.
+pub trait DynDeepSafeDrop
+{
+    /// Like [`DeepSafeDrop::take_child_at_index_0`].
+    fn dyn_take_child_at_index_0(&mut self) -> Option<Box<dyn DynDeepSafeDrop>>;
+
+    /// Like [`DeepSafeDrop::set_parent_at_index_0`].
+    fn dyn_set_parent_at_index_0(
+        &mut self,
+        parent: Box<dyn DynDeepSafeDrop>,
+    ) -> SetParent<Box<dyn DynDeepSafeDrop>>;
+
+    /// Like [`DeepSafeDrop::take_next_child_at_pos_index`].
+    fn dyn_take_next_child_at_pos_index(&mut self) -> Option<Box<dyn DynDeepSafeDrop>>;
+}
+
+impl<T> DynDeepSafeDrop for T
+where T: DeepSafeDrop<Box<dyn DynDeepSafeDrop>>
+{
+    #[inline]
+    fn dyn_take_child_at_index_0(&mut self) -> Option<Box<dyn DynDeepSafeDrop>>
+    {
+        self.take_child_at_index_0()
+    }
+
+    #[inline]
+    fn dyn_set_parent_at_index_0(
+        &mut self,
+        parent: Box<dyn DynDeepSafeDrop>,
+    ) -> SetParent<Box<dyn DynDeepSafeDrop>>
+    {
+        self.set_parent_at_index_0(parent)
+    }
+
+    #[inline]
+    fn dyn_take_next_child_at_pos_index(&mut self) -> Option<Box<dyn DynDeepSafeDrop>>
+    {
+        self.take_next_child_at_pos_index()
+    }
+}
+
+impl DeepSafeDrop<Box<Self>> for dyn DynDeepSafeDrop
+{
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.dyn_take_child_at_index_0()
+    }
+
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        self.dyn_set_parent_at_index_0(parent)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.dyn_take_next_child_at_pos_index()
+    }
+}
+
+
+/// Drop a tree of `dyn DynDeepSafeDrop` nodes, safely, like `deep_safe_drop`, with the `alloc`
+/// feature.
+///
+/// The node types do not even need to implement [`Drop`] with `deep_safe_drop`, because this
+/// drops all the nodes, including the root, itself.
+#[inline]
+pub fn drop_dyn(root: Box<dyn DynDeepSafeDrop>)
+{
+    main_deep_safe_drop(root, &mut |_: &mut (dyn DynDeepSafeDrop + 'static)| {});
+}
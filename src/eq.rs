@@ -0,0 +1,89 @@
+use {
+    crate::ReadableChildren,
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+};
+
+
+/// Compare two deep trees for structural equality without stack overflow, and without modifying
+/// them, with the `alloc` feature.
+///
+/// The nodes are compared via [`PartialEq`], which must only compare the node itself but not its
+/// children, because comparing the children is what this does, and because doing so recursively
+/// is what could cause stack overflow.  If your `PartialEq` does compare the children, use
+/// [`deep_safe_eq_by`] instead.
+#[inline]
+#[must_use]
+pub fn deep_safe_eq<L1, L2, N1, N2>(
+    t1: &L1,
+    t2: &L2,
+) -> bool
+where
+    L1: Borrow<N1>,
+    L2: Borrow<N2>,
+    N1: ReadableChildren<L1> + PartialEq<N2> + ?Sized,
+    N2: ReadableChildren<L2> + ?Sized,
+{
+    deep_safe_eq_by(t1, t2, |n1: &N1, n2: &N2| n1 == n2)
+}
+
+
+/// Like [`deep_safe_eq`] but the nodes, without their children, are compared by `eq`.
+///
+/// Both trees are traversed simultaneously, in pre-order, via two explicit stacks, and this
+/// returns `false` as soon as a pair of nodes is not equal or does not have the same amount of
+/// children.
+#[inline]
+#[must_use]
+pub fn deep_safe_eq_by<L1, L2, N1, N2, F>(
+    t1: &L1,
+    t2: &L2,
+    mut eq: F,
+) -> bool
+where
+    L1: Borrow<N1>,
+    L2: Borrow<N2>,
+    N1: ReadableChildren<L1> + ?Sized,
+    N2: ReadableChildren<L2> + ?Sized,
+    F: FnMut(&N1, &N2) -> bool,
+{
+    let mut stack1 = Vec::from([t1]);
+    let mut stack2 = Vec::from([t2]);
+
+    while let (Some(l1), Some(l2)) = (stack1.pop(), stack2.pop()) {
+        let (n1, n2) = (l1.borrow(), l2.borrow());
+        if !eq(n1, n2) {
+            return false;
+        }
+        stack1.extend(n1.children_of());
+        stack2.extend(n2.children_of());
+        if stack1.len() != stack2.len() {
+            return false;
+        }
+    }
+    true
+}
+
+
+/// Whether two deep trees have the same shape, regardless of the nodes themselves, without stack
+/// overflow, and without modifying them, with the `alloc` feature.
+///
+/// This is [`deep_safe_eq_by`] where all nodes are equal, and so it compares only the amount of
+/// children of each pair of nodes, in pre-order, and it returns `false` as soon as they differ,
+/// which takes time proportional to the smaller tree.  Children are compared by their order in
+/// [`ReadableChildren::children_of`], and so, e.g., a node whose only child is its left one is
+/// the same shape as a node whose only child is its right one.
+#[inline]
+#[must_use]
+pub fn are_isomorphic<L1, L2, N1, N2>(
+    t1: &L1,
+    t2: &L2,
+) -> bool
+where
+    L1: Borrow<N1>,
+    L2: Borrow<N2>,
+    N1: ReadableChildren<L1> + ?Sized,
+    N2: ReadableChildren<L2> + ?Sized,
+{
+    deep_safe_eq_by(t1, t2, |_: &N1, _: &N2| true)
+}
@@ -0,0 +1,84 @@
+use {
+    crate::DropStatistics,
+    alloc::{
+        boxed::Box,
+        string::String,
+        vec::Vec,
+    },
+    core::{
+        any::Any,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+
+/// Errors of dropping a tree, with the `alloc` feature.
+///
+/// The depth of a child of the root is 1, as for [`DropStatistics`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DropError
+{
+    /// The dropping of a node panicked, as caught by
+    /// [`try_deep_safe_drop`](crate::try_deep_safe_drop).
+    NodePanickedAt
+    {
+        /// Depth of the node whose dropping panicked.
+        depth:   usize,
+        /// Payload of the panic.
+        payload: Box<dyn Any + Send>,
+    },
+    /// The same node was encountered twice, which can only be caused by incorrect `DeepSafeDrop`
+    /// implementations.  Only detected in debug builds.
+    CycleDetectedAt
+    {
+        /// Address of the node that was encountered again.
+        node_ptr: usize,
+    },
+    /// The budget of nodes given to [`deep_safe_drop_within`](crate::deep_safe_drop_within) was
+    /// used up before the whole tree was dropped.
+    PartialDropAborted
+    {
+        /// Amount of nodes that were not dropped yet.
+        nodes_remaining: usize,
+    },
+}
+
+impl Display for DropError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            Self::NodePanickedAt { depth, payload } => {
+                write!(f, "deep_safe_drop: dropping of node at depth {depth} panicked")?;
+                let msg = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+                if let Some(msg) = msg {
+                    write!(f, ": {msg}")?;
+                }
+                Ok(())
+            },
+            Self::CycleDetectedAt { node_ptr } =>
+                write!(f, "deep_safe_drop: cycle detected at node {node_ptr:#x}"),
+            Self::PartialDropAborted { nodes_remaining } =>
+                write!(f, "deep_safe_drop: stopped with {nodes_remaining} nodes remaining"),
+        }
+    }
+}
+
+impl core::error::Error for DropError {}
+
+
+/// Result of functions that return both the metrics of the traversal, when it succeeded, and all
+/// the errors that occurred, otherwise.
+pub type DropResult = Result<DropStatistics, Vec<DropError>>;
@@ -0,0 +1,64 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+    },
+    alloc::vec::Vec,
+};
+
+
+/// Drop the nodes of the tree of `root` for which `keep` returns `false`, and return the links to
+/// the nodes for which it returns `true`, detached as separate trees, with the `alloc` feature.
+///
+/// This is a pruning from the top: each kept node is returned with its whole subtree, which is
+/// not given to `keep`, and so only the nodes that are reached via dropped nodes are given to it.
+/// The kept trees are returned in pre-order, with the children of each node in the order of
+/// [`DeepSafeDrop::take_next_child_at_any_index`].  Like with
+/// [`deep_safe_drop`](fn@crate::deep_safe_drop), the `root` itself is not given to `keep`, and
+/// its dropping is completed by your [`Drop::drop`] implementation.
+///
+/// This uses an explicit stack, of the links that remain to be given to `keep`, because the
+/// link-reversal cannot detach the kept nodes.  Each dropped node has had all its children taken
+/// before it is dropped, and so is a leaf and is safe from stack overflow.
+#[inline]
+pub fn filter_drop<RootNode, L, Node, F>(
+    root: &mut RootNode,
+    mut keep: F,
+) -> Vec<L>
+where
+    RootNode: DeepSafeDrop<L> + ?Sized,
+    L: Link<Node>,
+    Node: DeepSafeDrop<L> + ?Sized,
+    F: FnMut(&Node) -> bool,
+{
+    let mut kept = Vec::new();
+    let mut stack = Vec::new();
+    push_children(root, &mut stack);
+
+    while let Some(mut link) = stack.pop() {
+        if keep(link.get_mut()) {
+            kept.push(link);
+        }
+        else {
+            push_children(link.get_mut(), &mut stack);
+            drop(link);
+        }
+    }
+    kept
+}
+
+/// Reversed, so that they are popped in order.
+fn push_children<N, L>(
+    node: &mut N,
+    stack: &mut Vec<L>,
+) where
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    let start = stack.len();
+    while let Some(child) = node.take_next_child_at_any_index() {
+        stack.push(child);
+    }
+    if let Some(children) = stack.get_mut(start ..) {
+        children.reverse();
+    }
+}
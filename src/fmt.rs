@@ -0,0 +1,119 @@
+use {
+    crate::ReadableChildren,
+    alloc::{
+        string::String,
+        vec::Vec,
+    },
+    core::{
+        borrow::Borrow,
+        fmt::{
+            self,
+            Formatter,
+            Write,
+        },
+    },
+};
+
+
+/// Format a deep tree without stack overflow, with the `alloc` feature, e.g. for implementing
+/// [`Display`](core::fmt::Display) of your tree type.
+///
+/// Each node is formatted by `format_node` on its own line, in pre-order, indented by two spaces
+/// per level of depth, where the `root` is at depth 0.  Like [`pre_order`](crate::pre_order),
+/// this uses an explicit stack, which grows by the amount of children of each node along the
+/// deepest path.
+///
+/// # Errors
+///
+/// If `format_node` or writing to `f` fails.
+#[inline]
+pub fn deep_safe_fmt<L, N, F>(
+    root: &L,
+    f: &mut Formatter<'_>,
+    mut format_node: F,
+) -> fmt::Result
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+    F: FnMut(&mut Formatter<'_>, &N) -> fmt::Result,
+{
+    let mut stack = Vec::from([(root, 0_usize)]);
+    let mut first = true;
+
+    while let Some((link, depth)) = stack.pop() {
+        let node = link.borrow();
+        if !first {
+            f.write_str("\n")?;
+        }
+        first = false;
+        for _ in 0 .. depth {
+            f.write_str("  ")?;
+        }
+        format_node(f, node)?;
+
+        let start = stack.len();
+        let child_depth = depth.saturating_add(1);
+        stack.extend(node.children_of().map(|child| (child, child_depth)));
+        // So that the first child is popped next.
+        if let Some(children) = stack.get_mut(start ..) {
+            children.reverse();
+        }
+    }
+    Ok(())
+}
+
+
+/// Render a deep tree like the Unix `tree` command does, without stack overflow, with the `alloc`
+/// feature, e.g. for seeing the shape of a tree while debugging your `DeepSafeDrop`
+/// implementations.
+///
+/// The label of the `root` is on the first line, and each other node is on its own line, in
+/// pre-order, after `├── ` or `└── ` connectors that are indented according to its
+/// ancestors.  Each line ends with a newline.  Like [`deep_safe_fmt`], this uses an explicit
+/// stack, along with whether each ancestor of the current node is the last child of its parent.
+///
+/// # Errors
+///
+/// If writing to `out` fails.
+#[inline]
+pub fn print_tree<L, N, W, F>(
+    root: &L,
+    out: &mut W,
+    mut label: F,
+) -> fmt::Result
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+    W: Write + ?Sized,
+    F: FnMut(&N) -> String,
+{
+    let mut stack = Vec::from([(root, 0_usize, true)]);
+    // Whether each ancestor, below the root, of the current node is the last child.
+    let mut lasts = Vec::new();
+
+    while let Some((link, depth, is_last)) = stack.pop() {
+        let node = link.borrow();
+        if depth > 0 {
+            lasts.truncate(depth.saturating_sub(1));
+            for &ancestor_is_last in &lasts {
+                out.write_str(if ancestor_is_last { "    " } else { "│   " })?;
+            }
+            out.write_str(if is_last { "└── " } else { "├── " })?;
+            lasts.push(is_last);
+        }
+        out.write_str(&label(node))?;
+        out.write_char('\n')?;
+
+        let start = stack.len();
+        let child_depth = depth.saturating_add(1);
+        stack.extend(node.children_of().map(|child| (child, child_depth, false)));
+        if let Some(children) = stack.get_mut(start ..) {
+            if let Some((_, _, last)) = children.last_mut() {
+                *last = true;
+            }
+            // So that the first child is popped next.
+            children.reverse();
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        main_deep_safe_drop,
+    },
+    alloc::vec::{
+        self,
+        Vec,
+    },
+    core::marker::PhantomData,
+};
+
+
+/// A collection of separate trees that are all dropped safely, via `deep_safe_drop`, when the
+/// forest is dropped, with the `alloc` feature.  They are dropped in the reverse order that they
+/// were added.
+///
+/// If dropping a root panics, the remaining roots are still dropped safely, during the unwinding,
+/// before the panic continues propagating.  (If another panics during that, the process aborts,
+/// as usual for panics during unwinding.)
+#[derive(Debug)]
+pub struct Forest<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    roots: Vec<L>,
+    _node: PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> Forest<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Make an empty forest.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self { roots: Vec::new(), _node: PhantomData }
+    }
+
+    /// Add a tree.
+    #[inline]
+    pub fn push_root(
+        &mut self,
+        link: L,
+    )
+    {
+        self.roots.push(link);
+    }
+
+    /// Remove the most-recently added tree, without dropping it.
+    #[inline]
+    pub fn pop_root(&mut self) -> Option<L>
+    {
+        self.roots.pop()
+    }
+
+    /// The amount of trees.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.roots.len()
+    }
+
+    /// Whether there are no trees.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.roots.is_empty()
+    }
+}
+
+impl<L, N> Default for Forest<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Yields the roots without dropping them, in the order they were added.
+impl<L, N> IntoIterator for Forest<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    type IntoIter = vec::IntoIter<L>;
+    type Item = L;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter
+    {
+        core::mem::take(&mut self.roots).into_iter()
+    }
+}
+
+impl<L, N> Drop for Forest<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        let mut rest = Remaining::new(&mut self.roots);
+        rest.drop_all();
+    }
+}
+
+
+/// Drops the remaining roots when dropped, which only has any to do during unwinding from a
+/// panic of dropping a root.  Also used by [`DropBuffer`](crate::DropBuffer).
+pub(crate) struct Remaining<'r, L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    roots: &'r mut Vec<L>,
+    _node: PhantomData<fn(&mut N)>,
+}
+
+impl<'r, L, N> Remaining<'r, L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    pub(crate) fn new(roots: &'r mut Vec<L>) -> Self
+    {
+        Self { roots, _node: PhantomData }
+    }
+
+    pub(crate) fn drop_all(&mut self)
+    {
+        while let Some(root) = self.roots.pop() {
+            main_deep_safe_drop(root, &mut |_: &mut N| {});
+        }
+    }
+}
+
+impl<L, N> Drop for Remaining<'_, L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    fn drop(&mut self)
+    {
+        self.drop_all();
+    }
+}
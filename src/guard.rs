@@ -0,0 +1,290 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        main_deep_safe_drop,
+    },
+    core::{
+        borrow::Borrow,
+        marker::PhantomData,
+        ops::{
+            Deref,
+            DerefMut,
+        },
+    },
+};
+
+
+/// Owns a link to a tree, and drops the whole tree safely, via `deep_safe_drop`, when the guard
+/// is dropped.
+///
+/// Useful in code with complex control-flow, where an early `return`, `?`, or panic would
+/// otherwise bypass a manual drop.  The tree's node type does not even need to implement
+/// [`Drop`] with `deep_safe_drop`.  Dereferences to the root node.
+#[derive(Debug)]
+pub struct SafeDropGuard<L, N>(Option<L>, PhantomData<fn(&mut N)>)
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized;
+
+
+impl<L, N> SafeDropGuard<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of `link`.
+    #[inline]
+    #[must_use]
+    pub fn new(link: L) -> Self
+    {
+        Self(Some(link), PhantomData)
+    }
+
+    /// Relinquish ownership of the link, without dropping it.
+    #[inline]
+    #[must_use]
+    pub fn take(mut self) -> L
+    {
+        self.link_take()
+    }
+
+    /// The root node.
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> &N
+    where L: Borrow<N>
+    {
+        self.link().borrow()
+    }
+
+    /// The root node, for mutating it.
+    #[inline]
+    pub fn root_mut(&mut self) -> &mut N
+    {
+        self.link_mut().get_mut()
+    }
+
+    /// Drop the tree now.
+    #[inline]
+    pub fn drop_now(self)
+    {
+        drop(self);
+    }
+
+    // The link is only absent after `take` consumes `self`.
+    #[allow(clippy::expect_used)]
+    fn link_take(&mut self) -> L
+    {
+        self.0.take().expect("present until consumed")
+    }
+
+    #[allow(clippy::expect_used)]
+    fn link(&self) -> &L
+    {
+        self.0.as_ref().expect("present until consumed")
+    }
+
+    #[allow(clippy::expect_used)]
+    fn link_mut(&mut self) -> &mut L
+    {
+        self.0.as_mut().expect("present until consumed")
+    }
+}
+
+impl<L, N> Deref for SafeDropGuard<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    type Target = N;
+
+    #[inline]
+    fn deref(&self) -> &N
+    {
+        self.root()
+    }
+}
+
+impl<L, N> DerefMut for SafeDropGuard<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut N
+    {
+        self.root_mut()
+    }
+}
+
+impl<L, N> Drop for SafeDropGuard<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        if let Some(link) = self.0.take() {
+            main_deep_safe_drop(link, &mut |_: &mut N| {});
+        }
+    }
+}
+
+/// Formats the whole tree via [`deep_safe_fmt`](crate::deep_safe_fmt), with the `alloc` feature,
+/// where each node is formatted by its `Display`, which must only format the node itself but not
+/// its children.
+#[cfg(feature = "alloc")]
+impl<L, N> core::fmt::Display for SafeDropGuard<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + crate::ReadableChildren<L> + core::fmt::Display + ?Sized,
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result
+    {
+        crate::deep_safe_fmt(self.link(), f, |f, node: &N| node.fmt(f))
+    }
+}
+
+
+/// An owned tree, whose node type does not need to implement [`Drop`] with `deep_safe_drop`,
+/// because the whole tree is dropped safely when this is dropped.
+///
+/// This is like [`SafeDropGuard`], but for when it is used as the container of a tree, e.g.
+/// `let tree = Tree::new(root); /* use */ drop(tree);`, rather than as a scope guard.
+/// Dereferences to the root node.
+#[derive(Debug)]
+pub struct Tree<L, N>(SafeDropGuard<L, N>)
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized;
+
+
+impl<L, N> Tree<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of the tree at `root`.
+    #[inline]
+    #[must_use]
+    pub fn new(root: L) -> Self
+    {
+        Self(SafeDropGuard::new(root))
+    }
+
+    /// The root node.
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> &N
+    where L: Borrow<N>
+    {
+        self.0.root()
+    }
+
+    /// The root node, for mutating it.
+    #[inline]
+    pub fn root_mut(&mut self) -> &mut N
+    {
+        self.0.root_mut()
+    }
+
+    /// Relinquish ownership of the tree, without dropping it.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> L
+    {
+        self.0.take()
+    }
+}
+
+impl<L, N> Deref for Tree<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    type Target = N;
+
+    #[inline]
+    fn deref(&self) -> &N
+    {
+        self.root()
+    }
+}
+
+impl<L, N> DerefMut for Tree<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut N
+    {
+        self.root_mut()
+    }
+}
+
+/// Formats the whole tree, the same as for [`SafeDropGuard`], with the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<L, N> core::fmt::Display for Tree<L, N>
+where
+    L: Link<N> + Borrow<N>,
+    N: DeepSafeDrop<L> + crate::ReadableChildren<L> + core::fmt::Display + ?Sized,
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+
+/// A handle for dropping a tree at an explicit point, via [`drop_now`](Self::drop_now), e.g. in
+/// FFI code after signaling completion via a callback, rather than at a scope boundary.
+///
+/// If the handle is dropped without `drop_now`, it still drops the tree safely, like
+/// [`SafeDropGuard`].  [`forget`](Self::forget) relinquishes the link without dropping the tree,
+/// making the caller responsible for it.
+#[derive(Debug)]
+pub struct DropHandle<L, N>(SafeDropGuard<L, N>)
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized;
+
+
+impl<L, N> DropHandle<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of `link`.
+    #[inline]
+    #[must_use]
+    pub fn new(link: L) -> Self
+    {
+        Self(SafeDropGuard::new(link))
+    }
+
+    /// Drop the tree now.
+    #[inline]
+    pub fn drop_now(self)
+    {
+        drop(self);
+    }
+
+    /// Relinquish ownership of the link, without dropping any of the tree.
+    #[inline]
+    #[must_use]
+    pub fn forget(self) -> L
+    {
+        self.0.take()
+    }
+}
@@ -0,0 +1,41 @@
+//! Node types, with `DeepSafeDrop` implementations, for common shapes of trees.
+//!
+//! These are generic over the link type, so that you can use them as, or within, your own node
+//! types with your own link types.
+
+mod btree;
+#[cfg(feature = "alloc")]
+mod btree_map;
+mod doubly_linked;
+#[cfg(feature = "std")]
+mod hash_map;
+mod intrusive;
+mod left_child_right_sibling;
+mod multi_link;
+#[cfg(feature = "alloc")]
+mod nary;
+#[cfg(feature = "alloc")]
+mod rope;
+mod segment_tree;
+mod skip_list;
+mod slots;
+mod trie;
+
+#[cfg(feature = "std")]
+pub use hash_map::HashMapNode;
+pub use {
+    btree::BTreeNode,
+    doubly_linked::DoublyLinkedNode,
+    intrusive::IntrusiveNode,
+    left_child_right_sibling::LeftChildRightSiblingNode,
+    multi_link::MultiLink,
+    segment_tree::SegmentTreeNode,
+    skip_list::SkipListNode,
+    trie::TrieNode,
+};
+#[cfg(feature = "alloc")]
+pub use {
+    btree_map::BTreeMapNode,
+    nary::NaryNode,
+    rope::RopeNode,
+};
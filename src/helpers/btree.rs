@@ -0,0 +1,125 @@
+use {
+    super::slots,
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    core::array,
+};
+
+
+/// A node of a B-tree of order `ORDER`, i.e. with up to `ORDER` children.
+///
+/// This models the structure of the tree only; the storage of keys and values is left to you,
+/// e.g. by using this within your own node type.  The child at index 0 is also reused to link to
+/// the parent.  The other children are supplied by `take_next_child_at_pos_index`, by scanning
+/// them in ascending order.  `ORDER` must be at least 2, which is checked at compile time when a
+/// node is made:
+///
+/// ```compile_fail
+/// let _node = deep_safe_drop::helpers::BTreeNode::<Box<()>, 1>::new();
+/// ```
+#[derive(Debug)]
+pub struct BTreeNode<L, const ORDER: usize>
+{
+    children: [Option<L>; ORDER],
+}
+
+impl<L, const ORDER: usize> BTreeNode<L, ORDER>
+{
+    const ORDER_CHECK: () = assert!(ORDER >= 2, "BTreeNode requires an order of at least 2");
+
+    /// Make a node without any children.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        #[allow(clippy::let_unit_value)] // Forces the evaluation of the check.
+        let () = Self::ORDER_CHECK;
+        Self { children: array::from_fn(|_| None) }
+    }
+
+    /// The links to the children.
+    #[inline]
+    #[must_use]
+    pub const fn children(&self) -> &[Option<L>; ORDER]
+    {
+        &self.children
+    }
+
+    /// The links to the children, for adding or removing some.
+    #[inline]
+    pub fn children_mut(&mut self) -> &mut [Option<L>; ORDER]
+    {
+        &mut self.children
+    }
+}
+
+impl<L, const ORDER: usize> Default for BTreeNode<L, ORDER>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<L, const ORDER: usize> DeepSafeDrop<L> for BTreeNode<L, ORDER>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        slots::set_parent_at_index_0(&mut self.children, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        slots::take_child_at_index_0(&mut self.children)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        slots::take_next_child_at_pos_index(&mut self.children)
+    }
+
+    /// O(1), by the index of the slot, without dropping other children.
+    #[inline]
+    fn take_child_by_index(
+        &mut self,
+        i: usize,
+    ) -> Option<L>
+    {
+        slots::take_child_by_index(&mut self.children, i)
+    }
+
+    /// By the indices of the slots.
+    #[inline]
+    fn swap_child_at_index_0_with(
+        &mut self,
+        i: usize,
+    ) -> bool
+    {
+        slots::swap_child_at_index_0_with(&mut self.children, i)
+    }
+}
+
+impl<L, const ORDER: usize> ReadableChildren<L> for BTreeNode<L, ORDER>
+{
+    type Children<'n>
+        = core::iter::Flatten<core::slice::Iter<'n, Option<L>>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.children.iter().flatten()
+    }
+}
@@ -0,0 +1,105 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    alloc::collections::BTreeMap,
+};
+
+
+/// A node with any amount of children, each with a key, in a [`BTreeMap`], with the `alloc`
+/// feature, e.g. for nodes that have named children like AST nodes or XML elements.
+///
+/// The link at index 0 is a dedicated slot, separate from `children`, so that no key needs to be
+/// reserved as a sentinel for the parent link.  All the `children` are supplied by
+/// `take_next_child_at_pos_index`, in the order of their keys, and so the order of dropping
+/// siblings is deterministic.
+#[derive(Debug)]
+pub struct BTreeMapNode<K, L>
+where K: Ord
+{
+    children: BTreeMap<K, L>,
+    parent:   Option<L>,
+}
+
+impl<K, L> BTreeMapNode<K, L>
+where K: Ord
+{
+    /// Make a node with the given `children`.
+    #[inline]
+    #[must_use]
+    pub const fn new(children: BTreeMap<K, L>) -> Self
+    {
+        Self { children, parent: None }
+    }
+
+    /// The links to the children, by their keys.
+    #[inline]
+    #[must_use]
+    pub const fn children(&self) -> &BTreeMap<K, L>
+    {
+        &self.children
+    }
+
+    /// The links to the children, for adding or removing some.
+    #[inline]
+    pub fn children_mut(&mut self) -> &mut BTreeMap<K, L>
+    {
+        &mut self.children
+    }
+}
+
+impl<K, L> Default for BTreeMapNode<K, L>
+where K: Ord
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new(BTreeMap::new())
+    }
+}
+
+impl<K, L> DeepSafeDrop<L> for BTreeMapNode<K, L>
+where K: Ord
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.parent.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.parent.take()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        self.children.pop_first().map(|(_, child)| child)
+    }
+}
+
+impl<K, L> ReadableChildren<L> for BTreeMapNode<K, L>
+where K: Ord
+{
+    type Children<'n>
+        = alloc::collections::btree_map::Values<'n, K, L>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.children.values()
+    }
+}
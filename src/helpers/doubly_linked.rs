@@ -0,0 +1,111 @@
+use crate::{
+    DeepSafeDrop,
+    ReadableChildren,
+    SetParent,
+};
+
+
+/// A node of a doubly-linked list, whose traversal only proceeds forward, via `next`.
+///
+/// `next` is the only child, at index 0, and so it is also reused to link to the parent.  `prev`
+/// is never followed, nor supplied as a child, and so it remains as it is during traversal, e.g.
+/// the `prev` of the first node to be visited still links back to the root.  That is safe because
+/// it is never followed, but it means that `prev` is dropped along with its node, and so your
+/// link type's [`Drop`] implementation should first [`take_prev`](Self::take_prev) to break the
+/// backward link, in case dropping it would otherwise do something undesired, before calling
+/// `deep_safe_drop`.
+#[derive(Debug)]
+pub struct DoublyLinkedNode<L>
+{
+    prev: Option<L>,
+    next: Option<L>,
+}
+
+impl<L> DoublyLinkedNode<L>
+{
+    /// Make a node with the given links.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        prev: Option<L>,
+        next: Option<L>,
+    ) -> Self
+    {
+        Self { prev, next }
+    }
+
+    /// The backward link.
+    #[inline]
+    #[must_use]
+    pub const fn prev(&self) -> Option<&L>
+    {
+        self.prev.as_ref()
+    }
+
+    /// The forward link.
+    #[inline]
+    #[must_use]
+    pub const fn next(&self) -> Option<&L>
+    {
+        self.next.as_ref()
+    }
+
+    /// Take the backward link and replace it with a non-link.
+    #[inline]
+    pub fn take_prev(&mut self) -> Option<L>
+    {
+        self.prev.take()
+    }
+}
+
+impl<L> Default for DoublyLinkedNode<L>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new(None, None)
+    }
+}
+
+impl<L> DeepSafeDrop<L> for DoublyLinkedNode<L>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.next.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.next.take()
+    }
+
+    /// Always `None`, because `prev` is never followed.
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        None
+    }
+}
+
+impl<L> ReadableChildren<L> for DoublyLinkedNode<L>
+{
+    type Children<'n>
+        = core::option::Iter<'n, L>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.next.iter()
+    }
+}
@@ -0,0 +1,116 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    alloc::vec::Vec,
+    core::hash::Hash,
+    std::collections::HashMap,
+};
+
+
+/// A node with any amount of children, each with a key, in a [`HashMap`], with the `std`
+/// feature.
+///
+/// Like [`BTreeMapNode`](super::BTreeMapNode), but a `HashMap` does not preserve any order, and
+/// so the order of dropping siblings is unspecified.  If you care about a deterministic order of
+/// dropping, use `BTreeMapNode` instead.
+///
+/// Removing an arbitrary entry from a `HashMap` is only possible by draining it, which drops all
+/// the entries that are not consumed, and so, when the first child is taken by the traversal, all
+/// the children are moved out of the map into a separate `Vec` from which they are then supplied
+/// one at a time.  Their keys are dropped at that time.
+#[derive(Debug)]
+pub struct HashMapNode<K, L>
+where K: Hash + Eq
+{
+    children: HashMap<K, L>,
+    parent:   Option<L>,
+    drained:  Vec<L>,
+}
+
+impl<K, L> HashMapNode<K, L>
+where K: Hash + Eq
+{
+    /// Make a node with the given `children`.
+    #[inline]
+    #[must_use]
+    pub fn new(children: HashMap<K, L>) -> Self
+    {
+        Self { children, parent: None, drained: Vec::new() }
+    }
+
+    /// The links to the children, by their keys.
+    #[inline]
+    #[must_use]
+    pub fn children(&self) -> &HashMap<K, L>
+    {
+        &self.children
+    }
+
+    /// The links to the children, for adding or removing some.
+    #[inline]
+    pub fn children_mut(&mut self) -> &mut HashMap<K, L>
+    {
+        &mut self.children
+    }
+}
+
+impl<K, L> Default for HashMapNode<K, L>
+where K: Hash + Eq
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new(HashMap::new())
+    }
+}
+
+impl<K, L> DeepSafeDrop<L> for HashMapNode<K, L>
+where K: Hash + Eq
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.parent.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.parent.take()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        if self.drained.is_empty() {
+            self.drained.extend(self.children.drain().map(|(_, child)| child));
+        }
+        self.drained.pop()
+    }
+}
+
+impl<K, L> ReadableChildren<L> for HashMapNode<K, L>
+where K: Hash + Eq
+{
+    type Children<'n>
+        =
+        core::iter::Chain<std::collections::hash_map::Values<'n, K, L>, core::slice::Iter<'n, L>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.children.values().chain(&self.drained)
+    }
+}
@@ -0,0 +1,153 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        SetParent,
+    },
+    core::ptr::NonNull,
+};
+
+
+/// A node of an intrusive singly-linked list, whose link to the next node is a raw [`NonNull`]
+/// pointer, e.g. for embedded or kernel lists whose nodes are not owned via `Box`.
+///
+/// This crate has no `unsafe` code, and so dereferencing the pointers must be done by your link
+/// type, which converts to and from `NonNull<Self>` and implements [`Link`](crate::Link) by
+/// dereferencing its pointer.  That `unsafe` link layer is all that you must verify, because the
+/// traversal, done by [`deep_safe_drop`](fn@crate::deep_safe_drop), remains safe code.  `next` is
+/// the only child, at index 0, and so it is also reused to link to the parent.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     core::ptr::NonNull,
+///     deep_safe_drop::{Link, deep_safe_drop, helpers::IntrusiveNode},
+/// };
+///
+/// type Node = IntrusiveNode<u32>;
+///
+/// /// Owns its node, which was allocated via `Box`, like a kernel's node from its own pool.
+/// struct IntrusiveLink(NonNull<Node>);
+///
+/// impl IntrusiveLink
+/// {
+///     fn new(node: Node) -> Self
+///     {
+///         Self(NonNull::from(Box::leak(Box::new(node))))
+///     }
+/// }
+///
+/// impl Link<Node> for IntrusiveLink
+/// {
+///     fn get_mut(&mut self) -> &mut Node
+///     {
+///         // SAFETY: Only this link points to the node, while it exists.
+///         unsafe { self.0.as_mut() }
+///     }
+/// }
+///
+/// impl From<NonNull<Node>> for IntrusiveLink
+/// {
+///     fn from(ptr: NonNull<Node>) -> Self
+///     {
+///         Self(ptr)
+///     }
+/// }
+///
+/// impl From<IntrusiveLink> for NonNull<Node>
+/// {
+///     fn from(link: IntrusiveLink) -> Self
+///     {
+///         // The pointer takes over the ownership of the node.
+///         core::mem::ManuallyDrop::new(link).0
+///     }
+/// }
+///
+/// impl Drop for IntrusiveLink
+/// {
+///     fn drop(&mut self)
+///     {
+///         deep_safe_drop::<Node, Self, Node>(self.get_mut());
+///         // SAFETY: The node was allocated via `Box`, and only this link points to it.
+///         drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+///     }
+/// }
+///
+/// let list = (0 .. 1_000_000).fold(IntrusiveLink::new(Node::new(None, 0)), |next, data| {
+///     IntrusiveLink::new(Node::new(Some(next.into()), data))
+/// });
+/// drop(list);
+/// ```
+#[derive(Debug)]
+pub struct IntrusiveNode<D>
+{
+    next: Option<NonNull<Self>>,
+    data: D,
+}
+
+impl<D> IntrusiveNode<D>
+{
+    /// Make a node with the given `next` and `data`.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        next: Option<NonNull<Self>>,
+        data: D,
+    ) -> Self
+    {
+        Self { next, data }
+    }
+
+    /// The pointer to the next node.
+    #[inline]
+    #[must_use]
+    pub const fn next(&self) -> Option<NonNull<Self>>
+    {
+        self.next
+    }
+
+    /// The data.
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> &D
+    {
+        &self.data
+    }
+
+    /// The data, for changing it.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut D
+    {
+        &mut self.data
+    }
+}
+
+impl<D, L> DeepSafeDrop<L> for IntrusiveNode<D>
+where
+    L: From<NonNull<Self>>,
+    NonNull<Self>: From<L>,
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.next.replace(parent.into()) {
+            Some(child0) => SetParent::YesReplacedChild { child0: child0.into() },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.next.take().map(L::from)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        None
+    }
+}
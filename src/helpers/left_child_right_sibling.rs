@@ -0,0 +1,139 @@
+use crate::{
+    DeepSafeDrop,
+    ReadableChildren,
+    SetParent,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    Link,
+    cata,
+    helpers::NaryNode,
+};
+
+
+/// A node of the left-child right-sibling representation of a tree of any amount of children per
+/// node, as a binary tree, i.e. with only two links per node, and without the `Vec` of each
+/// [`NaryNode`](crate::helpers::NaryNode).
+///
+/// `first_child` is the child at index 0, and so it is also reused to link to the parent, and
+/// `next_sibling` is the child at index 1.  And so the traversal handles the siblings as if each
+/// were a child of the previous, which is still safe from stack overflow however many there are,
+/// and each node is dropped after its next siblings.
+#[doc(alias = "LCRSNode")]
+#[derive(Debug)]
+pub struct LeftChildRightSiblingNode<L>
+{
+    first_child:  Option<L>,
+    next_sibling: Option<L>,
+    data:         u64,
+}
+
+impl<L> LeftChildRightSiblingNode<L>
+{
+    /// Make a node with the given links and data.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        first_child: Option<L>,
+        next_sibling: Option<L>,
+        data: u64,
+    ) -> Self
+    {
+        Self { first_child, next_sibling, data }
+    }
+
+    /// Convert a tree of [`NaryNode`]s into a tree of these, with the `alloc` feature, without
+    /// stack overflow, keeping the order of the children.
+    ///
+    /// The `root` is consumed, via [`cata`], and each converted node, other than the returned
+    /// root, is given to `make_link` to make the link to it, e.g. `Box::new`.  The `data` of each
+    /// is 0, because `NaryNode` has none.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn from_nary<NL, F>(
+        root: NL,
+        mut make_link: F,
+    ) -> Self
+    where
+        NL: Link<NaryNode<NL>>,
+        F: FnMut(Self) -> L,
+    {
+        // The `children` of an `NaryNode` are given from last to first, and so folding them
+        // links each to its next sibling and results in the first.
+        cata::<NL, NaryNode<NL>, Self, _>(root, |_, children| {
+            let first_child = children.into_iter().fold(None, |next_sibling, mut child| {
+                child.next_sibling = next_sibling;
+                Some(make_link(child))
+            });
+            Self::new(first_child, None, 0)
+        })
+    }
+
+    /// The first child.
+    #[inline]
+    #[must_use]
+    pub const fn first_child(&self) -> Option<&L>
+    {
+        self.first_child.as_ref()
+    }
+
+    /// The next sibling.
+    #[inline]
+    #[must_use]
+    pub const fn next_sibling(&self) -> Option<&L>
+    {
+        self.next_sibling.as_ref()
+    }
+
+    /// The data.
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> u64
+    {
+        self.data
+    }
+}
+
+impl<L> DeepSafeDrop<L> for LeftChildRightSiblingNode<L>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.first_child.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.first_child.take()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        self.next_sibling.take()
+    }
+}
+
+/// The children of the binary representation, i.e. the first child and the next sibling.
+impl<L> ReadableChildren<L> for LeftChildRightSiblingNode<L>
+{
+    type Children<'n>
+        = core::iter::Chain<core::option::Iter<'n, L>, core::option::Iter<'n, L>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.first_child.iter().chain(&self.next_sibling)
+    }
+}
@@ -0,0 +1,132 @@
+use {
+    super::slots,
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    core::array,
+};
+
+
+/// A node with up to `N` children, in an array of links within the node, e.g. a binary tree when
+/// `N` is 2, or a quadtree when 4, without any allocation other than that of your link type.
+///
+/// The link at index 0 is also reused to link to the parent.  The other links are supplied by
+/// `take_next_child_at_pos_index`, by scanning them in ascending order.  `N` must be at least 1,
+/// for storing the parent, which is checked at compile time when a node is made:
+///
+/// ```compile_fail
+/// let _node = deep_safe_drop::helpers::MultiLink::<Box<()>, 0>::new();
+/// ```
+#[derive(Debug)]
+pub struct MultiLink<L, const N: usize>
+{
+    links: [Option<L>; N],
+}
+
+impl<L, const N: usize> MultiLink<L, N>
+{
+    const N_CHECK: () =
+        assert!(N >= 1, "MultiLink requires at least one link slot for parent storage");
+
+    /// Make a node without any children.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        #[allow(clippy::let_unit_value)] // Forces the evaluation of the check.
+        let () = Self::N_CHECK;
+        Self { links: array::from_fn(|_| None) }
+    }
+
+    /// The links to the children.
+    #[inline]
+    #[must_use]
+    pub const fn links(&self) -> &[Option<L>; N]
+    {
+        &self.links
+    }
+
+    /// The links to the children, for adding or removing some.
+    #[inline]
+    pub fn links_mut(&mut self) -> &mut [Option<L>; N]
+    {
+        &mut self.links
+    }
+}
+
+impl<L, const N: usize> Default for MultiLink<L, N>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<L, const N: usize> DeepSafeDrop<L> for MultiLink<L, N>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        slots::set_parent_at_index_0(&mut self.links, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        slots::take_child_at_index_0(&mut self.links)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        slots::take_next_child_at_pos_index(&mut self.links)
+    }
+
+    /// Exact, by counting the occupied slots.
+    #[inline]
+    fn child_count_hint(&self) -> Option<usize>
+    {
+        slots::child_count(&self.links)
+    }
+
+    /// O(1), by the index of the slot, without dropping other children.
+    #[inline]
+    fn take_child_by_index(
+        &mut self,
+        i: usize,
+    ) -> Option<L>
+    {
+        slots::take_child_by_index(&mut self.links, i)
+    }
+
+    /// By the indices of the slots.
+    #[inline]
+    fn swap_child_at_index_0_with(
+        &mut self,
+        i: usize,
+    ) -> bool
+    {
+        slots::swap_child_at_index_0_with(&mut self.links, i)
+    }
+}
+
+impl<L, const N: usize> ReadableChildren<L> for MultiLink<L, N>
+{
+    type Children<'n>
+        = core::iter::Flatten<core::slice::Iter<'n, Option<L>>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.links.iter().flatten()
+    }
+}
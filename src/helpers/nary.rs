@@ -0,0 +1,141 @@
+use {
+    crate::{
+        DeepSafeClone,
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    alloc::vec::Vec,
+};
+
+
+/// A node with any amount of children, with the `alloc` feature.
+///
+/// The link at index 0 is a dedicated slot, separate from `children`, because removing the first
+/// element of a `Vec` would shift the next child into index 0, which would then be mistaken for
+/// the parent link.  All the `children` are supplied by `take_next_child_at_pos_index`, from last
+/// to first, because `Vec::pop` is O(1) whereas `Vec::remove(0)` would be O(n) in the number of
+/// children.  If you need the children to be dropped from first to last, for wide nodes, consider
+/// a `VecDeque` in your own node type instead.
+///
+/// With the `serde` feature, this is serialized as only its `children`, because the link at index
+/// 0 is only used during traversal.  Like all of serde, serializing and deserializing are
+/// recursive, and so are limited to the depths that the stack allows, but dropping a deserialized
+/// tree is still safe.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "L: serde::Deserialize<'de>")))]
+pub struct NaryNode<L>
+{
+    children: Vec<L>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    parent:   Option<L>,
+}
+
+impl<L> NaryNode<L>
+{
+    /// Make a node with the given `children`.
+    #[inline]
+    #[must_use]
+    pub fn new(children: Vec<L>) -> Self
+    {
+        Self { children, parent: None }
+    }
+
+    /// The links to the children.
+    #[inline]
+    #[must_use]
+    pub fn children(&self) -> &[L]
+    {
+        &self.children
+    }
+
+    /// The links to the children, for adding or removing some.
+    #[inline]
+    pub fn children_mut(&mut self) -> &mut Vec<L>
+    {
+        &mut self.children
+    }
+}
+
+impl<L> Default for NaryNode<L>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new(Vec::new())
+    }
+}
+
+/// Appends to the `children`, e.g. for [`ana`](crate::ana).
+impl<L> Extend<L> for NaryNode<L>
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = L>>(
+        &mut self,
+        iter: I,
+    )
+    {
+        self.children.extend(iter);
+    }
+}
+
+impl<L> DeepSafeDrop<L> for NaryNode<L>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.parent.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.parent.take()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        self.children.pop()
+    }
+}
+
+impl<L> ReadableChildren<L> for NaryNode<L>
+{
+    type Children<'n>
+        = core::slice::Iter<'n, L>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.children.iter()
+    }
+}
+
+impl<L> DeepSafeClone<L> for NaryNode<L>
+{
+    #[inline]
+    fn clone_without_children(&self) -> Self
+    {
+        Self::default()
+    }
+
+    #[inline]
+    fn attach_children(
+        &mut self,
+        children: impl Iterator<Item = L>,
+    )
+    {
+        self.children.extend(children);
+    }
+}
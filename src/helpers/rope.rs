@@ -0,0 +1,128 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    alloc::string::String,
+    core::borrow::Borrow,
+};
+
+
+/// A node of a rope, i.e. a string that is a binary tree of concatenations of its pieces, with
+/// the `alloc` feature.
+///
+/// Ropes built by repeated concatenation, e.g. by appending each edit of a text editor, are as
+/// deep as the amount of concatenations, which would overflow the stack when dropped recursively.
+///
+/// `left` is the child at index 0, and so it is also reused to link to the parent, and `right` is
+/// the child at index 1.  These are `Option`s only so that they can be taken by the traversal,
+/// and both are `Some` otherwise.  A `Leaf` has no children.
+#[derive(Debug)]
+#[allow(clippy::exhaustive_enums)] // These are all the kinds of nodes of a rope.
+pub enum RopeNode<L>
+{
+    /// A piece of the string.
+    Leaf(String),
+    /// The concatenation of two ropes.
+    Node
+    {
+        /// The first part.
+        left:      Option<L>,
+        /// The second part.
+        right:     Option<L>,
+        /// The length, in bytes, of the whole concatenation.
+        total_len: usize,
+    },
+}
+
+impl<L> RopeNode<L>
+{
+    /// The concatenation of `left` and then `right`.
+    #[inline]
+    #[must_use]
+    pub fn concat(
+        left: L,
+        right: L,
+    ) -> Self
+    where
+        L: Borrow<Self>,
+    {
+        let total_len = left.borrow().len().saturating_add(right.borrow().len());
+        Self::Node { left: Some(left), right: Some(right), total_len }
+    }
+
+    /// The length, in bytes, of the string.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        match self {
+            Self::Leaf(piece) => piece.len(),
+            Self::Node { total_len, .. } => *total_len,
+        }
+    }
+
+    /// Whether the string is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+}
+
+impl<L> DeepSafeDrop<L> for RopeNode<L>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self {
+            Self::Leaf(_) => SetParent::No { returned_parent: parent },
+            Self::Node { left, .. } => match left.replace(parent) {
+                Some(child0) => SetParent::YesReplacedChild { child0 },
+                None => SetParent::Yes,
+            },
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        match self {
+            Self::Leaf(_) => None,
+            Self::Node { left, .. } => left.take(),
+        }
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        match self {
+            Self::Leaf(_) => None,
+            Self::Node { right, .. } => right.take(),
+        }
+    }
+}
+
+impl<L> ReadableChildren<L> for RopeNode<L>
+{
+    type Children<'n>
+        = core::iter::Chain<core::option::IntoIter<&'n L>, core::option::IntoIter<&'n L>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        let (left, right) = match self {
+            Self::Leaf(_) => (None, None),
+            Self::Node { left, right, .. } => (left.as_ref(), right.as_ref()),
+        };
+        left.into_iter().chain(right)
+    }
+}
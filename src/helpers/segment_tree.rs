@@ -0,0 +1,164 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        ReadableChildren,
+        SetParent,
+    },
+    core::borrow::Borrow,
+};
+
+
+/// A node of a sparse segment tree, of sums over the range of indices `lo .. hi`, whose nodes are
+/// only allocated for the ranges that contain updated indices.
+///
+/// The depth is at most the logarithm of the size of the whole range, e.g. 64 for all of
+/// `usize`, which would not overflow the stack, but many such trees, or nodes with large data,
+/// might still.  `left` is the child at index 0, and so it is also reused to link to the parent,
+/// and `right` is the child at index 1.
+#[derive(Debug)]
+pub struct SegmentTreeNode<L>
+{
+    left:  Option<L>,
+    right: Option<L>,
+    value: i64,
+    lo:    usize,
+    hi:    usize,
+}
+
+impl<L> SegmentTreeNode<L>
+{
+    /// Make a node, without children, for the range `lo .. hi`, whose sum is 0.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        lo: usize,
+        hi: usize,
+    ) -> Self
+    {
+        Self { left: None, right: None, value: 0, lo, hi }
+    }
+
+    /// The sum over the whole range of this node.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> i64
+    {
+        self.value
+    }
+
+    /// The range of this node.
+    #[inline]
+    #[must_use]
+    pub const fn range(&self) -> core::ops::Range<usize>
+    {
+        self.lo .. self.hi
+    }
+
+    /// The middle of the range, which is where the range of `right` starts.
+    fn mid(&self) -> usize
+    {
+        self.lo.saturating_add(self.hi.saturating_sub(self.lo).div_euclid(2))
+    }
+
+    /// Add `delta` at `index`, allocating the nodes for the ranges that contain it when they do
+    /// not exist yet, whose links are made by `make_link`, e.g. `Box::new`.  Does nothing when
+    /// `index` is not within the range.
+    #[inline]
+    pub fn add<F>(
+        &mut self,
+        index: usize,
+        delta: i64,
+        mut make_link: F,
+    ) where
+        L: Link<Self>,
+        F: FnMut(Self) -> L,
+    {
+        if !self.range().contains(&index) {
+            return;
+        }
+        let mut node = self;
+        loop {
+            node.value = node.value.saturating_add(delta);
+            if node.hi.saturating_sub(node.lo) <= 1 {
+                break;
+            }
+            let mid = node.mid();
+            let (child, lo, hi) = if index < mid {
+                (&mut node.left, node.lo, mid)
+            }
+            else {
+                (&mut node.right, mid, node.hi)
+            };
+            node = child.get_or_insert_with(|| make_link(Self::new(lo, hi))).get_mut();
+        }
+    }
+
+    /// The sum over the indices of `lo .. hi` that are within the range of this node.
+    ///
+    /// This recurses, but only as deep as the tree, which is at most the logarithm of the size of
+    /// the range.
+    #[inline]
+    #[must_use]
+    pub fn sum(
+        &self,
+        lo: usize,
+        hi: usize,
+    ) -> i64
+    where
+        L: Borrow<Self>,
+    {
+        if hi <= self.lo || self.hi <= lo {
+            0
+        }
+        else if lo <= self.lo && self.hi <= hi {
+            self.value
+        }
+        else {
+            let sum_of = |child: &Option<L>| child.as_ref().map_or(0, |c| c.borrow().sum(lo, hi));
+            sum_of(&self.left).saturating_add(sum_of(&self.right))
+        }
+    }
+}
+
+impl<L> DeepSafeDrop<L> for SegmentTreeNode<L>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        match self.left.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.left.take()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        self.right.take()
+    }
+}
+
+impl<L> ReadableChildren<L> for SegmentTreeNode<L>
+{
+    type Children<'n>
+        = core::iter::Chain<core::option::Iter<'n, L>, core::option::Iter<'n, L>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
@@ -0,0 +1,123 @@
+use {
+    super::slots,
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+};
+
+
+/// A node of a skip list, with a tower of `LEVELS` forward links, whose traversal only proceeds
+/// via the level-0 link.
+///
+/// `forward[0]` is the only child, at index 0, and so it is also reused to link to the parent.
+/// The upper-level links, of `forward[1 ..]`, skip ahead to nodes that are also reachable via
+/// level 0, i.e. they alias, and so they are never followed, nor supplied as children, because
+/// that would reach those nodes twice.  With such aliasing, your link type must be shared, e.g.
+/// [`SharedNode`](crate::SharedNode), and then a node is only descended into by the traversal
+/// when its level-0 link is the only reference to it.  And so, before the list is dropped, the
+/// upper-level links of all its nodes should be nil'ed by
+/// [`clear_upper_levels`](Self::clear_upper_levels), e.g. in the [`Drop::drop`] of the type that
+/// owns the list, walking level 0, before calling `deep_safe_drop`.  Otherwise, each node that is
+/// still referred to by an upper-level link is dropped when that is, nested within the dropping
+/// of the node that had that link, which could overflow the stack.
+///
+/// When `LEVELS` is 0, there is no storage for links, and so the node is always a leaf.
+#[derive(Debug)]
+pub struct SkipListNode<L, const LEVELS: usize>
+{
+    forward: [Option<L>; LEVELS],
+    key:     i64,
+}
+
+impl<L, const LEVELS: usize> SkipListNode<L, LEVELS>
+{
+    /// Make a node with the given key, and without any links.
+    #[inline]
+    #[must_use]
+    pub fn new(key: i64) -> Self
+    {
+        Self { forward: core::array::from_fn(|_| None), key }
+    }
+
+    /// The key.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> i64
+    {
+        self.key
+    }
+
+    /// The forward link at `level`, if any.
+    #[inline]
+    #[must_use]
+    pub fn forward(
+        &self,
+        level: usize,
+    ) -> Option<&L>
+    {
+        self.forward.get(level).and_then(Option::as_ref)
+    }
+
+    /// The slot of the forward link at `level`, for storing or taking it, unless `level` is not
+    /// less than `LEVELS`.
+    #[inline]
+    pub fn forward_mut(
+        &mut self,
+        level: usize,
+    ) -> Option<&mut Option<L>>
+    {
+        self.forward.get_mut(level)
+    }
+
+    /// Replace the upper-level links with non-links, which drops them, but not the level-0 link.
+    #[inline]
+    pub fn clear_upper_levels(&mut self)
+    {
+        for link in self.forward.iter_mut().skip(1) {
+            drop(link.take());
+        }
+    }
+}
+
+impl<L, const LEVELS: usize> DeepSafeDrop<L> for SkipListNode<L, LEVELS>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        slots::set_parent_at_index_0(&mut self.forward, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        slots::take_child_at_index_0(&mut self.forward)
+    }
+
+    /// Always `None`, unlike for the other slots, because the upper-level links are never
+    /// followed.
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        None
+    }
+}
+
+impl<L, const LEVELS: usize> ReadableChildren<L> for SkipListNode<L, LEVELS>
+{
+    type Children<'n>
+        = core::option::IntoIter<&'n L>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.forward(0).into_iter()
+    }
+}
@@ -0,0 +1,55 @@
+//! The `DeepSafeDrop` methods for nodes whose children are in a slice of slots, where the first
+//! slot is index 0, and the rest are scanned in ascending order.
+
+use crate::SetParent;
+
+
+pub(super) fn set_parent_at_index_0<L>(
+    slots: &mut [Option<L>],
+    parent: L,
+) -> SetParent<L>
+{
+    match slots.first_mut() {
+        Some(slot) => match slot.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        },
+        None => SetParent::No { returned_parent: parent },
+    }
+}
+
+pub(super) fn take_child_at_index_0<L>(slots: &mut [Option<L>]) -> Option<L>
+{
+    slots.first_mut().and_then(Option::take)
+}
+
+pub(super) fn take_next_child_at_pos_index<L>(slots: &mut [Option<L>]) -> Option<L>
+{
+    slots.iter_mut().skip(1).find_map(Option::take)
+}
+
+pub(super) fn take_child_by_index<L>(
+    slots: &mut [Option<L>],
+    i: usize,
+) -> Option<L>
+{
+    slots.get_mut(i).and_then(Option::take)
+}
+
+pub(super) fn swap_child_at_index_0_with<L>(
+    slots: &mut [Option<L>],
+    i: usize,
+) -> bool
+{
+    let has = i < slots.len();
+    if has {
+        slots.swap(0, i);
+    }
+    has
+}
+
+#[allow(clippy::unnecessary_wraps)] // For returning directly as `child_count_hint`.
+pub(super) fn child_count<L>(slots: &[Option<L>]) -> Option<usize>
+{
+    Some(slots.iter().flatten().count())
+}
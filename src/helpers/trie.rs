@@ -0,0 +1,131 @@
+use {
+    super::slots,
+    crate::{
+        DeepSafeDrop,
+        ReadableChildren,
+        SetParent,
+    },
+    core::array,
+};
+
+
+/// A node of a trie with an alphabet of `ALPHA` symbols, with a child for each.
+///
+/// The child of symbol 0 is at index 0 and so it is also reused to link to the parent.  The
+/// children of the other symbols are supplied by `take_next_child_at_pos_index`, by scanning them
+/// in ascending order.  `ALPHA` must be at least 1, which is checked at compile time when a node
+/// is made:
+///
+/// ```compile_fail
+/// let _node = deep_safe_drop::helpers::TrieNode::<Box<()>, 0>::new(false);
+/// ```
+#[derive(Debug)]
+pub struct TrieNode<L, const ALPHA: usize>
+{
+    children:    [Option<L>; ALPHA],
+    is_terminal: bool,
+}
+
+impl<L, const ALPHA: usize> TrieNode<L, ALPHA>
+{
+    const ALPHA_CHECK: () =
+        assert!(ALPHA >= 1, "TrieNode requires at least one slot for the parent link");
+
+    /// Make a node without any children.
+    #[inline]
+    #[must_use]
+    pub fn new(is_terminal: bool) -> Self
+    {
+        #[allow(clippy::let_unit_value)] // Forces the evaluation of the check.
+        let () = Self::ALPHA_CHECK;
+        Self { children: array::from_fn(|_| None), is_terminal }
+    }
+
+    /// The links to the children, indexed by symbol.
+    #[inline]
+    #[must_use]
+    pub const fn children(&self) -> &[Option<L>; ALPHA]
+    {
+        &self.children
+    }
+
+    /// The links to the children, indexed by symbol, for adding or removing some.
+    #[inline]
+    pub fn children_mut(&mut self) -> &mut [Option<L>; ALPHA]
+    {
+        &mut self.children
+    }
+
+    /// Whether a key ends at this node.
+    #[inline]
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool
+    {
+        self.is_terminal
+    }
+
+    /// Set whether a key ends at this node.
+    #[inline]
+    pub fn set_terminal(
+        &mut self,
+        is_terminal: bool,
+    )
+    {
+        self.is_terminal = is_terminal;
+    }
+}
+
+impl<L, const ALPHA: usize> Default for TrieNode<L, ALPHA>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new(false)
+    }
+}
+
+impl<L, const ALPHA: usize> DeepSafeDrop<L> for TrieNode<L, ALPHA>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        slots::set_parent_at_index_0(&mut self.children, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        slots::take_child_at_index_0(&mut self.children)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        slots::take_next_child_at_pos_index(&mut self.children)
+    }
+
+    /// Exact, by counting the occupied slots.
+    #[inline]
+    fn child_count_hint(&self) -> Option<usize>
+    {
+        slots::child_count(&self.children)
+    }
+}
+
+impl<L, const ALPHA: usize> ReadableChildren<L> for TrieNode<L, ALPHA>
+{
+    type Children<'n>
+        = core::iter::Flatten<core::slice::Iter<'n, Option<L>>>
+    where
+        Self: 'n,
+        L: 'n;
+
+    #[inline]
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.children.iter().flatten()
+    }
+}
@@ -0,0 +1,114 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+    },
+    alloc::collections::VecDeque,
+    core::{
+        iter::FusedIterator,
+        marker::PhantomData,
+    },
+};
+
+
+/// Consume a tree, supplying the links to its nodes in level-order (breadth-first), without
+/// dropping them, with the `alloc` feature.
+///
+/// Each supplied link is to a node from which all its children have already been extracted, via
+/// [`DeepSafeDrop::take_next_child_at_any_index`], i.e. a leaf, and so dropping it is safe from
+/// stack overflow.  The children of a node are supplied in the order that it gives them.
+///
+/// Unlike [`into_post_order`](crate::into_post_order), this allocates a queue of the extracted
+/// children that have not been supplied yet, which grows to the maximum width of the tree.  For
+/// very wide trees, this can be as much as the amount of nodes.  If the iterator is dropped
+/// before it is exhausted, it drops the remaining nodes in the same way.
+#[inline]
+pub fn into_level_order<L, N>(root: L) -> BreadthFirstIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    BreadthFirstIter { queue: VecDeque::from([root]), _node: PhantomData }
+}
+
+
+/// Drop a tree level by level (breadth-first), instead of by the depth-first link-reversal of
+/// [`deep_safe_drop`](fn@crate::deep_safe_drop), with the `alloc` feature, e.g. so that the
+/// memory of the upper levels is released first.
+///
+/// Each node has all its children extracted, via
+/// [`DeepSafeDrop::take_next_child_at_any_index`], into a queue, and then it is dropped, which is
+/// safe from stack overflow because it is a leaf by then.  Returns the greatest amount of links
+/// that were in the queue at once, which is about the maximum width of the tree.
+///
+/// Unlike `deep_safe_drop`, which does not allocate and needs no memory beyond the tree itself,
+/// this allocates the queue, and so it is not preferable for reducing the peak memory usage.
+/// The queue is grown by [`DeepSafeDrop::child_count_hint`] before the children of a node are
+/// extracted, when your node type gives it.
+#[inline]
+pub fn deep_safe_drop_bfs<L, N>(root: L) -> usize
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    let mut queue = VecDeque::from([root]);
+    let mut peak = queue.len();
+
+    while let Some(mut node) = queue.pop_front() {
+        if let Some(count) = node.get_mut().child_count_hint() {
+            queue.reserve(count);
+        }
+        queue.extend(core::iter::from_fn(|| node.get_mut().take_next_child_at_any_index()));
+        peak = peak.max(queue.len());
+        drop(node);
+    }
+    peak
+}
+
+
+/// Iterator returned by [`into_level_order`].
+#[derive(Debug)]
+pub struct BreadthFirstIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    queue: VecDeque<L>,
+    _node: PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> Iterator for BreadthFirstIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<L>
+    {
+        let mut node = self.queue.pop_front()?;
+        let children = core::iter::from_fn(|| node.get_mut().take_next_child_at_any_index());
+        self.queue.extend(children);
+        Some(node)
+    }
+}
+
+impl<L, N> FusedIterator for BreadthFirstIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+}
+
+impl<L, N> Drop for BreadthFirstIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        self.for_each(drop);
+    }
+}
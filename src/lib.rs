@@ -1,6 +1,286 @@
 #![cfg_attr(not(windows), doc = include_str!("../README.md"))]
 #![cfg_attr(windows, doc = include_str!("..\\README.md"))]
 #![no_std]
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(all(debug_assertions, feature = "alloc"))]
+use alloc::collections::BTreeSet;
+
+#[cfg(feature = "derive")]
+pub use deep_safe_drop_derive::DeepSafeDrop;
+
+pub mod helpers;
+pub mod v2;
+
+#[cfg(feature = "std")]
+mod background;
+#[cfg(feature = "std")]
+pub use background::BackgroundDropQueue;
+
+mod bounded_stack;
+pub use bounded_stack::bounded_stack_drop;
+
+mod checked;
+pub use checked::CheckedNode;
+
+#[cfg(feature = "std")]
+mod cooperative;
+#[cfg(feature = "std")]
+pub use cooperative::CooperativeDrop;
+
+#[cfg(feature = "alloc")]
+mod clone;
+#[cfg(feature = "alloc")]
+pub use clone::{
+    DeepSafeClone,
+    deep_safe_clone,
+};
+
+mod cursor;
+pub use cursor::TreeCursor;
+
+#[cfg(feature = "alloc")]
+mod drop_buffer;
+#[cfg(feature = "alloc")]
+pub use drop_buffer::DropBuffer;
+
+#[cfg(feature = "debug-viz")]
+mod dot;
+#[cfg(feature = "debug-viz")]
+pub use dot::to_dot;
+
+#[cfg(feature = "std")]
+mod drop_queue;
+#[cfg(feature = "std")]
+pub use drop_queue::DropQueueGuard;
+
+#[cfg(feature = "alloc")]
+mod dry_run;
+#[cfg(feature = "alloc")]
+pub use dry_run::dry_run_deep_safe_drop;
+
+#[cfg(feature = "alloc")]
+mod dyn_node;
+#[cfg(feature = "alloc")]
+pub use dyn_node::{
+    DynDeepSafeDrop,
+    drop_dyn,
+};
+
+#[cfg(feature = "alloc")]
+mod error;
+#[cfg(feature = "alloc")]
+pub use error::{
+    DropError,
+    DropResult,
+};
+
+#[cfg(feature = "alloc")]
+mod eq;
+#[cfg(feature = "alloc")]
+pub use eq::{
+    are_isomorphic,
+    deep_safe_eq,
+    deep_safe_eq_by,
+};
+
+#[cfg(feature = "alloc")]
+mod fmt;
+#[cfg(feature = "alloc")]
+pub use fmt::{
+    deep_safe_fmt,
+    print_tree,
+};
+
+#[cfg(feature = "alloc")]
+mod filter;
+#[cfg(feature = "alloc")]
+pub use filter::filter_drop;
+
+#[cfg(feature = "alloc")]
+mod forest;
+#[cfg(feature = "alloc")]
+pub use forest::Forest;
+
+mod macros;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use alloc::boxed::Box as __Box;
+
+#[doc(hidden)]
+pub use macros::{
+    deep_safe_drop_as as __deep_safe_drop_as,
+    link_type_of as __link_type_of,
+};
+
+mod guard;
+pub use guard::{
+    DropHandle,
+    SafeDropGuard,
+    Tree,
+};
+
+#[cfg(feature = "alloc")]
+mod level_order;
+#[cfg(feature = "alloc")]
+pub use level_order::{
+    BreadthFirstIter,
+    deep_safe_drop_bfs,
+    into_level_order,
+};
+
+#[cfg(feature = "alloc")]
+mod morphism;
+#[cfg(feature = "alloc")]
+pub use morphism::{
+    ana,
+    cata,
+};
+
+mod order;
+#[cfg(feature = "alloc")]
+pub use order::RightToLeft;
+pub use order::{
+    DropOrder,
+    LeftToRight,
+    deep_safe_drop_ordered,
+};
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::deep_safe_drop_parallel;
+
+mod partial;
+#[cfg(feature = "alloc")]
+pub use partial::deep_safe_drop_within;
+pub use partial::{
+    deep_safe_drop_n,
+    deep_safe_drop_partial,
+};
+
+#[cfg(feature = "alloc")]
+mod pinned;
+#[cfg(feature = "alloc")]
+pub use pinned::{
+    PinnedLink,
+    drop_pinned,
+};
+
+mod post_order;
+#[cfg(feature = "alloc")]
+pub use post_order::topological_order;
+pub use post_order::{
+    PostOrderIter,
+    into_post_order,
+};
+
+#[cfg(feature = "profile")]
+mod profile;
+#[cfg(feature = "profile")]
+pub use profile::{
+    Percentile,
+    ProfiledDrop,
+    StepType,
+    deep_safe_drop_profiled,
+};
+
+mod real_time;
+pub use real_time::{
+    RealTimeDrop,
+    RealTimeDropStatus,
+};
+
+#[cfg(feature = "testing")]
+mod random_tree;
+#[cfg(feature = "testing")]
+pub use random_tree::{
+    RandomTreeBuilder,
+    count_nodes,
+};
+
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(feature = "alloc")]
+pub use shared::{
+    ArcLink,
+    RcLink,
+    SharedNode,
+    WeakLink,
+};
+
+mod simple;
+pub use simple::SimpleDeepSafeDrop;
+
+mod split;
+pub use split::{
+    ParentSlot,
+    SiblingLinks,
+};
+
+mod subtree;
+pub use subtree::{
+    attach_subtree_at_index_0,
+    detach_subtree_at_index,
+    detach_subtree_at_index_0,
+};
+
+mod stack_size;
+pub use stack_size::{
+    required_stack_bytes_per_level,
+    would_overflow_default_stack,
+};
+
+mod stats;
+pub use stats::{
+    DropStatistics,
+    deep_safe_drop_stats,
+};
+
+#[cfg(feature = "alloc")]
+mod tracer;
+#[cfg(feature = "alloc")]
+pub use tracer::{
+    DropTracer,
+    deep_safe_drop_traced,
+};
+
+#[cfg(feature = "alloc")]
+mod two_phase;
+#[cfg(feature = "alloc")]
+pub use two_phase::{
+    phase1_extract_all,
+    phase2_drop_vec,
+};
+
+#[cfg(feature = "std")]
+mod try_drop;
+#[cfg(feature = "std")]
+pub use try_drop::try_deep_safe_drop;
+
+mod unordered;
+pub use unordered::deep_safe_drop_unordered;
+
+#[cfg(feature = "alloc")]
+mod visit;
+#[cfg(feature = "alloc")]
+pub use visit::{
+    TreeVisitor,
+    visit_tree,
+};
+
+#[cfg(feature = "alloc")]
+mod pre_order;
+#[cfg(feature = "alloc")]
+pub use pre_order::{
+    PreOrderIter,
+    pre_order,
+};
 
 
 /// Implement this for your tree node type, with `Link` as your tree link type that references or
@@ -16,7 +296,10 @@ pub trait DeepSafeDrop<Link>
     /// Take the next child and replace the link to it with a non-link, if the current state of
     /// `self` has another child that has not been supplied yet.  This may return the child at
     /// index 0 when there is one.
-    #[inline]
+    // Always, because this is called for every node and is only two calls, which should not add a
+    // call frame of their own.
+    #[allow(clippy::inline_always)]
+    #[inline(always)]
     fn take_next_child_at_any_index(&mut self) -> Option<Link>
     {
         self.take_child_at_index_0().or_else(|| self.take_next_child_at_pos_index())
@@ -24,6 +307,10 @@ pub trait DeepSafeDrop<Link>
 
     /// Take the child at index 0 and replace the link to it with a given replacement that links
     /// to the parent of `self`.
+    ///
+    /// When `self` has no storage for links, e.g. when your node type is zero-sized, this must
+    /// always return [`SetParent::No`], and then each such node is supplied as a leaf
+    /// immediately, which is correct but involves no working back up.
     fn set_parent_at_index_0(
         &mut self,
         parent: Link,
@@ -32,17 +319,121 @@ pub trait DeepSafeDrop<Link>
     /// Take the child at index 0 and replace the link to it with a non-link.
     fn take_child_at_index_0(&mut self) -> Option<Link>;
 
+    /// Store `child` as the child at index 0.  Return the child that it replaced, if any, or
+    /// return `child` back if it could not be stored because `self` has no links.
+    ///
+    /// This should only be called when `self` has no child at index 0, so that none is replaced.
+    /// The default implementation does this via `set_parent_at_index_0`, because, outside of a
+    /// traversal, storing a parent is the same as storing a child.
+    #[inline]
+    #[must_use]
+    fn store_child_at_index_0(
+        &mut self,
+        child: Link,
+    ) -> Option<Link>
+    {
+        match self.set_parent_at_index_0(child) {
+            SetParent::YesReplacedChild { child0 } => Some(child0),
+            SetParent::Yes => None,
+            SetParent::No { returned_parent } => Some(returned_parent),
+        }
+    }
+
     /// Take the next child at an index greater than or equal to 1 and replace the link to it with
     /// a non-link, if the current state of `self` has another child at those indices that has not
     /// been supplied yet.  This must not return the child at index 0 when there is one, because
     /// that is reused to link to the parent.
     fn take_next_child_at_pos_index(&mut self) -> Option<Link>;
+
+    /// Take the child at index `i` and replace the link to it with a non-link, if any, e.g. for
+    /// [`detach_subtree_at_index`].
+    ///
+    /// The default implementation is O(`i`): for 0, it calls `take_child_at_index_0`, otherwise
+    /// it calls `take_next_child_at_pos_index` `i` times and returns the last result, which
+    /// takes and drops the intermediate children.  And so, with it, the positive indices are in
+    /// the order that your node supplies its children, of the children that have not been taken
+    /// yet.  You should override this with an O(1) implementation, without dropping other
+    /// children, when your node type supports random access to its children.
+    #[inline]
+    fn take_child_by_index(
+        &mut self,
+        i: usize,
+    ) -> Option<Link>
+    {
+        match i.checked_sub(1) {
+            None => self.take_child_at_index_0(),
+            Some(skip) => {
+                for _ in 0 .. skip {
+                    drop(self.take_next_child_at_pos_index());
+                }
+                self.take_next_child_at_pos_index()
+            },
+        }
+    }
+
+    /// Exchange the child at index 0 with the child at index `i`, including when either is
+    /// absent, e.g. for [`TreeCursor::move_down`].  Return whether they were exchanged, which
+    /// they are not when `self` has no link at either index.  Exchanging them again must restore
+    /// them.
+    ///
+    /// The default implementation exchanges nothing and returns `false`.  You should override
+    /// this when your node type supports random access to its children, as for
+    /// `take_child_by_index`.
+    #[inline]
+    #[must_use]
+    fn swap_child_at_index_0_with(
+        &mut self,
+        _i: usize,
+    ) -> bool
+    {
+        false
+    }
+
+    /// The exact amount of children that `self` currently has, if it is known cheaply, outside of
+    /// a traversal, e.g. for reserving capacity.
+    ///
+    /// The default implementation returns `None`, meaning unknown.  You should override this
+    /// when your node type knows its amount of children, e.g. `Some(0)` for a leaf, or `Some(2)`
+    /// for a binary node that has both children, but it must never be wrong when it is `Some`.
+    #[inline]
+    #[must_use]
+    fn child_count_hint(&self) -> Option<usize>
+    {
+        None
+    }
 }
 
 
 /// Result of [`DeepSafeDrop::set_parent_at_index_0`].
+///
+/// This must be used, because the `YesReplacedChild` and `No` variants own a link, of a child or
+/// of the parent, which would otherwise be lost:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use deep_safe_drop::{DeepSafeDrop, drop_boxed};
+///
+/// #[derive(DeepSafeDrop)]
+/// struct List
+/// {
+///     #[child(index = 0)]
+///     next: Option<Box<Self>>,
+/// }
+/// # impl Drop for List
+/// # {
+/// #     fn drop(&mut self)
+/// #     {
+/// #         drop_boxed(self);
+/// #     }
+/// # }
+///
+/// let mut node = List { next: Some(Box::new(List { next: None })) };
+/// node.set_parent_at_index_0(Box::new(List { next: None }));
+/// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::exhaustive_enums)]
+#[must_use = "check which variant was returned and handle the returned child or parent link"]
 pub enum SetParent<Link>
 {
     /// There was a child at index 0 and it was replaced by the parent.
@@ -64,6 +455,24 @@ pub enum SetParent<Link>
     },
 }
 
+/// Implement this for your tree node type, in addition to [`DeepSafeDrop`], to enable the
+/// traversals that do not modify the tree, e.g. [`pre_order`].
+///
+/// Those traversals read a node through a link via [`Borrow`](core::borrow::Borrow), which is
+/// already implemented for `Box<Node>` and for when the `Link` type is the same as the `Node`
+/// type.
+pub trait ReadableChildren<Link>
+{
+    /// The iterator of [`children_of`](Self::children_of).
+    type Children<'n>: Iterator<Item = &'n Link>
+    where
+        Self: 'n,
+        Link: 'n;
+
+    /// The links to the current children of `self`, in order, without taking them.
+    fn children_of(&self) -> Self::Children<'_>;
+}
+
 /// Implement this for your tree link type, with `Node` as your tree node type.
 ///
 /// The `Node` type may be the same as the `Self` type, when possible, which might be convenient.
@@ -74,22 +483,142 @@ pub trait Link<Node: ?Sized>
     fn get_mut(&mut self) -> &mut Node;
 }
 
+/// `Box` is the most common link type, so this is provided for it, with the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<Node: ?Sized> Link<Node> for alloc::boxed::Box<Node>
+{
+    #[inline]
+    fn get_mut(&mut self) -> &mut Node
+    {
+        self
+    }
+}
+
+
+/// Detects when the same node is encountered twice, which would otherwise cause an infinite loop,
+/// which can only be caused by incorrect `DeepSafeDrop` implementations.  Only does so in debug
+/// builds with the `alloc` feature, otherwise this is a complete no-op.
+#[derive(Debug, Default)]
+struct CycleCheck
+{
+    /// The addresses of the nodes that have been encountered and not yet dropped.
+    #[cfg(all(debug_assertions, feature = "alloc"))]
+    visited:     BTreeSet<*const ()>,
+    /// The address of the node at which a cycle was detected, just before panicking.
+    #[cfg(all(debug_assertions, feature = "alloc"))]
+    detected_at: Option<*const ()>,
+}
+
+impl CycleCheck
+{
+    #[inline]
+    #[cfg_attr(not(all(debug_assertions, feature = "alloc")), allow(clippy::unused_self))]
+    fn enter<L, N>(
+        &mut self,
+        #[cfg_attr(not(all(debug_assertions, feature = "alloc")), allow(unused_variables))]
+        link: &mut L,
+    ) where
+        L: Link<N>,
+        N: ?Sized,
+    {
+        #[cfg(all(debug_assertions, feature = "alloc"))]
+        if let Some(addr) = Self::addr(link) {
+            if !self.visited.insert(addr) {
+                self.detected(addr);
+            }
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(not(all(debug_assertions, feature = "alloc")), allow(clippy::unused_self))]
+    fn leave<L, N>(
+        &mut self,
+        #[cfg_attr(not(all(debug_assertions, feature = "alloc")), allow(unused_variables))]
+        link: &mut L,
+    ) where
+        L: Link<N>,
+        N: ?Sized,
+    {
+        #[cfg(all(debug_assertions, feature = "alloc"))]
+        if let Some(addr) = Self::addr(link) {
+            let _: bool = self.visited.remove(&addr);
+        }
+    }
+
+    /// The address of the node at which a cycle was detected, if the detection panicked, e.g. for
+    /// reporting it after catching that.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[cfg_attr(not(all(debug_assertions, feature = "alloc")), allow(clippy::unused_self))]
+    fn detected_at(&self) -> Option<usize>
+    {
+        #[cfg(all(debug_assertions, feature = "alloc"))]
+        #[allow(clippy::as_conversions)] // Only an opaque ID.
+        {
+            self.detected_at.map(|addr| addr as usize)
+        }
+        #[cfg(not(all(debug_assertions, feature = "alloc")))]
+        {
+            None
+        }
+    }
+
+    /// Cold, so that the checking in the hot loop of the traversal is not burdened by the
+    /// formatting of this.
+    #[cfg(all(debug_assertions, feature = "alloc"))]
+    #[cold]
+    #[inline(never)]
+    fn detected(
+        &mut self,
+        addr: *const (),
+    ) -> !
+    {
+        self.detected_at = Some(addr);
+        // The address is what identifies the node.  This is the failure of an assertion.
+        #[allow(clippy::pointer_format, clippy::panic)]
+        {
+            panic!(
+                "deep_safe_drop: cycle detected at node {addr:p} — check your \
+                 set_parent_at_index_0 impl"
+            );
+        }
+    }
+
+    /// Nodes that are stored inline within their links (e.g. when the link type is the node
+    /// type) move along with their links and so do not have a stable address, and zero-sized
+    /// nodes all have the same address, so these are not checked.
+    #[cfg(all(debug_assertions, feature = "alloc"))]
+    fn addr<L, N>(link: &mut L) -> Option<*const ()>
+    where
+        L: Link<N>,
+        N: ?Sized,
+    {
+        let link_start: *const L = link;
+        let link_start = link_start.cast::<u8>();
+        let link_end = link_start.wrapping_add(size_of::<L>());
+        let node = link.get_mut();
+        let is_zero_sized = size_of_val(node) == 0;
+        let node_ptr: *const N = node;
+        let node_start = node_ptr.cast::<u8>();
+        let is_inline = link_start <= node_start && node_start < link_end;
+        (!is_zero_sized && !is_inline).then(|| node_start.cast())
+    }
+}
+
 
 /// Exists to do these `debug_assert`s when a node can be immediately dropped because it's a leaf.
-fn drop_leaf<L, N>(mut link: L)
-where
-    L: Link<N>,
-    N: DeepSafeDrop<L> + ?Sized,
+#[inline]
+fn debug_assert_leaf<L, N>(node: &mut N)
+where N: DeepSafeDrop<L> + ?Sized
 {
-    let node = link.get_mut();
     debug_assert!(node.take_next_child_at_any_index().is_none(), "must be leaf");
     debug_assert!(node.take_child_at_index_0().is_none(), "must be leaf");
     debug_assert!(node.take_next_child_at_pos_index().is_none(), "must be leaf");
-    drop(link);
 }
 
 
 /// A node's link at index 0 is reused as the parent link.
+#[inline]
 fn take_parent<L, N>(node: &mut N) -> Option<L>
 where N: DeepSafeDrop<L> + ?Sized
 {
@@ -99,92 +628,270 @@ where N: DeepSafeDrop<L> + ?Sized
 }
 
 
-/// Return the nearest ancestor that has a next child if any, or the root ancestor even when it
-/// does not have a next child.  Drop any ancestors in the upwards path that do not have a child
-/// but that do have a parent.
-fn take_ancestor_next_child<L, N>(parent: L) -> (L, Option<L>)
-where
+/// The main algorithm, which is the traversal of [`PostOrderIter`] with each node dropped as soon
+/// as it is supplied as a leaf.
+fn main_deep_safe_drop<L, N, F>(
+    top: L,
+    visitor: &mut F,
+) where
     L: Link<N>,
     N: DeepSafeDrop<L> + ?Sized,
+    F: FnMut(&mut N),
 {
-    let mut ancestor = parent;
-    loop {
-        if let Some(next_child) = ancestor.get_mut().take_next_child_at_pos_index() {
-            break (ancestor, Some(next_child));
-        }
-        else if let Some(grandancestor) = take_parent(ancestor.get_mut()) {
-            drop_leaf(ancestor); // `ancestor` is now a leaf node so drop it here.
-            ancestor = grandancestor;
-        }
-        else {
-            break (ancestor, None);
-        }
+    for mut leaf in into_post_order(top) {
+        visitor(leaf.get_mut());
+        // `leaf` is now mutated to no longer have any children, so, when dropping it is
+        // completed, by the implicit compiler-added code, recursion into children cannot occur
+        // and so stack overflow cannot occur.
+        drop(leaf);
     }
 }
 
 
-/// The main algorithm.
-fn main_deep_safe_drop<L, N>(top: L)
+/// To be called from your [`Drop::drop`] implementations, to ensure that stack overflow is
+/// avoided.
+///
+/// The `RootNode` type may be different than the primary `Node` type, when possible, which might
+/// be convenient.  Or, they can be the same.
+///
+/// # Borrowed data
+///
+/// Your node types may have lifetime parameters, e.g. for data borrowed from outside the tree,
+/// which must outlive the tree like with any other type, and which is not accessed nor affected
+/// by this.  Such data may be borrowed from the nodes of another tree, but not from the nodes of
+/// the same tree, because the borrow checker prevents moving the borrowed-from nodes into the
+/// tree:
+///
+/// ```compile_fail
+/// use deep_safe_drop::{SimpleDeepSafeDrop, drop_boxed};
+///
+/// struct Node<'a>
+/// {
+///     data: &'a str,
+///     text: String,
+///     next: Option<Box<Self>>,
+/// }
+/// # impl SimpleDeepSafeDrop for Node<'_>
+/// # {
+/// #     type Link = Box<Self>;
+/// #     fn swap_with_parent(&mut self, parent: Box<Self>) -> Option<Box<Self>>
+/// #     {
+/// #         self.next.replace(parent)
+/// #     }
+/// #     fn take_sole_child(&mut self) -> Option<Box<Self>>
+/// #     {
+/// #         self.next.take()
+/// #     }
+/// # }
+/// # impl Drop for Node<'_>
+/// # {
+/// #     fn drop(&mut self)
+/// #     {
+/// #         drop_boxed(self);
+/// #     }
+/// # }
+///
+/// let leaf = Box::new(Node { data: "", text: "leaf".to_owned(), next: None });
+/// let root = Node { data: &leaf.text, text: "root".to_owned(), next: Some(leaf) };
+/// ```
+///
+/// Only with `unsafe` link types, e.g. of raw pointers, could a node refer to the data of other
+/// nodes of the same tree.  That is only sound when the referenced nodes are dropped after the
+/// referencing ones, and, since nodes are dropped in post-order, that is only when they are
+/// ancestors of them, but not descendants nor siblings.
+///
+/// # Async-signal safety
+///
+/// The traversal itself does not allocate, lock, nor call any other functions that are not
+/// async-signal-safe, and so this may be called from a signal handler, when all of these hold:
+///
+/// - Your `DeepSafeDrop` and `Link` methods, and the `Drop` of your node and link types, are
+///   async-signal-safe.  E.g., they must not free memory via `free`, as dropping a `Box` does,
+///   nor lock a `Mutex`, but they may update atomics, or nodes in a preallocated arena.
+///
+/// - The `log` and `tracing` features are not enabled, because their events could do anything.
+///
+/// - Either it is not a debug build or the `alloc` feature is not enabled, because then cycle
+///   detection allocates to record the addresses of the nodes.  (Nodes that are stored inline
+///   within their links are not recorded, and so do not cause this.)
+///
+/// # Panics
+///
+/// In debug builds with the `alloc` feature, if the same node is encountered twice, which can
+/// only be caused by incorrect `DeepSafeDrop` implementations and which would otherwise cause an
+/// infinite loop.  Nodes that are stored inline within their links, and zero-sized nodes, are not
+/// checked, because they do not have stable addresses.
+#[inline]
+pub fn deep_safe_drop<RootNode, Link, Node>(root: &mut RootNode)
 where
-    L: Link<N>,
-    N: DeepSafeDrop<L> + ?Sized,
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
 {
-    let mut parent = top;
-
-    if let Some(mut cur) = parent.get_mut().take_next_child_at_any_index() {
-        loop {
-            match cur.get_mut().set_parent_at_index_0(parent) {
-                SetParent::YesReplacedChild { child0 } => {
-                    parent = cur;
-                    cur = child0;
-                    continue;
-                },
-                SetParent::Yes => {
-                    let next = cur.get_mut().take_next_child_at_pos_index();
-                    parent = cur;
-                    if let Some(child) = next {
-                        cur = child;
-                        continue;
-                    }
-                },
-                SetParent::No { returned_parent } => {
-                    parent = returned_parent;
-                    drop_leaf(cur); // `cur` is now a leaf node so drop it here.
-                },
-            }
+    deep_safe_drop_with(root, |_: &mut Node| {});
+}
 
-            let (ancestor, ancestor_child) = take_ancestor_next_child(parent);
-            parent = ancestor;
 
-            if let Some(ancestor_child) = ancestor_child {
-                cur = ancestor_child;
-            }
-            else {
-                // Done. `parent` is now `top` which is now mutated to no longer have any
-                // children, so, when dropping it is completed, by the implicit compiler-added
-                // code, after this function returns, recursion into children cannot occur and so
-                // stack overflow cannot occur.
-                drop_leaf(parent);
-                break;
-            }
+/// Like [`deep_safe_drop`](fn@deep_safe_drop) but also calls `visitor` on every node, just before
+/// it is dropped, after all its children have been extracted and dropped, i.e. in post-order.
+///
+/// The `visitor` is not called on the `root` itself, because its dropping is completed by your
+/// [`Drop::drop`] implementation after this returns.  This is useful for patterns like
+/// decrementing a reference count, unregistering from a global registry, or emitting telemetry
+/// for each dropped node.
+///
+/// # Panics
+///
+/// If `visitor` panics, the panic propagates and `visitor` is not called on any of the remaining
+/// nodes.  The remaining nodes are still dropped, during the unwinding, by the dropping of the
+/// links that this function holds at that point, which is still safe from stack overflow when
+/// your [`Drop::drop`] implementations use `deep_safe_drop`, because the link-reversal state of
+/// the tree is a valid tree of those links.
+#[inline]
+pub fn deep_safe_drop_with<RootNode, Link, Node, F>(
+    root: &mut RootNode,
+    mut visitor: F,
+) where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+    F: FnMut(&mut Node),
+{
+    let mut next_child = root.take_next_child_at_any_index();
+
+    // Only when there is something to traverse, because this is also called when dropping each
+    // leaf node.
+    #[cfg(feature = "tracing")]
+    let _span = next_child.is_some().then(|| tracing::debug_span!("deep_safe_drop").entered());
+
+    while let Some(child) = next_child {
+        main_deep_safe_drop(child, &mut visitor);
+        next_child = root.take_next_child_at_any_index();
+    }
+}
+
+
+/// Like [`deep_safe_drop_with`] but calls `hook` on every node when it is first reached, before
+/// any of its children are extracted, i.e. in pre-order (parent before children), instead of in
+/// post-order.
+///
+/// The `hook` sees the node with all its children intact, e.g. for inspecting the full node.
+/// Like with `deep_safe_drop_with`, the `hook` is not called on the `root` itself.
+///
+/// # Panics
+///
+/// If `hook` panics, the same as for the `visitor` of [`deep_safe_drop_with`].
+#[inline]
+pub fn deep_safe_drop_with_pre_hook<RootNode, Link, Node, F>(
+    root: &mut RootNode,
+    mut hook: F,
+) where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+    F: FnMut(&mut Node),
+{
+    while let Some(child) = root.take_next_child_at_any_index() {
+        PostOrderIter::new(child, &mut hook).for_each(drop);
+    }
+}
+
+
+/// Like [`deep_safe_drop_with`] but also gives the `visitor` the depth of each node, where the
+/// depth of a child of the `root` is 1, like for [`DropStatistics`].
+///
+/// # Panics
+///
+/// If `visitor` panics, the same as for [`deep_safe_drop_with`].
+#[inline]
+pub fn deep_safe_drop_with_depth<RootNode, Link, Node, F>(
+    root: &mut RootNode,
+    mut visitor: F,
+) where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+    F: FnMut(&mut Node, usize),
+{
+    while let Some(child) = root.take_next_child_at_any_index() {
+        let mut leaves = into_post_order(child);
+        while let Some(mut leaf) = leaves.next() {
+            visitor(leaf.get_mut(), leaves.leaf_depth());
         }
     }
 }
 
 
-/// To be called from your [`Drop::drop`] implementations, to ensure that stack overflow is
-/// avoided.
+/// Like [`deep_safe_drop_with`] but also threads an accumulator through the calls of `f` on every
+/// node, in post-order, and returns its final value.
 ///
-/// The `RootNode` type may be different than the primary `Node` type, when possible, which might
-/// be convenient.  Or, they can be the same.
+/// `f` is given each node just before it is dropped, and so it may move data out of it, e.g. via
+/// [`core::mem::take`], to aggregate, e.g. to sum sizes or to collect IDs, without a separate
+/// traversal.  Like with `deep_safe_drop_with`, `f` is not called on the `root` itself.
+///
+/// # Panics
+///
+/// If `f` panics, the same as for the `visitor` of [`deep_safe_drop_with`], and the accumulator
+/// is lost.
 #[inline]
-pub fn deep_safe_drop<RootNode, Link, Node>(root: &mut RootNode)
+pub fn fold_drop<RootNode, Link, Node, T, F>(
+    root: &mut RootNode,
+    init: T,
+    mut f: F,
+) -> T
 where
     RootNode: DeepSafeDrop<Link> + ?Sized,
     Link: crate::Link<Node>,
     Node: DeepSafeDrop<Link> + ?Sized,
+    F: FnMut(T, &mut Node) -> T,
 {
-    while let Some(next_child) = root.take_next_child_at_any_index() {
-        main_deep_safe_drop(next_child);
+    let mut acc = init;
+    while let Some(child) = root.take_next_child_at_any_index() {
+        for mut leaf in into_post_order(child) {
+            acc = f(acc, leaf.get_mut());
+        }
     }
+    acc
+}
+
+
+/// Like [`deep_safe_drop_with`] but also gives the `visitor` a shared reference to `ctx`, the
+/// same for every node, in post-order.
+///
+/// The context is not cloned nor moved, e.g. it can be an `AtomicUsize` for counting the drops,
+/// a `Mutex` of a registry for unregistering the nodes, or a `Path` for logging.  For mutable
+/// context, use interior mutability, e.g. a `RefCell`.  Like with `deep_safe_drop_with`, the
+/// `visitor` is not called on the `root` itself.
+///
+/// # Panics
+///
+/// If `visitor` panics, the same as for [`deep_safe_drop_with`].
+#[inline]
+pub fn deep_safe_drop_with_context<RootNode, Link, Node, C, F>(
+    root: &mut RootNode,
+    ctx: &C,
+    mut visitor: F,
+) where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+    C: ?Sized,
+    F: FnMut(&Node, &C),
+{
+    deep_safe_drop_with(root, |node: &mut Node| visitor(node, ctx));
+}
+
+
+/// Like [`deep_safe_drop`](fn@deep_safe_drop) but for the most common case of `Box<Node>` as the
+/// link type, which enables the type parameters to be inferred.
+///
+/// [`Link`] is already implemented for `Box<Node>`, with the `alloc` feature, so you only need to
+/// implement `DeepSafeDrop<Box<Self>>` for your node type and call `drop_boxed(self)` in your
+/// [`Drop::drop`] implementation.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn drop_boxed<Node>(root: &mut Node)
+where Node: DeepSafeDrop<alloc::boxed::Box<Node>> + ?Sized
+{
+    deep_safe_drop::<Node, alloc::boxed::Box<Node>, Node>(root);
 }
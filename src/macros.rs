@@ -0,0 +1,202 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        deep_safe_drop,
+    },
+    core::marker::PhantomData,
+};
+
+
+/// Expands to a complete [`Drop::drop`] method that drops safely, via `deep_safe_drop`, the
+/// given fields of `self`, which must be of `Option<Link>` types.
+///
+/// The fields before the `;` are roots: each one is taken and its tree is dropped by a separate
+/// traversal, which is useful for a type that owns trees but is not a node itself.  The fields
+/// after the `;` are the children of `self` as a node, which are all handled by the same
+/// traversal, via `deep_safe_drop(self)`, and so they must be the fields that the
+/// [`DeepSafeDrop`] implementation of `Self` supplies.  Their types must be the same, which is
+/// the `Link` type that is inferred for that.  Either part may be omitted.
+///
+/// ```
+/// use deep_safe_drop::DeepSafeDrop;
+///
+/// #[derive(DeepSafeDrop)]
+/// struct BinaryTree
+/// {
+///     #[child(index = 0)]
+///     left:  Option<Box<Self>>,
+///     #[child(index = 1)]
+///     right: Option<Box<Self>>,
+/// }
+///
+/// impl Drop for BinaryTree
+/// {
+///     deep_safe_drop::deep_safe_drop!(; self.left, self.right);
+/// }
+///
+/// struct Trees
+/// {
+///     first:  Option<Box<BinaryTree>>,
+///     second: Option<Box<BinaryTree>>,
+/// }
+///
+/// impl Drop for Trees
+/// {
+///     deep_safe_drop::deep_safe_drop!(self.first, self.second);
+/// }
+/// # let _trees = Trees { first: None, second: None };
+/// ```
+#[macro_export]
+macro_rules! deep_safe_drop {
+    ($(self.$root:ident),* $(; $(self.$child:ident),+)?) => {
+        fn drop(&mut self)
+        {
+            $(
+                if let ::core::option::Option::Some(link) =
+                    ::core::option::Option::take(&mut self.$root)
+                {
+                    ::core::iter::Iterator::for_each(
+                        $crate::into_post_order(link),
+                        ::core::mem::drop,
+                    );
+                }
+            )*
+            $(
+                let [link_type, ..] = [$($crate::__link_type_of(&self.$child)),+];
+                $crate::__deep_safe_drop_as(self, link_type);
+            )?
+        }
+    };
+}
+
+
+/// Exists so that the macro can infer the `Link` type from a field's type.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn link_type_of<L>(_field: &Option<L>) -> PhantomData<L>
+{
+    PhantomData
+}
+
+
+/// Exists so that the macro can call `deep_safe_drop` with an inferred `Link` type.
+#[doc(hidden)]
+#[inline]
+pub fn deep_safe_drop_as<RootNode, L, N>(
+    root: &mut RootNode,
+    _link_type: PhantomData<L>,
+) where
+    RootNode: DeepSafeDrop<L> + ?Sized,
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    deep_safe_drop::<RootNode, L, N>(root);
+}
+
+
+/// Expands to a link type, named as given, that is a newtype of `Box<dyn DeepSafeDrop<Self>>`,
+/// with the `alloc` feature, for trees of heterogeneous node types, and to the implementations
+/// of [`DeepSafeDrop`], [`Link`], and [`Drop`], via `deep_safe_drop`, for it, and of [`From`]
+/// each of the given node types.
+///
+/// The link type is also the node type of the traversal, which delegates to the boxed node.
+/// Unlike with [`DynDeepSafeDrop`](crate::DynDeepSafeDrop), the node types are generic over this
+/// link type, and so can also be used with other link types.
+///
+/// ```
+/// use deep_safe_drop::{DeepSafeDrop, dyn_link};
+///
+/// #[derive(DeepSafeDrop)]
+/// struct List<L>
+/// {
+///     #[child(index = 0)]
+///     next: Option<L>,
+/// }
+///
+/// #[derive(DeepSafeDrop)]
+/// struct BinaryTree<L>
+/// {
+///     #[child(index = 0)]
+///     left:  Option<L>,
+///     #[child(index = 1)]
+///     right: Option<L>,
+/// }
+///
+/// dyn_link!(MyLink; List<MyLink>, BinaryTree<MyLink>);
+///
+/// let tree = BinaryTree { left: Some(MyLink::from(List { next: None })), right: None };
+/// let list = (0 .. 1_000_000).fold(MyLink::from(tree), |next, _| {
+///     MyLink::from(List { next: Some(next) })
+/// });
+/// drop(list);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! dyn_link {
+    ($(#[$attr:meta])* $vis:vis $name:ident; $($node:ty),* $(,)?) => {
+        $(#[$attr])*
+        $vis struct $name($crate::__Box<dyn $crate::DeepSafeDrop<$name>>);
+
+        impl $crate::DeepSafeDrop<Self> for $name
+        {
+            fn set_parent_at_index_0(
+                &mut self,
+                parent: Self,
+            ) -> $crate::SetParent<Self>
+            {
+                self.0.set_parent_at_index_0(parent)
+            }
+
+            fn take_child_at_index_0(&mut self) -> ::core::option::Option<Self>
+            {
+                self.0.take_child_at_index_0()
+            }
+
+            fn take_next_child_at_pos_index(&mut self) -> ::core::option::Option<Self>
+            {
+                self.0.take_next_child_at_pos_index()
+            }
+
+            fn child_count_hint(&self) -> ::core::option::Option<usize>
+            {
+                self.0.child_count_hint()
+            }
+
+            fn swap_child_at_index_0_with(
+                &mut self,
+                i: usize,
+            ) -> bool
+            {
+                self.0.swap_child_at_index_0_with(i)
+            }
+        }
+
+        impl $crate::Link<Self> for $name
+        {
+            fn get_mut(&mut self) -> &mut Self
+            {
+                self
+            }
+        }
+
+        impl ::core::ops::Drop for $name
+        {
+            fn drop(&mut self)
+            {
+                $crate::deep_safe_drop::<Self, Self, Self>(self);
+            }
+        }
+
+        $(
+            impl ::core::convert::From<$node> for $name
+            {
+                fn from(node: $node) -> Self
+                {
+                    Self($crate::__Box::new(node))
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,121 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+    },
+    alloc::vec::{
+        self,
+        Vec,
+    },
+    core::mem,
+};
+
+
+/// Consume a tree, reducing it bottom-up via `alg`, without stack overflow, with the `alloc`
+/// feature.  (A catamorphism, where `alg` is the algebra.)
+///
+/// `alg` is given the link to each node, after all its children have been extracted, i.e. a
+/// leaf, along with the results of `alg` for those children, in the order that the node gives
+/// them via [`DeepSafeDrop::take_next_child_at_any_index`].  So `alg` may move data out of the
+/// node, e.g. via `*link` for a `Box`, and dropping the link is safe from stack overflow.  The
+/// result for the `root` is returned.
+///
+/// Unlike [`into_post_order`](crate::into_post_order), this uses an explicit stack, of each node
+/// along the current path with the results of its children so far, because the results must be
+/// grouped by their parent.
+///
+/// # Panics
+///
+/// If `alg` panics, the remaining nodes are dropped, via the `Drop` of your link type, during
+/// the unwinding, but not necessarily safely, unless your link or node type uses
+/// `deep_safe_drop`.
+#[inline]
+pub fn cata<L, N, O, F>(
+    root: L,
+    mut alg: F,
+) -> O
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    F: FnMut(L, Vec<O>) -> O,
+{
+    // The ancestors of `cur`, each with the results of its children so far.
+    let mut stack = Vec::new();
+    let mut cur = (root, Vec::new());
+
+    loop {
+        if let Some(child) = cur.0.get_mut().take_next_child_at_any_index() {
+            stack.push(mem::replace(&mut cur, (child, Vec::new())));
+        }
+        else {
+            let (leaf, results) = cur;
+            let result = alg(leaf, results);
+            match stack.pop() {
+                Some(mut parent) => {
+                    parent.1.push(result);
+                    cur = parent;
+                },
+                None => break result,
+            }
+        }
+    }
+}
+
+
+/// Build a tree top-down from `seed` via `coalg`, without stack overflow, with the `alloc`
+/// feature.  (An anamorphism, where `coalg` is the coalgebra.)
+///
+/// `coalg` is given each seed and returns the node for it along with the seeds of its children,
+/// which are expanded in order, depth-first.  Each node is linked to only after all its
+/// descendants are built, when the links to its children are given to it via [`Extend`], in the
+/// order of their seeds, and then it is converted into its link via [`From`], e.g. `Box::from`.
+/// The link to the node of the first `seed` is returned.
+///
+/// This uses an explicit stack, of each node along the current path with its remaining seeds and
+/// the links to its children so far.  The built tree can be deep, and so should be dropped via
+/// [`deep_safe_drop`](fn@crate::deep_safe_drop), e.g. by the `Drop` of your node type.
+#[inline]
+pub fn ana<L, N, S, F>(
+    seed: S,
+    mut coalg: F,
+) -> L
+where
+    L: From<N>,
+    N: Extend<L>,
+    F: FnMut(S) -> (N, Vec<S>),
+{
+    /// A node whose children are still being built.
+    struct Pending<L, N, S>
+    {
+        node:     N,
+        seeds:    vec::IntoIter<S>,
+        children: Vec<L>,
+    }
+
+    let mut pending = |node_seed| {
+        let (node, seeds) = coalg(node_seed);
+        Pending { node, seeds: seeds.into_iter(), children: Vec::new() }
+    };
+    // The ancestors of `cur`.
+    let mut stack = Vec::new();
+    let mut cur = pending(seed);
+
+    loop {
+        if let Some(child_seed) = cur.seeds.next() {
+            let child = pending(child_seed);
+            stack.push(mem::replace(&mut cur, child));
+        }
+        else {
+            let Pending { mut node, children, .. } = cur;
+            node.extend(children);
+            let link = L::from(node);
+            match stack.pop() {
+                Some(mut parent) => {
+                    parent.children.push(link);
+                    cur = parent;
+                },
+                None => break link,
+            }
+        }
+    }
+}
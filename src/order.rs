@@ -0,0 +1,119 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    DeepSafeDrop,
+    Link,
+    main_deep_safe_drop,
+};
+
+
+mod sealed
+{
+    #[allow(unnameable_types)] // Intentionally, so that it cannot be implemented elsewhere.
+    pub trait Sealed {}
+}
+
+
+/// The order in which [`deep_safe_drop_ordered`] drops the children of each node.
+///
+/// This is sealed, and so it is only implemented by [`LeftToRight`] and [`RightToLeft`].
+pub trait DropOrder: sealed::Sealed
+{
+    /// Extract and drop all the children of `root`, and their descendants, in this order.
+    #[doc(hidden)]
+    fn drop_children<RootNode, L, N>(root: &mut RootNode)
+    where
+        RootNode: DeepSafeDrop<L> + ?Sized,
+        L: Link<N>,
+        N: DeepSafeDrop<L> + ?Sized;
+}
+
+
+/// The children of a node are dropped in the order that it gives them, i.e. the child at index 0
+/// first.  This is the order of [`deep_safe_drop`](fn@crate::deep_safe_drop), which does not
+/// allocate.
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::exhaustive_enums)]
+pub enum LeftToRight {}
+
+impl sealed::Sealed for LeftToRight {}
+
+impl DropOrder for LeftToRight
+{
+    #[inline]
+    fn drop_children<RootNode, L, N>(root: &mut RootNode)
+    where
+        RootNode: DeepSafeDrop<L> + ?Sized,
+        L: Link<N>,
+        N: DeepSafeDrop<L> + ?Sized,
+    {
+        while let Some(child) = root.take_next_child_at_any_index() {
+            main_deep_safe_drop(child, &mut |_: &mut N| {});
+        }
+    }
+}
+
+
+/// The children of a node are dropped in the reverse of the order that it gives them, i.e. the
+/// child at index 0 last, with the `alloc` feature, e.g. for stack-like trees where the
+/// last-pushed should be the first dropped.
+///
+/// The link-reversal of `deep_safe_drop` must reuse the link at index 0 to link to the parent,
+/// and so it always descends into that child first.  Therefore, this instead uses an explicit
+/// stack of the extracted children whose subtrees have not been dropped yet, which grows to the
+/// sum of the amounts of children of the nodes along the deepest path.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::exhaustive_enums)]
+pub enum RightToLeft {}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for RightToLeft {}
+
+#[cfg(feature = "alloc")]
+impl DropOrder for RightToLeft
+{
+    #[inline]
+    fn drop_children<RootNode, L, N>(root: &mut RootNode)
+    where
+        RootNode: DeepSafeDrop<L> + ?Sized,
+        L: Link<N>,
+        N: DeepSafeDrop<L> + ?Sized,
+    {
+        // Each entry is a link and whether its children have already been extracted, in which
+        // case it is now a leaf.
+        let mut stack: Vec<(L, bool)> =
+            core::iter::from_fn(|| root.take_next_child_at_any_index())
+                .map(|c| (c, false))
+                .collect();
+
+        while let Some((mut link, is_leaf)) = stack.pop() {
+            if is_leaf {
+                drop(link);
+            }
+            else {
+                let start = stack.len();
+                let children =
+                    core::iter::from_fn(|| link.get_mut().take_next_child_at_any_index());
+                stack.extend(children.map(|child| (child, false)));
+                // Below its children, so that it is dropped after them.
+                stack.insert(start, (link, true));
+            }
+        }
+    }
+}
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but the order in which the children of each
+/// node are dropped is given by `O`.
+#[inline]
+pub fn deep_safe_drop_ordered<RootNode, Link, Node, O>(root: &mut RootNode)
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+    O: DropOrder,
+{
+    O::drop_children(root);
+}
@@ -0,0 +1,26 @@
+use crate::{
+    DeepSafeDrop,
+    main_deep_safe_drop,
+};
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but the subtrees of the children of `root`
+/// are dropped in parallel, via [`rayon::in_place_scope`], with the `rayon` feature.
+///
+/// Each subtree is owned exclusively by the task that drops it, sequentially, like
+/// `deep_safe_drop`.  This can use all the available CPUs for trees whose root has many
+/// children, e.g. a wide fan, but, for a root with a single child, e.g. a list, parallelism
+/// provides no benefit.  This returns after all the subtrees have been dropped.
+#[inline]
+pub fn deep_safe_drop_parallel<RootNode, Link, Node>(root: &mut RootNode)
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node> + Send,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    rayon::in_place_scope(|scope| {
+        while let Some(child) = root.take_next_child_at_any_index() {
+            scope.spawn(move |_| main_deep_safe_drop(child, &mut |_: &mut Node| {}));
+        }
+    });
+}
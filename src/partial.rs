@@ -0,0 +1,246 @@
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        DropError,
+        ReadableChildren,
+        pre_order,
+        take_parent,
+    },
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+};
+
+use crate::{
+    DeepSafeDrop,
+    PostOrderIter,
+    SetParent,
+    into_post_order,
+};
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but drops at most `max_nodes` nodes, and
+/// returns the amount dropped, so that the cost of dropping a large tree can be spread across
+/// multiple calls, e.g. for real-time constraints.  Done when this returns 0.
+///
+/// When the budget is exhausted in the middle of a child's tree, the rest of that tree is stored
+/// back in the `root`, via [`DeepSafeDrop::set_parent_at_index_0`], which requires that the link
+/// at index 0 of the `root` is vacant by then, as it is when `take_child_at_index_0` has been
+/// called.  The stored tree is the link-reversal state of the traversal, i.e. the lowest node of
+/// the path that was being worked back up, whose link at index 0 is to its parent, and so on.
+/// The next call continues by working back up that path from that node, without working back
+/// down to it, and so all the calls together cost the same as one whole traversal, regardless of
+/// the depths at which they stopped, although a call that descends into a deep subtree also
+/// costs the depth of that.  If the `root` has no link at index 0, the rest of that tree is
+/// dropped regardless of the budget.
+///
+/// Because of that, the child at index 0 of the `root` is always continued as such a stored
+/// rest.  This is still safe when it is instead an original child of the `root`, but then the
+/// nodes of its path of children at index 0 are dropped before those children, i.e. not in
+/// post-order.  The other nodes are dropped in post-order.
+#[inline]
+#[must_use = "the amount dropped is needed to know when the whole tree is done"]
+pub fn deep_safe_drop_partial<RootNode, Link, Node>(
+    root: &mut RootNode,
+    max_nodes: usize,
+) -> usize
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut rest = root.take_child_at_index_0();
+    let dropped = drop_some(root, &mut rest, max_nodes);
+    dropped.saturating_add(rest.map_or(0, |rest| store_rest(root, rest)))
+}
+
+
+/// Like [`deep_safe_drop_partial`], which drops exactly `n` nodes unless the tree runs out before
+/// that, but also tells whether the whole tree is done, without a further call that drops
+/// nothing, by returning `(actual_dropped, is_fully_done)`, e.g. for an exact budget per frame.
+///
+/// `is_fully_done` is whether no nodes remain, and so it is `true` whenever `actual_dropped` is
+/// less than `n`, but it is also `true` when the tree had exactly `n` nodes remaining, which
+/// is known by taking the next child of the `root`, if any, and storing it back as a rest, as
+/// `deep_safe_drop_partial` does.  The tree is left valid after each call, and the next call
+/// continues where this one stopped.  If the `root` has no link at index 0, where the rest would
+/// be stored, then the rest of the whole tree is dropped regardless of `n`, as for
+/// `deep_safe_drop_partial`, and so it is done.
+#[inline]
+#[must_use = "whether the whole tree is done is needed to know when to stop"]
+pub fn deep_safe_drop_n<RootNode, Link, Node>(
+    root: &mut RootNode,
+    n: usize,
+) -> (usize, bool)
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut rest = root.take_child_at_index_0();
+    let mut dropped = drop_some(root, &mut rest, n);
+    // When `n` was reached without a rest, only taking the next child shows whether any remain.
+    while let Some(next) = rest.take().or_else(|| root.take_next_child_at_any_index()) {
+        let unstored = store_rest(root, next);
+        if unstored == 0 {
+            return (dropped, false);
+        }
+        // It could not be stored and so was dropped, and so must be any other remaining child.
+        dropped = dropped.saturating_add(unstored);
+    }
+    (dropped, true)
+}
+
+
+/// Drop at most `max_nodes` nodes, by first continuing the traversal of the `rest`, and then by
+/// traversing each next child of the `root`, and return the amount dropped.  When the budget is
+/// exhausted in the middle of a traversal, its rest is left in `rest`, otherwise the `root` has
+/// no children remaining when this returns less than `max_nodes`.
+pub(crate) fn drop_some<RootNode, Link, Node>(
+    root: &mut RootNode,
+    rest: &mut Option<Link>,
+    max_nodes: usize,
+) -> usize
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut dropped: usize = 0;
+
+    while dropped < max_nodes {
+        let mut leaves = match rest.take() {
+            Some(rest) => PostOrderIter::resume(rest),
+            None => match root.take_next_child_at_any_index() {
+                Some(child) => into_post_order(child),
+                None => break,
+            },
+        };
+        for leaf in leaves.by_ref().take(max_nodes.saturating_sub(dropped)) {
+            drop(leaf);
+            dropped = dropped.saturating_add(1);
+        }
+        *rest = leaves.into_rest();
+    }
+
+    dropped
+}
+
+
+/// Store the `rest` of a traversal back in the vacant link at index 0 of the `root`, or drop it
+/// when it cannot be stored, and return the amount of nodes dropped by that.
+fn store_rest<RootNode, Link, Node>(
+    root: &mut RootNode,
+    rest: Link,
+) -> usize
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let unstored = match root.set_parent_at_index_0(rest) {
+        SetParent::Yes => None,
+        // Put back the link that was at index 0.
+        SetParent::YesReplacedChild { child0 } => match root.set_parent_at_index_0(child0) {
+            SetParent::YesReplacedChild { child0: returned } => Some(returned),
+            SetParent::Yes | SetParent::No { .. } => None,
+        },
+        SetParent::No { returned_parent } => Some(returned_parent),
+    };
+    unstored.map_or(0, |unstored| into_post_order(unstored).count())
+}
+
+
+/// Like [`deep_safe_drop_partial`] but an error when `max_nodes` is exhausted before the whole
+/// tree is dropped, with the `alloc` feature, e.g. for a deadline that is expected to be enough.
+///
+/// The remaining nodes are counted, via [`ReadableChildren`], only when stopped early, and the
+/// rest of the tree is still stored back in the `root`, as for `deep_safe_drop_partial`, and so
+/// can be continued with by further calls.  The stored rest is counted including the path above
+/// it, even when the link at index 0 is not one of the `children_of` your node type.
+///
+/// # Errors
+///
+/// [`DropError::PartialDropAborted`] with the amount of nodes that remain.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn deep_safe_drop_within<RootNode, Link, Node>(
+    root: &mut RootNode,
+    max_nodes: usize,
+) -> Result<usize, DropError>
+where
+    RootNode: DeepSafeDrop<Link> + ReadableChildren<Link> + ?Sized,
+    Link: crate::Link<Node> + Borrow<Node>,
+    Node: DeepSafeDrop<Link> + ReadableChildren<Link> + ?Sized,
+{
+    let mut rest = root.take_child_at_index_0();
+    let mut dropped = drop_some(root, &mut rest, max_nodes);
+
+    // Less means that the traversals ran out of children, and so nothing remains.
+    let nodes_remaining = if dropped < max_nodes {
+        0
+    }
+    else {
+        // Before the rest is stored, because the link at index 0 of the `root` might be one of
+        // its `children_of`, which would count it twice.
+        let children: usize =
+            root.children_of().map(|child| pre_order::<Link, Node>(child).count()).sum();
+        let stored = rest.map_or(0, |mut rest| {
+            let count = count_rest(&mut rest);
+            let unstored = store_rest(root, rest);
+            dropped = dropped.saturating_add(unstored);
+            if unstored == 0 { count } else { 0 }
+        });
+        children.saturating_add(stored)
+    };
+
+    if nodes_remaining == 0 {
+        Ok(dropped)
+    }
+    else {
+        Err(DropError::PartialDropAborted { nodes_remaining })
+    }
+}
+
+
+/// The amount of nodes of the `rest` of a traversal, including those of the reversed-links path
+/// above it.  That path is not necessarily among the `children_of` its nodes, and so it is
+/// unlinked, to read each of its nodes without their links at index 0, and then relinked.
+#[cfg(feature = "alloc")]
+fn count_rest<Link, Node>(rest: &mut Link) -> usize
+where
+    Link: crate::Link<Node> + Borrow<Node>,
+    Node: DeepSafeDrop<Link> + ReadableChildren<Link> + ?Sized,
+{
+    fn count_without_index_0<Link, Node>(node: &Link) -> usize
+    where
+        Link: Borrow<Node>,
+        Node: ReadableChildren<Link> + ?Sized,
+    {
+        let children: usize =
+            node.borrow().children_of().map(|child| pre_order::<Link, Node>(child).count()).sum();
+        children.saturating_add(1)
+    }
+
+    let mut path = Vec::new();
+    let mut upper = take_parent(rest.get_mut());
+    let mut count = count_without_index_0(rest);
+    while let Some(mut ancestor) = upper {
+        upper = take_parent(ancestor.get_mut());
+        count = count.saturating_add(count_without_index_0(&ancestor));
+        path.push(ancestor);
+    }
+
+    let relink = |node: &mut Node, link| {
+        let unstored = node.store_child_at_index_0(link);
+        debug_assert!(unstored.is_none(), "the link at index 0 was just taken");
+        drop(unstored);
+    };
+    let parent = path.into_iter().rev().reduce(|above, mut lower| {
+        relink(lower.get_mut(), above);
+        lower
+    });
+    if let Some(parent) = parent {
+        relink(rest.get_mut(), parent);
+    }
+    count
+}
@@ -0,0 +1,89 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        main_deep_safe_drop,
+    },
+    alloc::boxed::Box,
+    core::{
+        borrow::Borrow,
+        pin::Pin,
+    },
+};
+
+
+/// A link via [`Pin<Box<N>>`](Pin), with the `alloc` feature.
+///
+/// Only for `N: Unpin`, because the traversal must move links out of, and into, nodes, which is
+/// only safe via [`Pin::get_mut`] for types that do not rely on being pinned.  For `!Unpin` node
+/// types, `deep_safe_drop` cannot be used with `Pin<Box<N>>` without first proving, in your own
+/// code, that moving their links is sound for them.
+#[derive(Debug)]
+pub struct PinnedLink<N>(Pin<Box<N>>)
+where N: Unpin + ?Sized;
+
+impl<N> PinnedLink<N>
+where N: Unpin
+{
+    /// Make a link to a new pinned node.
+    #[inline]
+    #[must_use]
+    pub fn new(node: N) -> Self
+    {
+        Self(Box::pin(node))
+    }
+}
+
+impl<N> PinnedLink<N>
+where N: Unpin + ?Sized
+{
+    /// Relinquish the pinned box.
+    #[inline]
+    #[must_use]
+    pub fn into_pin(self) -> Pin<Box<N>>
+    {
+        self.0
+    }
+}
+
+impl<N> From<Pin<Box<N>>> for PinnedLink<N>
+where N: Unpin + ?Sized
+{
+    #[inline]
+    fn from(pinned: Pin<Box<N>>) -> Self
+    {
+        Self(pinned)
+    }
+}
+
+impl<N> Borrow<N> for PinnedLink<N>
+where N: Unpin + ?Sized
+{
+    #[inline]
+    fn borrow(&self) -> &N
+    {
+        &self.0
+    }
+}
+
+impl<N> Link<N> for PinnedLink<N>
+where N: Unpin + ?Sized
+{
+    #[inline]
+    fn get_mut(&mut self) -> &mut N
+    {
+        Pin::get_mut(self.0.as_mut())
+    }
+}
+
+
+/// Drop a tree whose root is pinned, safely, like `deep_safe_drop`, with the `alloc` feature.
+///
+/// The node type does not even need to implement [`Drop`] with `deep_safe_drop`, because this
+/// drops all the nodes, including the root, itself.
+#[inline]
+pub fn drop_pinned<N>(root: Pin<Box<N>>)
+where N: Unpin + DeepSafeDrop<PinnedLink<N>> + ?Sized
+{
+    main_deep_safe_drop(PinnedLink(root), &mut |_: &mut N| {});
+}
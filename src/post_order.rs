@@ -0,0 +1,424 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use {
+    crate::{
+        CycleCheck,
+        DeepSafeDrop,
+        DropStatistics,
+        Link,
+        SetParent,
+        debug_assert_leaf,
+        take_parent,
+    },
+    core::{
+        iter::FusedIterator,
+        marker::PhantomData,
+    },
+};
+#[cfg(feature = "profile")]
+use {
+    crate::{
+        ProfiledDrop,
+        StepType,
+    },
+    std::time::Instant,
+};
+
+
+/// Consume a tree, supplying the links to its nodes in post-order, without dropping them.
+///
+/// Each supplied link is to a node from which all its children have already been extracted,
+/// i.e. a leaf, and so dropping it is safe from stack overflow.  This enables "recycle or drop"
+/// patterns: the caller may reuse a node, or drop it.  The last supplied link is the `root`.
+///
+/// The supplied items are your links, not your nodes, and so the wrapping of your smart-pointer
+/// link type is preserved, e.g. a `Box` can be returned to a pool without reallocating.  Nothing
+/// is dropped until you drop a supplied link.
+///
+/// This is the same link-reversal traversal that [`deep_safe_drop`](fn@crate::deep_safe_drop)
+/// does, and so it also does not allocate.  If the iterator is dropped before it is exhausted, it
+/// drops the remaining nodes in the same way.
+#[doc(alias = "drain")]
+#[inline]
+pub fn into_post_order<L, N>(root: L) -> PostOrderIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    PostOrderIter::new(root, |_| {})
+}
+
+
+/// Consume a tree, returning the links to its nodes in a topological order, i.e. each node before
+/// all its descendants, without dropping them, with the `alloc` feature.
+///
+/// This is the reverse of the order of [`into_post_order`], and so the first link is the `root`.
+/// Each node has already had all its children extracted, i.e. is a leaf, and so the links can be
+/// dropped individually, in any order, safely from stack overflow, or be used in reverse for
+/// bottom-up processing.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn topological_order<L, N>(mut root: L) -> Vec<L>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    // The root and its children are the least amount of nodes, when that is known.
+    let capacity = root.get_mut().child_count_hint().map_or(1, |count| count.saturating_add(1));
+    let mut order = Vec::with_capacity(capacity);
+    order.extend(into_post_order(root));
+    order.reverse();
+    order
+}
+
+
+/// Iterator returned by [`into_post_order`].
+///
+/// Instead of using recursive function calls to enable working back up a tree branch, a link of
+/// each node is reused to record which parent node must be worked back "up" to, and this
+/// iterator only holds the lowest such parent and the next child to descend into.
+///
+/// The `H` type is of a hook that is called on each node when it is first reached, before any of
+/// its children are extracted, i.e. in pre-order, which is a no-op for `into_post_order`.
+#[derive(Debug)]
+pub struct PostOrderIter<L, N, H = fn(&mut N)>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    H: FnMut(&mut N),
+{
+    /// The lowest node in the reversed-links path back up to the top, or `None` when done.
+    parent:     Option<L>,
+    /// The next child to descend into, whose parent is `parent`.
+    cur:        Option<L>,
+    cycles:     CycleCheck,
+    /// The depth of `parent`, where the top is at depth 1.
+    depth:      usize,
+    /// The depth of the most-recently supplied leaf.
+    leaf_depth: usize,
+    stats:      DropStatistics,
+    enter:      H,
+    /// The durations of the steps, only when profiling.
+    #[cfg(feature = "profile")]
+    profile:    Option<ProfiledDrop>,
+    _node:      PhantomData<fn(&mut N)>,
+}
+
+impl<L, N> PostOrderIter<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Continue a traversal from the `rest` that [`into_rest`](Self::into_rest) returned, without
+    /// working back down to where it stopped, by ascending from it as the lowest node of the
+    /// reversed-links path.
+    ///
+    /// If `rest` is instead a node whose link at index 0 is to a child, this is still safe, but
+    /// then that node is supplied before that child, i.e. the path of children at index 0 from
+    /// it is supplied parent-first, not in post-order.
+    pub(crate) fn resume(rest: L) -> Self
+    {
+        let stats = DropStatistics { max_depth_reached: 1, ..DropStatistics::default() };
+        Self {
+            parent: Some(rest),
+            cur: None,
+            cycles: CycleCheck::default(),
+            depth: 1,
+            leaf_depth: 0,
+            stats,
+            enter: |_| {},
+            #[cfg(feature = "profile")]
+            profile: None,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<L, N, H> PostOrderIter<L, N, H>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    H: FnMut(&mut N),
+{
+    pub(crate) fn new(
+        mut top: L,
+        mut enter: H,
+    ) -> Self
+    {
+        let mut cycles = CycleCheck::default();
+        cycles.enter(&mut top);
+        enter(top.get_mut());
+        let cur = top.get_mut().take_next_child_at_any_index();
+        let mut stats = DropStatistics { max_depth_reached: 1, ..DropStatistics::default() };
+        stats.count_node(cur.is_some());
+        Self {
+            parent: Some(top),
+            cur,
+            cycles,
+            depth: 1,
+            leaf_depth: 0,
+            stats,
+            enter,
+            #[cfg(feature = "profile")]
+            profile: None,
+            _node: PhantomData,
+        }
+    }
+
+    /// Stop the traversal, without dropping the rest of the tree, and return the lowest node of
+    /// the reversed-links path, or `None` when done.  That node is the root of a valid tree of
+    /// the rest of the nodes, because each node of that path links to its parent, which
+    /// remains linked to its children that have not been supplied yet.
+    ///
+    /// Must only be called between calls of `next`, because then there is no `cur`.
+    pub(crate) fn into_rest(mut self) -> Option<L>
+    {
+        debug_assert!(self.cur.is_none(), "must not have cur");
+        self.parent.take()
+    }
+
+    /// The depth of the most-recently supplied leaf, where the top is at depth 1, or 0 when none
+    /// has been supplied yet.
+    pub(crate) fn leaf_depth(&self) -> usize
+    {
+        self.leaf_depth
+    }
+
+    /// The address of the node at which a cycle was detected, if the traversal panicked because
+    /// of that.
+    #[cfg(feature = "std")]
+    pub(crate) fn cycle_detected_at(&self) -> Option<usize>
+    {
+        self.cycles.detected_at()
+    }
+
+    /// Start recording the durations of the steps, in addition to those already in `profile`.
+    #[cfg(feature = "profile")]
+    pub(crate) fn set_profile(
+        &mut self,
+        profile: ProfiledDrop,
+    )
+    {
+        self.profile = Some(profile);
+    }
+
+    /// Stop recording the durations of the steps, and return them.
+    #[cfg(feature = "profile")]
+    pub(crate) fn take_profile(&mut self) -> ProfiledDrop
+    {
+        self.profile.take().unwrap_or_default()
+    }
+
+    /// Record the duration of a step that was started at `start`, only when profiling.
+    #[cfg(feature = "profile")]
+    #[inline]
+    pub(crate) fn record_step(
+        &mut self,
+        step_type: StepType,
+        start: Option<Instant>,
+    )
+    {
+        if let (Some(profile), Some(start)) = (&mut self.profile, start) {
+            profile.record(step_type, start);
+        }
+    }
+
+    /// The start of a step, only when profiling.
+    #[cfg(feature = "profile")]
+    #[inline]
+    fn start_step(&self) -> Option<Instant>
+    {
+        self.profile.as_ref().map(|_| Instant::now())
+    }
+
+    /// The metrics of the traversal so far.
+    pub(crate) fn statistics(&self) -> DropStatistics
+    {
+        self.stats
+    }
+
+    // These steps of the traversal are `#[inline]`, because they form the hot loop of `next`,
+    // which otherwise might not be inlined into it across codegen units.
+
+    /// Record that `parent` is now one level deeper.
+    #[inline]
+    fn deeper(&mut self)
+    {
+        self.depth = self.depth.saturating_add(1);
+        self.stats.max_depth_reached = self.stats.max_depth_reached.max(self.depth);
+    }
+
+    /// Descend from `parent` through `cur`, reusing the link at index 0 of each node to link to
+    /// its parent, until a node that has no children is reached, which is returned.  Or, return
+    /// `None` when a node that has no child at index 0 and no next child at a positive index is
+    /// reached (i.e. it becomes the new `parent` that must be ascended from).
+    #[inline]
+    fn descend(
+        &mut self,
+        mut parent: L,
+        mut cur: L,
+    ) -> Option<L>
+    {
+        loop {
+            self.cycles.enter(&mut cur);
+            (self.enter)(cur.get_mut());
+            event("descend", &mut cur, self.depth.saturating_add(1));
+            #[cfg(feature = "profile")]
+            let start = self.start_step();
+            let set_parent = cur.get_mut().set_parent_at_index_0(parent);
+            #[cfg(feature = "profile")]
+            self.record_step(StepType::Descent, start);
+            match set_parent {
+                SetParent::YesReplacedChild { child0 } => {
+                    self.stats.count_node(true);
+                    self.deeper();
+                    parent = cur;
+                    cur = child0;
+                },
+                SetParent::Yes => {
+                    let next_child = cur.get_mut().take_next_child_at_pos_index();
+                    self.stats.count_node(next_child.is_some());
+                    self.deeper();
+                    if let Some(next_child) = next_child {
+                        parent = cur;
+                        cur = next_child;
+                    }
+                    else {
+                        self.parent = Some(cur);
+                        break None;
+                    }
+                },
+                SetParent::No { returned_parent } => {
+                    self.stats.count_node(false);
+                    self.stats.max_depth_reached =
+                        self.stats.max_depth_reached.max(self.depth.saturating_add(1));
+                    self.parent = Some(returned_parent);
+                    self.leaf_depth = self.depth.saturating_add(1);
+                    event("leaf", &mut cur, self.leaf_depth);
+                    break Some(cur); // `cur` is a leaf node.
+                },
+            }
+        }
+    }
+
+    /// Ascend from `parent`, until the nearest ancestor that has a next child, which becomes
+    /// `cur`.  Return any ancestor that does not have a next child, which is now a leaf, when its
+    /// parent is restored as `parent`.  Return the top when it is reached and it does not have a
+    /// next child, which leaves `parent` as `None` to indicate done.
+    #[inline]
+    fn ascend(&mut self) -> Option<L>
+    {
+        let mut ancestor = self.parent.take()?;
+        #[cfg(feature = "profile")]
+        let start = self.start_step();
+
+        if let Some(next_child) = ancestor.get_mut().take_next_child_at_pos_index() {
+            self.parent = Some(ancestor);
+            self.cur = Some(next_child);
+            #[cfg(feature = "profile")]
+            self.record_step(StepType::Ascent, start);
+            None
+        }
+        else {
+            // The top has no parent, which leaves `self.parent` as `None`.
+            self.parent = take_parent(ancestor.get_mut());
+            #[cfg(feature = "profile")]
+            self.record_step(StepType::Ascent, start);
+            self.leaf_depth = self.depth;
+            event("leaf", &mut ancestor, self.depth);
+            self.depth = self.depth.saturating_sub(1);
+            event("ascend", &mut ancestor, self.depth);
+            Some(ancestor) // `ancestor` is now a leaf node.
+        }
+    }
+
+    #[inline]
+    fn supply_leaf(
+        &mut self,
+        mut leaf: L,
+    ) -> L
+    {
+        self.cycles.leave(&mut leaf);
+        self.stats.nodes_dropped = self.stats.nodes_dropped.saturating_add(1);
+        debug_assert_leaf(leaf.get_mut());
+        leaf
+    }
+}
+
+impl<L, N, H> Iterator for PostOrderIter<L, N, H>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    H: FnMut(&mut N),
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<L>
+    {
+        loop {
+            // When there is a `cur`, there always is its `parent`.
+            if self.cur.is_some() {
+                if let (Some(parent), Some(cur)) = (self.parent.take(), self.cur.take()) {
+                    if let Some(leaf) = self.descend(parent, cur) {
+                        break Some(self.supply_leaf(leaf));
+                    }
+                }
+            }
+            if let Some(leaf) = self.ascend() {
+                break Some(self.supply_leaf(leaf));
+            }
+            if self.parent.is_none() {
+                break None;
+            }
+        }
+    }
+}
+
+impl<L, N, H> FusedIterator for PostOrderIter<L, N, H>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    H: FnMut(&mut N),
+{
+}
+
+impl<L, N, H> Drop for PostOrderIter<L, N, H>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+    H: FnMut(&mut N),
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        self.for_each(drop);
+    }
+}
+
+
+/// Emits a trace log of an action of the traversal at a node, with the `log` feature, and a trace
+/// event, with the `tracing` feature, otherwise this is a complete no-op.
+#[inline]
+#[cfg_attr(not(any(feature = "log", feature = "tracing")), allow(unused_variables))]
+fn event<L, N>(
+    action: &str,
+    link: &mut L,
+    depth: usize,
+) where
+    L: Link<N>,
+    N: ?Sized,
+{
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    {
+        let node: *const N = link.get_mut();
+        #[allow(clippy::pointer_format)] // The address is what identifies the node.
+        {
+            #[cfg(feature = "log")]
+            log::trace!("deep_safe_drop: {action} node={node:p} depth={depth}");
+            #[cfg(feature = "tracing")]
+            tracing::trace!(action, depth, node_ptr = format_args!("{node:p}"));
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use {
+    crate::ReadableChildren,
+    alloc::vec::Vec,
+    core::{
+        borrow::Borrow,
+        marker::PhantomData,
+    },
+};
+
+
+/// Iterate over the nodes of a tree in pre-order, without modifying the tree, with the `alloc`
+/// feature.
+///
+/// Because the link-reversal of [`deep_safe_drop`](fn@crate::deep_safe_drop) is inherently
+/// destructive, this instead uses an explicit stack, which grows by the amount of children of
+/// each node along the deepest path.
+#[inline]
+pub fn pre_order<L, N>(root: &L) -> PreOrderIter<'_, L, N>
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+{
+    PreOrderIter { stack: Vec::from([root]), _node: PhantomData }
+}
+
+
+/// Iterator returned by [`pre_order`].
+#[derive(Debug)]
+pub struct PreOrderIter<'l, L, N: ?Sized>
+{
+    stack: Vec<&'l L>,
+    _node: PhantomData<&'l N>,
+}
+
+impl<'l, L, N> Iterator for PreOrderIter<'l, L, N>
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized + 'l,
+{
+    type Item = &'l N;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let node = self.stack.pop()?.borrow();
+        let start = self.stack.len();
+        self.stack.extend(node.children_of());
+        // So that the first child is popped next.
+        if let Some(children) = self.stack.get_mut(start ..) {
+            children.reverse();
+        }
+        Some(node)
+    }
+}
@@ -0,0 +1,157 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        PostOrderIter,
+    },
+    alloc::vec::Vec,
+    core::mem,
+    std::time::Instant,
+};
+
+
+/// The steps of the traversal of [`deep_safe_drop`](fn@crate::deep_safe_drop), whose durations
+/// are recorded by [`deep_safe_drop_profiled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)] // These are all the steps of the algorithm.
+pub enum StepType
+{
+    /// Descending into a child, by [`DeepSafeDrop::set_parent_at_index_0`].
+    Descent,
+    /// Ascending to a parent, by taking its next child, or, when it has none, by taking its
+    /// parent.
+    Ascent,
+    /// Dropping a node that has become a leaf.
+    Drop,
+}
+
+
+/// Which value of the sorted durations of a [`StepType`] is given by
+/// [`ProfiledDrop::percentile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Percentile
+{
+    /// The shortest.
+    Min,
+    /// The median.
+    P50,
+    /// The 90th percentile.
+    P90,
+    /// The 99th percentile.
+    P99,
+    /// The longest.
+    Max,
+}
+
+impl Percentile
+{
+    /// The index, in sorted durations of `len` amount, via the nearest-rank method.
+    fn index(
+        self,
+        len: usize,
+    ) -> usize
+    {
+        let rank = |percent: usize| len.saturating_mul(percent).div_ceil(100).saturating_sub(1);
+        match self {
+            Self::Min => 0,
+            Self::P50 => rank(50),
+            Self::P90 => rank(90),
+            Self::P99 => rank(99),
+            Self::Max => len.saturating_sub(1),
+        }
+    }
+}
+
+
+/// Durations of every step of dropping a tree, as recorded by [`deep_safe_drop_profiled`], in
+/// nanoseconds, in the order that the steps occurred, with the `profile` feature.
+///
+/// This is strictly a development tool, for diagnosing performance bottlenecks in your
+/// `DeepSafeDrop` implementations, because getting the time at every step has significant
+/// overhead.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProfiledDrop
+{
+    /// In nanoseconds, as are the others.
+    descents: Vec<u64>,
+    ascents:  Vec<u64>,
+    drops:    Vec<u64>,
+}
+
+impl ProfiledDrop
+{
+    /// The durations of all the steps of the given type.
+    #[inline]
+    #[must_use]
+    pub fn times_ns(
+        &self,
+        step_type: StepType,
+    ) -> &[u64]
+    {
+        match step_type {
+            StepType::Descent => &self.descents,
+            StepType::Ascent => &self.ascents,
+            StepType::Drop => &self.drops,
+        }
+    }
+
+    /// The duration, in nanoseconds, at the given percentile of the steps of the given type, or 0
+    /// when there were no such steps.
+    #[inline]
+    #[must_use]
+    pub fn percentile(
+        &self,
+        which: Percentile,
+        step_type: StepType,
+    ) -> u64
+    {
+        let mut sorted = self.times_ns(step_type).to_vec();
+        sorted.sort_unstable();
+        sorted.get(which.index(sorted.len())).copied().unwrap_or(0)
+    }
+
+    /// Record the duration of a step that was started at `start`.
+    pub(crate) fn record(
+        &mut self,
+        step_type: StepType,
+        start: Instant,
+    )
+    {
+        let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let times = match step_type {
+            StepType::Descent => &mut self.descents,
+            StepType::Ascent => &mut self.ascents,
+            StepType::Drop => &mut self.drops,
+        };
+        times.push(nanos);
+    }
+}
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but also records the duration of every step
+/// of the traversal, with the `profile` feature.
+///
+/// Like [`deep_safe_drop_with`](crate::deep_safe_drop_with), the `root` itself is not dropped
+/// nor recorded.
+#[inline]
+#[must_use = "use `deep_safe_drop` when the profile is not needed"]
+pub fn deep_safe_drop_profiled<RootNode, Link, Node>(root: &mut RootNode) -> ProfiledDrop
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut profile = ProfiledDrop::default();
+
+    while let Some(next_child) = root.take_next_child_at_any_index() {
+        let mut leaves: PostOrderIter<Link, Node> = crate::into_post_order(next_child);
+        leaves.set_profile(mem::take(&mut profile));
+        while let Some(leaf) = leaves.next() {
+            let start = Instant::now();
+            drop(leaf);
+            leaves.record_step(StepType::Drop, Some(start));
+        }
+        profile = leaves.take_profile();
+    }
+    profile
+}
@@ -0,0 +1,161 @@
+use {
+    crate::{
+        ReadableChildren,
+        helpers::NaryNode,
+        pre_order,
+    },
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+    rand::{
+        Rng,
+        RngExt as _,
+    },
+};
+
+
+/// Builds trees of [`NaryNode`]s of varying shapes, with the `testing` feature, e.g. for property
+/// tests and benchmarks of your uses of `deep_safe_drop`.
+///
+/// Your link type `L` is made from each node by `From<NaryNode<L>>`.  The building does not use
+/// the call-stack for each level, and so it can build trees that are deep enough to require
+/// `deep_safe_drop`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RandomTreeBuilder
+{
+    /// Greatest depth of the trees built by `build_random`, where the root is at depth 1.
+    pub max_depth:         usize,
+    /// Greatest amount of children of any node of the trees built by `build_random`.
+    pub max_breadth:       usize,
+    /// Greatest amount of nodes of the trees built by `build_random`, which always have at least
+    /// the root.
+    pub total_node_budget: usize,
+}
+
+impl RandomTreeBuilder
+{
+    /// Make a builder with the given limits of the shapes of the trees.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        max_depth: usize,
+        max_breadth: usize,
+        total_node_budget: usize,
+    ) -> Self
+    {
+        Self { max_depth, max_breadth, total_node_budget }
+    }
+
+    /// Build a list of `len` nodes, i.e. where each node has a single child, except the last.  A
+    /// `len` of 0 is the same as 1.
+    #[inline]
+    #[must_use]
+    pub fn build_list<L>(len: usize) -> NaryNode<L>
+    where L: From<NaryNode<L>>
+    {
+        (1 .. len).fold(NaryNode::default(), |tail, _| NaryNode::new(Vec::from([L::from(tail)])))
+    }
+
+    /// Build a complete binary tree of the given `depth`, where the root is at depth 1.  A
+    /// `depth` of 0 is the same as 1.
+    #[inline]
+    #[must_use]
+    pub fn build_balanced_binary<L>(depth: usize) -> NaryNode<L>
+    where L: From<NaryNode<L>>
+    {
+        let exp = u32::try_from(depth.saturating_sub(1)).unwrap_or(u32::MAX);
+        let mut level: Vec<NaryNode<L>> = core::iter::repeat_with(NaryNode::default)
+            .take(2_usize.saturating_pow(exp))
+            .collect();
+        while level.len() > 1 {
+            let mut links = level.into_iter().map(L::from);
+            level = core::iter::from_fn(|| {
+                Some(NaryNode::new(Vec::from([links.next()?, links.next()?])))
+            })
+            .collect();
+        }
+        level.pop().unwrap_or_default()
+    }
+
+    /// Build a tree where each node has a random amount of children, up to `max_breadth`, and
+    /// where the depth is at most `max_depth` and the amount of nodes is at most
+    /// `total_node_budget`.
+    #[inline]
+    pub fn build_random<L, R>(
+        &self,
+        rng: &mut R,
+    ) -> NaryNode<L>
+    where
+        L: From<NaryNode<L>>,
+        R: Rng + ?Sized,
+    {
+        /// A node whose children are still being built.
+        struct Pending<L>
+        {
+            remaining: usize,
+            children:  Vec<L>,
+        }
+
+        let mut reserved = 1;
+        let mut pending = |depth, nodes: &mut usize| {
+            let arity = self.arity(rng, depth, nodes);
+            Pending { remaining: arity, children: Vec::with_capacity(arity) }
+        };
+        let mut path = Vec::from([pending(1, &mut reserved)]);
+
+        loop {
+            let depth = path.len();
+            match path.last_mut() {
+                Some(Pending { remaining: 0, .. }) => {
+                    let node = path.pop().map(|p| NaryNode::new(p.children)).unwrap_or_default();
+                    if let Some(parent) = path.last_mut() {
+                        parent.children.push(L::from(node));
+                    }
+                    else {
+                        break node;
+                    }
+                },
+                Some(Pending { remaining, .. }) => {
+                    *remaining = remaining.saturating_sub(1);
+                    path.push(pending(depth.saturating_add(1), &mut reserved));
+                },
+                None => break NaryNode::default(),
+            }
+        }
+    }
+
+    /// The random amount of children of a node at `depth`, which are added to `reserved`
+    /// without exceeding the limits.
+    fn arity<R>(
+        &self,
+        rng: &mut R,
+        depth: usize,
+        reserved: &mut usize,
+    ) -> usize
+    where
+        R: Rng + ?Sized,
+    {
+        if depth >= self.max_depth {
+            return 0;
+        }
+        let left = self.total_node_budget.saturating_sub(*reserved);
+        let arity = rng.random_range(0 ..= self.max_breadth).min(left);
+        *reserved = reserved.saturating_add(arity);
+        arity
+    }
+}
+
+
+/// The amount of nodes of the tree that `root` links to, including itself, without modifying it,
+/// with the `testing` feature.
+///
+/// This is a reference implementation, for checking the results of the traversals that modify a
+/// tree.  Like [`pre_order`], it does not use the call-stack for each level.
+#[inline]
+pub fn count_nodes<L, N>(root: &L) -> usize
+where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+{
+    pre_order::<L, N>(root).count()
+}
@@ -0,0 +1,223 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        ReadableChildren,
+        partial::drop_some,
+    },
+    core::{
+        borrow::Borrow,
+        marker::PhantomData,
+    },
+};
+
+
+/// Result of [`RealTimeDrop::step`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)]
+pub enum RealTimeDropStatus
+{
+    /// Some of the tree remains to be dropped, by further steps.
+    Pending(
+        /// A lower bound of the amount of nodes that remain, from the
+        /// [`child_count_hint`](DeepSafeDrop::child_count_hint) of the root and of the node at
+        /// which the step stopped.  See [`RealTimeDrop::total_remaining_estimate`] for a better
+        /// one.
+        usize,
+    ),
+    /// The whole tree has been dropped.
+    Complete(
+        /// The amount of nodes dropped, including the root.
+        usize,
+    ),
+}
+
+
+/// Owns a tree and drops it in steps of a bounded amount of nodes each, like
+/// [`deep_safe_drop_partial`](crate::deep_safe_drop_partial), e.g. one step per frame of a game
+/// loop, or per period of a real-time task, so that the time of dropping a large tree is spread
+/// over them.
+///
+/// ```
+/// use deep_safe_drop::{DeepSafeDrop, RealTimeDrop, RealTimeDropStatus::Pending, drop_boxed};
+///
+/// #[derive(DeepSafeDrop)]
+/// struct List
+/// {
+///     #[child(index = 0)]
+///     next: Option<Box<Self>>,
+/// }
+///
+/// impl Drop for List
+/// {
+///     fn drop(&mut self)
+///     {
+///         drop_boxed(self);
+///     }
+/// }
+///
+/// let list = (0 .. 1000).fold(List { next: None }, |acc, _| List { next: Some(Box::new(acc)) });
+/// let mut dropper = RealTimeDrop::<_, List>::new(Box::new(list));
+/// # let mut frames = 0;
+/// # let mut run_one_frame = || frames += 1;
+///
+/// while let Pending(_) = dropper.step(100) {
+///     run_one_frame();
+/// }
+/// # assert_eq!(frames, 10);
+/// ```
+///
+/// Like `deep_safe_drop_partial`, each step continues from where the previous step stopped,
+/// without working back down to it, and so the time of a step is bounded by `max_nodes` plus the
+/// depth of any subtree that it descends into.  Unlike that, the rest of the traversal is kept in
+/// this instead of in the root, and so the whole tree is dropped in post-order.  If this is
+/// dropped before it is complete, the rest of the tree is dropped at once, via the `Drop` of your
+/// link type.
+///
+/// This is not `Send`, because it is meant to be stepped by the same single thread, e.g. a task
+/// of an RTOS:
+///
+/// ```compile_fail
+/// # use deep_safe_drop::{DeepSafeDrop, RealTimeDrop};
+/// # #[derive(DeepSafeDrop)]
+/// # struct List
+/// # {
+/// #     #[child(index = 0)]
+/// #     next: Option<Box<Self>>,
+/// # }
+/// fn assert_send<T: Send>() {}
+/// assert_send::<RealTimeDrop<Box<List>, List>>();
+/// ```
+///
+/// For async tasks, see `CooperativeDrop`, with the `std` feature, instead.
+#[derive(Debug)]
+pub struct RealTimeDrop<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// The root, which is dropped by the step that completes, or `None` after that.
+    state:              Option<L>,
+    /// The rest of the traversal of a child of the root, where the previous step stopped.
+    rest:               Option<L>,
+    dropped_count:      usize,
+    remaining_estimate: usize,
+    _node:              PhantomData<fn(&mut N)>,
+    _not_send:          PhantomData<*const ()>,
+}
+
+impl<L, N> RealTimeDrop<L, N>
+where
+    L: Link<N>,
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    /// Take ownership of the tree at `root`, without dropping any of it yet.
+    #[inline]
+    #[must_use]
+    pub fn new(mut root: L) -> Self
+    {
+        let remaining_estimate = hinted_size(root.get_mut());
+        Self {
+            state: Some(root),
+            rest: None,
+            dropped_count: 0,
+            remaining_estimate,
+            _node: PhantomData,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Drop at most `max_nodes` more nodes of the tree, where the root is dropped last, once all
+    /// the others have been.  A `max_nodes` of 0 is the same as 1, so that the steps complete.
+    #[inline]
+    pub fn step(
+        &mut self,
+        max_nodes: usize,
+    ) -> RealTimeDropStatus
+    {
+        let max_nodes = max_nodes.max(1);
+        if let Some(root) = &mut self.state {
+            let dropped = drop_some(root.get_mut(), &mut self.rest, max_nodes);
+            self.dropped_count = self.dropped_count.saturating_add(dropped);
+            if dropped < max_nodes {
+                // The root has no children remaining.
+                self.state = None;
+                self.dropped_count = self.dropped_count.saturating_add(1);
+                self.remaining_estimate = 0;
+            }
+            else {
+                let rest = self.rest.as_mut().map_or(0, |rest| hinted_size(rest.get_mut()));
+                self.remaining_estimate = hinted_size(root.get_mut()).saturating_add(rest);
+            }
+        }
+        self.status()
+    }
+
+    /// The same as was returned by the most recent `step`.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> RealTimeDropStatus
+    {
+        if self.state.is_some() {
+            RealTimeDropStatus::Pending(self.remaining_estimate)
+        }
+        else {
+            RealTimeDropStatus::Complete(self.dropped_count)
+        }
+    }
+
+    /// The amount of nodes dropped so far, by all the steps.
+    #[inline]
+    #[must_use]
+    pub const fn dropped_count(&self) -> usize
+    {
+        self.dropped_count
+    }
+
+    /// A lower bound of the amount of nodes that remain to be dropped, from the
+    /// [`child_count_hint`](DeepSafeDrop::child_count_hint) of each node of the first levels of
+    /// the root and of the rest where the previous step stopped, without reading deeper than
+    /// the grandchildren, so that this is fast.
+    #[inline]
+    #[must_use]
+    pub fn total_remaining_estimate(&self) -> usize
+    where
+        L: Borrow<N>,
+        N: ReadableChildren<L>,
+    {
+        const LEVELS: usize = 2;
+
+        self.state.as_ref().map_or(0, |root| {
+            let rest = self.rest.as_ref().map_or(0, |rest| lower_bound(rest.borrow(), LEVELS));
+            lower_bound(root.borrow(), LEVELS).saturating_add(rest)
+        })
+    }
+}
+
+
+/// The amount of nodes of the tree at `node` that its `child_count_hint` shows, at least 1.
+fn hinted_size<L, N>(node: &N) -> usize
+where N: DeepSafeDrop<L> + ?Sized
+{
+    node.child_count_hint().unwrap_or(0).saturating_add(1)
+}
+
+
+/// A lower bound of the amount of nodes of the tree at `node`, from its `child_count_hint`, or,
+/// for `levels` more levels, from those of its children, recursively.
+fn lower_bound<L, N>(
+    node: &N,
+    levels: usize,
+) -> usize
+where
+    L: Borrow<N>,
+    N: DeepSafeDrop<L> + ReadableChildren<L> + ?Sized,
+{
+    match levels.checked_sub(1) {
+        None => hinted_size(node),
+        Some(levels) => node
+            .children_of()
+            .map(|child| lower_bound(child.borrow(), levels))
+            .fold(1, usize::saturating_add),
+    }
+}
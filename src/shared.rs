@@ -0,0 +1,365 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        Link,
+        SetParent,
+        deep_safe_drop,
+    },
+    alloc::{
+        rc::{
+            Rc,
+            Weak,
+        },
+        sync::Arc,
+    },
+    core::{
+        borrow::Borrow,
+        cell::{
+            Ref,
+            RefCell,
+            RefMut,
+        },
+        convert,
+    },
+};
+
+
+macro_rules! shared_link {
+    ($(#[$attr:meta])* $name:ident, $ptr:ident) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<N>(Option<$ptr<N>>)
+        where N: DeepSafeDrop<Self>;
+
+        impl<N> $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            /// Make a link to a new node.
+            #[inline]
+            #[must_use]
+            pub fn new(node: N) -> Self
+            {
+                Self(Some($ptr::new(node)))
+            }
+
+            // The pointer is only absent temporarily, within `node_mut`.
+            #[allow(clippy::expect_used)]
+            fn ptr(&self) -> &$ptr<N>
+            {
+                self.0.as_ref().expect("always present")
+            }
+
+            /// The node, when this is the only reference to it.  When the only other references
+            /// are weak, the node is first moved to a new allocation, which those do not refer
+            /// to, as `make_mut` does, because they would otherwise prevent this.
+            fn node_mut(&mut self) -> Option<&mut N>
+            {
+                if let Some(ptr) = self.0.take() {
+                    self.0 = Some(if $ptr::weak_count(&ptr) > 0 {
+                        $ptr::try_unwrap(ptr).map_or_else(convert::identity, $ptr::new)
+                    }
+                    else {
+                        ptr
+                    });
+                }
+                self.0.as_mut().and_then($ptr::get_mut)
+            }
+        }
+
+        impl<N> Clone for $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            #[inline]
+            fn clone(&self) -> Self
+            {
+                Self(Some($ptr::clone(self.ptr())))
+            }
+        }
+
+        impl<N> Borrow<N> for $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            #[inline]
+            fn borrow(&self) -> &N
+            {
+                self.ptr()
+            }
+        }
+
+        impl<N> Link<Self> for $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            #[inline]
+            fn get_mut(&mut self) -> &mut Self
+            {
+                self
+            }
+        }
+
+        impl<N> DeepSafeDrop<Self> for $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            #[inline]
+            fn set_parent_at_index_0(
+                &mut self,
+                parent: Self,
+            ) -> SetParent<Self>
+            {
+                match self.node_mut() {
+                    Some(node) => node.set_parent_at_index_0(parent),
+                    None => SetParent::No { returned_parent: parent },
+                }
+            }
+
+            #[inline]
+            fn take_child_at_index_0(&mut self) -> Option<Self>
+            {
+                self.node_mut().and_then(DeepSafeDrop::take_child_at_index_0)
+            }
+
+            #[inline]
+            fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+            {
+                self.node_mut().and_then(DeepSafeDrop::take_next_child_at_pos_index)
+            }
+        }
+
+        impl<N> Drop for $name<N>
+        where N: DeepSafeDrop<Self>
+        {
+            #[inline]
+            fn drop(&mut self)
+            {
+                deep_safe_drop::<Self, Self, Self>(self);
+            }
+        }
+    };
+}
+
+
+shared_link! {
+    /// A link that shares ownership of its node, via [`Rc`], with the `alloc` feature.
+    ///
+    /// This is also the node type of the traversal, which delegates to the `N` node only when
+    /// this is the only reference to it, i.e. when [`Rc::get_mut`] succeeds.  Otherwise, the node
+    /// is treated as a leaf, and so the traversal does not descend into shared subtrees, and
+    /// dropping this link merely decrements the reference count.  The last reference to be
+    /// dropped does descend, and so a node shared by multiple parents, as in a DAG, is dropped
+    /// exactly once.
+    ///
+    /// Dropping this link uses `deep_safe_drop`, so `N` does not need to implement [`Drop`]
+    /// itself.
+    ///
+    /// Back-references, e.g. to parents, should be [`WeakLink`]s, via [`RcLink::downgrade`], so
+    /// that they do not prevent this.
+    RcLink, Rc
+}
+
+impl<N> RcLink<N>
+where N: DeepSafeDrop<Self>
+{
+    /// Make a weak link to the same node, e.g. for a back-reference to a parent.
+    #[inline]
+    #[must_use]
+    pub fn downgrade(&self) -> WeakLink<N>
+    {
+        WeakLink(Rc::downgrade(self.ptr()))
+    }
+}
+
+
+shared_link! {
+    /// Like [`RcLink`] but via [`Arc`], and so is [`Send`] and [`Sync`] when `N` is.
+    ///
+    /// Another thread may clone or drop another reference concurrently, but that cannot cause a
+    /// node to be dropped twice nor leaked: [`Arc::get_mut`] only succeeds for the single owner,
+    /// which is then the only one that descends, and, when it does not succeed, dropping this
+    /// link merely decrements the reference count, and then whichever thread drops the last
+    /// reference does the descending.
+    ArcLink, Arc
+}
+
+
+/// A weak link to a node of [`RcLink`]s, via [`Weak`], with the `alloc` feature, e.g. for
+/// back-references to parents, which must not be followed when dropping.
+///
+/// This never owns its node, and so, as a node type of the traversal, it is always a leaf, and
+/// dropping it never drops its node.  A node that is only referred to by one `RcLink` and some
+/// `WeakLink`s is still dropped by the traversal, after which those fail to [`upgrade`].
+///
+/// [`upgrade`]: WeakLink::upgrade
+#[derive(Debug)]
+pub struct WeakLink<N>(Weak<N>)
+where N: DeepSafeDrop<RcLink<N>>;
+
+impl<N> WeakLink<N>
+where N: DeepSafeDrop<RcLink<N>>
+{
+    /// Make a strong link to the node, unless it was already dropped.
+    #[inline]
+    #[must_use]
+    pub fn upgrade(&self) -> Option<RcLink<N>>
+    {
+        self.0.upgrade().map(|ptr| RcLink(Some(ptr)))
+    }
+}
+
+impl<N> Clone for WeakLink<N>
+where N: DeepSafeDrop<RcLink<N>>
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        Self(Weak::clone(&self.0))
+    }
+}
+
+impl<N> Link<Self> for WeakLink<N>
+where N: DeepSafeDrop<RcLink<N>>
+{
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+impl<N> DeepSafeDrop<Self> for WeakLink<N>
+where N: DeepSafeDrop<RcLink<N>>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        SetParent::No { returned_parent: parent }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        None
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        None
+    }
+}
+
+
+/// Like [`RcLink`] but via [`Rc`] of [`RefCell`], with the `alloc` feature, for nodes that are
+/// mutated through any of their shared references.
+///
+/// The traversal delegates to the `N` node only when this is the only reference to it, i.e. when
+/// [`Rc::get_mut`] succeeds, which then gives the node without needing to borrow the `RefCell`.
+/// Otherwise, the node is treated as a leaf, and dropping this link merely decrements the
+/// reference count, and so a node shared by multiple parents, as in a DAG, is dropped exactly
+/// once, by the last reference to be dropped.
+///
+/// Dropping this link uses `deep_safe_drop`, so `N` does not need to implement [`Drop`] itself.
+#[derive(Debug)]
+pub struct SharedNode<N>(Rc<RefCell<N>>)
+where N: DeepSafeDrop<Self>;
+
+impl<N> SharedNode<N>
+where N: DeepSafeDrop<Self>
+{
+    /// Make a link to a new node.
+    #[inline]
+    #[must_use]
+    pub fn new(node: N) -> Self
+    {
+        Self(Rc::new(RefCell::new(node)))
+    }
+
+    /// Immutably borrow the node.
+    ///
+    /// # Panics
+    ///
+    /// If the node is currently mutably borrowed, as [`RefCell::borrow`] does.
+    #[inline]
+    #[must_use]
+    pub fn borrow(&self) -> Ref<'_, N>
+    {
+        RefCell::borrow(&self.0)
+    }
+
+    /// Mutably borrow the node, even though it might be shared.
+    ///
+    /// # Panics
+    ///
+    /// If the node is currently borrowed, as [`RefCell::borrow_mut`] does.
+    #[inline]
+    #[must_use]
+    pub fn borrow_mut(&self) -> RefMut<'_, N>
+    {
+        RefCell::borrow_mut(&self.0)
+    }
+
+    /// The node, when this is the only reference to it.
+    fn node_mut(&mut self) -> Option<&mut N>
+    {
+        Rc::get_mut(&mut self.0).map(RefCell::get_mut)
+    }
+}
+
+impl<N> Clone for SharedNode<N>
+where N: DeepSafeDrop<Self>
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<N> Link<Self> for SharedNode<N>
+where N: DeepSafeDrop<Self>
+{
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+impl<N> DeepSafeDrop<Self> for SharedNode<N>
+where N: DeepSafeDrop<Self>
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        match self.node_mut() {
+            Some(node) => node.set_parent_at_index_0(parent),
+            None => SetParent::No { returned_parent: parent },
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        self.node_mut().and_then(DeepSafeDrop::take_child_at_index_0)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        self.node_mut().and_then(DeepSafeDrop::take_next_child_at_pos_index)
+    }
+}
+
+impl<N> Drop for SharedNode<N>
+where N: DeepSafeDrop<Self>
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Self, Self, Self>(self);
+    }
+}
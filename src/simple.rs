@@ -0,0 +1,93 @@
+use crate::{
+    SetParent,
+    v2::DeepSafeDropAssoc,
+};
+
+
+/// A simpler alternative to implementing [`DeepSafeDrop`](crate::DeepSafeDrop), for node types
+/// that have only a single link, e.g. singly-linked lists.
+///
+/// `DeepSafeDrop` is then implemented for it, via [`DeepSafeDropAssoc`], and so via
+/// [`ParentSlot`](crate::ParentSlot) and [`SiblingLinks`](crate::SiblingLinks), where
+/// `set_parent_at_index_0` is done by `swap_with_parent`, `take_child_at_index_0` is done by
+/// `take_sole_child`, and there never are children at positive indices.  Like with those, the
+/// link type is an associated type.
+///
+/// # Example
+///
+/// ```
+/// use deep_safe_drop::{SimpleDeepSafeDrop, drop_boxed};
+///
+/// struct List(Option<Box<Self>>);
+///
+/// impl SimpleDeepSafeDrop for List
+/// {
+///     type Link = Box<Self>;
+///
+///     fn swap_with_parent(&mut self, parent: Box<Self>) -> Option<Box<Self>>
+///     {
+///         self.0.replace(parent)
+///     }
+///
+///     fn take_sole_child(&mut self) -> Option<Box<Self>>
+///     {
+///         self.0.take()
+///     }
+/// }
+///
+/// impl Drop for List
+/// {
+///     fn drop(&mut self)
+///     {
+///         drop_boxed(self);
+///     }
+/// }
+///
+/// let list = (0 .. 1_000_000).fold(List(None), |tail, _| List(Some(Box::new(tail))));
+/// drop(list);
+/// ```
+pub trait SimpleDeepSafeDrop
+{
+    /// Your tree link type that references or is `Self`.
+    type Link;
+
+    /// Replace the link with `parent`, and return the child that it previously linked to, if
+    /// any.
+    fn swap_with_parent(
+        &mut self,
+        parent: Self::Link,
+    ) -> Option<Self::Link>;
+
+    /// Take the link and replace it with a non-link.
+    fn take_sole_child(&mut self) -> Option<Self::Link>;
+}
+
+impl<N> DeepSafeDropAssoc for N
+where N: SimpleDeepSafeDrop + ?Sized
+{
+    type Link = N::Link;
+
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: N::Link,
+    ) -> SetParent<N::Link>
+    {
+        match self.swap_with_parent(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<N::Link>
+    {
+        self.take_sole_child()
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<N::Link>
+    {
+        None
+    }
+}
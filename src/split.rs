@@ -0,0 +1,72 @@
+use crate::{
+    DeepSafeDrop,
+    SetParent,
+};
+
+
+/// The part of [`DeepSafeDrop`] about the link at index 0, which is reused to link to the parent.
+///
+/// Implementing this and [`SiblingLinks`] for your node type is an alternative to implementing
+/// `DeepSafeDrop`, which is then implemented for it by combining them.  The link type is an
+/// associated type, instead of a type parameter like with `DeepSafeDrop`, because otherwise that
+/// combining would conflict with the implementations of `DeepSafeDrop` for node types that are
+/// generic over their link type, e.g. those of the [`helpers`](crate::helpers).
+///
+/// As an associated type, the link type is inferred from the node type, which is simpler for
+/// implementing and for calling `deep_safe_drop`, but a node type can only have a single link
+/// type, whereas it can implement `DeepSafeDrop` for multiple link types.  This is also why
+/// [`DeepSafeDropAssoc`](crate::v2::DeepSafeDropAssoc), which combines all the methods, is
+/// implemented via these: its own implementations of `DeepSafeDrop` would conflict with these.
+pub trait ParentSlot
+{
+    /// Your tree link type that references or is `Self`.
+    type Link;
+
+    /// Like [`DeepSafeDrop::set_parent_at_index_0`].
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self::Link,
+    ) -> SetParent<Self::Link>;
+
+    /// Like [`DeepSafeDrop::take_child_at_index_0`].
+    fn take_child_at_index_0(&mut self) -> Option<Self::Link>;
+}
+
+/// The part of [`DeepSafeDrop`] about the links at indices greater than or equal to 1.
+///
+/// The provided method supplies no children, and so, for list-like nodes that only have a link at
+/// index 0, implementing this only needs an empty `impl` block.
+pub trait SiblingLinks: ParentSlot
+{
+    /// Like [`DeepSafeDrop::take_next_child_at_pos_index`].
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self::Link>
+    {
+        None
+    }
+}
+
+impl<T> DeepSafeDrop<T::Link> for T
+where T: SiblingLinks + ?Sized
+{
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: T::Link,
+    ) -> SetParent<T::Link>
+    {
+        ParentSlot::set_parent_at_index_0(self, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<T::Link>
+    {
+        ParentSlot::take_child_at_index_0(self)
+    }
+
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<T::Link>
+    {
+        SiblingLinks::take_next_child_at_pos_index(self)
+    }
+}
@@ -0,0 +1,41 @@
+/// The assumed stack cost of a frame of a recursive `Drop`, other than its link: return address,
+/// saved registers, and local variables.  A heuristic for typical calling conventions.
+const FRAME_OVERHEAD_BYTES: usize = 128;
+
+/// The main-thread stack size of common platforms.
+const DEFAULT_STACK_BYTES: usize = 8 * 1024 * 1024;
+
+
+/// Estimate of the stack bytes that each level of recursion of a naive recursive `Drop` would
+/// use, for a link type of the given size.
+///
+/// This is only advisory, not a guarantee.  The actual cost depends on the platform, the
+/// optimization level, and the `Drop` implementations involved.
+#[inline]
+#[must_use]
+pub const fn required_stack_bytes_per_level(link_size: usize) -> usize
+{
+    FRAME_OVERHEAD_BYTES.saturating_add(link_size)
+}
+
+
+/// Whether a naive recursive `Drop` of a tree of the given depth would be estimated to overflow
+/// an 8 MiB stack, i.e. whether you need `deep_safe_drop`.
+///
+/// This is only advisory, like [`required_stack_bytes_per_level`], but it is `const` and so it
+/// can be used to fail compilation:
+///
+/// ```
+/// # use {core::mem::size_of, deep_safe_drop::would_overflow_default_stack};
+/// const MAX_TREE_DEPTH: usize = 1000;
+/// const _: () = assert!(!would_overflow_default_stack(MAX_TREE_DEPTH, size_of::<Box<u8>>()));
+/// ```
+#[inline]
+#[must_use]
+pub const fn would_overflow_default_stack(
+    depth: usize,
+    link_size: usize,
+) -> bool
+{
+    depth.saturating_mul(required_stack_bytes_per_level(link_size)) > DEFAULT_STACK_BYTES
+}
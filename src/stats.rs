@@ -0,0 +1,67 @@
+use crate::{
+    DeepSafeDrop,
+    into_post_order,
+};
+
+
+/// Metrics of the traversal done by [`deep_safe_drop_stats`].
+///
+/// The depth of a child of the root is 1.  The root itself is not counted, because its dropping
+/// is completed by your [`Drop::drop`] implementation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct DropStatistics
+{
+    /// Amount of nodes that were dropped.
+    pub nodes_dropped:     usize,
+    /// Greatest depth of any node that was dropped.
+    pub max_depth_reached: usize,
+    /// Amount of nodes that had no children.
+    pub leaf_nodes:        usize,
+    /// Amount of nodes that had at least one child.
+    pub internal_nodes:    usize,
+}
+
+impl DropStatistics
+{
+    pub(crate) fn count_node(
+        &mut self,
+        has_children: bool,
+    )
+    {
+        let count = if has_children { &mut self.internal_nodes } else { &mut self.leaf_nodes };
+        *count = count.saturating_add(1);
+    }
+
+    pub(crate) fn merge(
+        &mut self,
+        other: Self,
+    )
+    {
+        self.nodes_dropped = self.nodes_dropped.saturating_add(other.nodes_dropped);
+        self.max_depth_reached = self.max_depth_reached.max(other.max_depth_reached);
+        self.leaf_nodes = self.leaf_nodes.saturating_add(other.leaf_nodes);
+        self.internal_nodes = self.internal_nodes.saturating_add(other.internal_nodes);
+    }
+}
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but also returns metrics of the traversal,
+/// e.g. for asserting the expected shape of a tree in tests.
+#[inline]
+#[must_use = "use `deep_safe_drop` when the statistics are not needed"]
+pub fn deep_safe_drop_stats<RootNode, Link, Node>(root: &mut RootNode) -> DropStatistics
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut stats = DropStatistics::default();
+    while let Some(next_child) = root.take_next_child_at_any_index() {
+        let mut leaves = into_post_order(next_child);
+        leaves.by_ref().for_each(drop);
+        stats.merge(leaves.statistics());
+    }
+    stats
+}
@@ -0,0 +1,45 @@
+use crate::DeepSafeDrop;
+
+
+/// Take the subtree at index 0 of `node`, without dropping it, e.g. to move it elsewhere via
+/// [`attach_subtree_at_index_0`].
+#[inline]
+pub fn detach_subtree_at_index_0<N, L>(node: &mut N) -> Option<L>
+where N: DeepSafeDrop<L> + ?Sized
+{
+    node.take_child_at_index_0()
+}
+
+
+/// Take the subtree at index `i` of `node`, without dropping it, via
+/// [`DeepSafeDrop::take_child_by_index`], whose default implementation drops the children that
+/// it takes before index `i`.
+#[inline]
+pub fn detach_subtree_at_index<N, L>(
+    node: &mut N,
+    i: usize,
+) -> Option<L>
+where
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    node.take_child_by_index(i)
+}
+
+
+/// Store `child` as the subtree at index 0 of `node`, via
+/// [`DeepSafeDrop::store_child_at_index_0`].
+///
+/// This should only be called when `node` has no child at index 0, e.g. after
+/// [`detach_subtree_at_index_0`].  Return the subtree that was replaced, if any, or return
+/// `child` back if it could not be stored because `node` has no links.
+#[inline]
+#[must_use]
+pub fn attach_subtree_at_index_0<N, L>(
+    node: &mut N,
+    child: L,
+) -> Option<L>
+where
+    N: DeepSafeDrop<L> + ?Sized,
+{
+    node.store_child_at_index_0(child)
+}
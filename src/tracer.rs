@@ -0,0 +1,83 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        deep_safe_drop_with,
+    },
+    alloc::vec::Vec,
+};
+
+
+/// Records the sequence of the nodes dropped by [`deep_safe_drop_traced`], as their addresses,
+/// with the `alloc` feature, for verifying the order of dropping in tests.
+///
+/// The addresses are captured while the nodes are still alive, and they are only opaque IDs,
+/// which is why they are `usize` and so this is [`Send`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DropTracer
+{
+    sequence: Vec<usize>,
+}
+
+impl DropTracer
+{
+    /// Make an empty tracer.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// The addresses of the nodes, in the order they were dropped.
+    #[inline]
+    #[must_use]
+    pub fn sequence(&self) -> &[usize]
+    {
+        &self.sequence
+    }
+
+    /// The address of `node`, as recorded.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::as_conversions)] // Only an opaque ID.
+    pub fn address_of<N: ?Sized>(node: &N) -> usize
+    {
+        let ptr: *const N = node;
+        ptr.cast::<()>() as usize
+    }
+
+    /// Assert that the recorded sequence of addresses is `expected`, e.g. the addresses of the
+    /// nodes in post-order, as given by [`DropTracer::address_of`], taken before dropping.
+    ///
+    /// # Panics
+    ///
+    /// If the recorded sequence differs from `expected`.
+    #[inline]
+    #[allow(clippy::missing_assert_message)] // The values are the message.
+    pub fn assert_matches_post_order(
+        &self,
+        expected: &[usize],
+    )
+    {
+        assert_eq!(self.sequence(), expected);
+    }
+}
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but also records the address of every node,
+/// just before it is dropped, in `tracer`, with the `alloc` feature.
+///
+/// Like [`deep_safe_drop_with`], the `root` itself is not recorded.
+#[inline]
+pub fn deep_safe_drop_traced<RootNode, Link, Node>(
+    root: &mut RootNode,
+    tracer: &mut DropTracer,
+) where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    deep_safe_drop_with(root, |node: &mut Node| {
+        tracer.sequence.push(DropTracer::address_of(node));
+    });
+}
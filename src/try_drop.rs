@@ -0,0 +1,68 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        DropError,
+        DropResult,
+        DropStatistics,
+        into_post_order,
+    },
+    alloc::vec::Vec,
+    core::panic::AssertUnwindSafe,
+    std::panic::{
+        catch_unwind,
+        resume_unwind,
+    },
+};
+
+
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop) but catches panics from the dropping of each
+/// node, and continues with the remaining nodes, so that as many nodes as possible are dropped,
+/// with the `std` feature.
+///
+/// Also, in debug builds, when a cycle is detected, that is caught, and the remaining nodes of
+/// that child of the `root` are dropped as during unwinding, and the remaining children are
+/// continued with.  Other panics of the traversal itself, i.e. of your `DeepSafeDrop`
+/// implementations, are propagated.
+///
+/// # Errors
+///
+/// If the dropping of any nodes panicked, [`DropError::NodePanickedAt`] with the payloads of
+/// those panics, and [`DropError::CycleDetectedAt`] for any cycles, in the order that they
+/// occurred.
+#[inline]
+pub fn try_deep_safe_drop<RootNode, Link, Node>(root: &mut RootNode) -> DropResult
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut stats = DropStatistics::default();
+    let mut errors = Vec::new();
+
+    while let Some(next_child) = root.take_next_child_at_any_index() {
+        let mut leaves = into_post_order(next_child);
+        loop {
+            // After a panic of the traversal, its state is already dropped, and so it is done.
+            let leaf = match catch_unwind(AssertUnwindSafe(|| leaves.next())) {
+                Ok(Some(leaf)) => leaf,
+                Ok(None) => break,
+                Err(payload) => match leaves.cycle_detected_at() {
+                    Some(node_ptr) => {
+                        errors.push(DropError::CycleDetectedAt { node_ptr });
+                        break;
+                    },
+                    None => resume_unwind(payload),
+                },
+            };
+            let depth = leaves.leaf_depth();
+            // Unwind safety is not a concern, because the `leaf` is gone either way, and the rest
+            // of the tree is not observed by its dropping.
+            if let Err(payload) = catch_unwind(AssertUnwindSafe(|| drop(leaf))) {
+                errors.push(DropError::NodePanickedAt { depth, payload });
+            }
+        }
+        stats.merge(leaves.statistics());
+    }
+
+    if errors.is_empty() { Ok(stats) } else { Err(errors) }
+}
@@ -0,0 +1,49 @@
+use {
+    crate::{
+        DeepSafeDrop,
+        into_post_order,
+    },
+    alloc::vec::Vec,
+};
+
+
+/// The first phase of dropping a tree in two phases, with the `alloc` feature: dismantle the
+/// `root`'s tree entirely, and return the links to all its nodes, in post-order, without dropping
+/// any of them.
+///
+/// Each returned link is to a node from which all its children have already been extracted, i.e.
+/// a leaf, and so dropping it is safe from stack overflow.  The traversal is the same
+/// link-reversal that [`deep_safe_drop`](fn@crate::deep_safe_drop) does, and so it does not
+/// allocate, other than the growing of the returned `Vec`.  Arbitrary code, e.g. a debug pass
+/// that inspects the nodes, may be run before the second phase, [`phase2_drop_vec`].
+///
+/// Like [`deep_safe_drop`](fn@crate::deep_safe_drop), the `root` itself is not included, because
+/// its dropping is completed by your [`Drop::drop`] implementation.
+#[inline]
+#[must_use = "the nodes are dropped when the returned links are, which is the second phase"]
+pub fn phase1_extract_all<RootNode, Link, Node>(root: &mut RootNode) -> Vec<Link>
+where
+    RootNode: DeepSafeDrop<Link> + ?Sized,
+    Link: crate::Link<Node>,
+    Node: DeepSafeDrop<Link> + ?Sized,
+{
+    let mut links = Vec::new();
+    while let Some(next_child) = root.take_next_child_at_any_index() {
+        links.extend(into_post_order(next_child));
+    }
+    links
+}
+
+
+/// The second phase of dropping a tree in two phases, with the `alloc` feature: drop each of the
+/// `links` returned by [`phase1_extract_all`], one at a time, in their order.
+///
+/// This is only a simple loop, because the nodes are already leaves.  Together, the two phases
+/// drop the nodes in the same order as [`deep_safe_drop`](fn@crate::deep_safe_drop) does.
+#[inline]
+pub fn phase2_drop_vec<Link>(links: Vec<Link>)
+{
+    for link in links {
+        drop(link);
+    }
+}
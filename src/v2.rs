@@ -0,0 +1,176 @@
+//! A design of [`DeepSafeDrop`](crate::DeepSafeDrop) with the link type as an associated type,
+//! instead of as a type parameter.
+//!
+//! An associated link type is inferred from the node type, and so calling `deep_safe_drop` needs
+//! no annotations, and implementing [`DeepSafeDropAssoc`] does not repeat `<Link>` on every
+//! method nor `impl` block.  But a node type can then only have a single link type, whereas it
+//! can implement `DeepSafeDrop` for multiple link types, e.g. for both `Box` and `Rc` links.
+//!
+//! `DeepSafeDrop` is implemented, for your node type that implements `DeepSafeDropAssoc`, via
+//! [`ParentSlot`] and [`SiblingLinks`], which are implemented for it, and so your node type may
+//! implement either `DeepSafeDropAssoc` or those, but not both.  This is because a direct
+//! implementation of `DeepSafeDrop` would conflict with the one for those.  Likewise,
+//! `DeepSafeDropAssoc` is implemented for your node type that implements
+//! [`SimpleDeepSafeDrop`](crate::SimpleDeepSafeDrop).
+//!
+//! The `List` and `BinaryTree` of the tests, with this design:
+//!
+//! ```
+//! use deep_safe_drop::{
+//!     SetParent,
+//!     deep_safe_drop,
+//!     v2::DeepSafeDropAssoc,
+//! };
+//!
+//! struct List
+//! {
+//!     next: Option<Box<Self>>,
+//! }
+//!
+//! impl DeepSafeDropAssoc for List
+//! {
+//!     type Link = Box<Self>;
+//!
+//!     fn set_parent_at_index_0(
+//!         &mut self,
+//!         parent: Box<Self>,
+//!     ) -> SetParent<Box<Self>>
+//!     {
+//!         match self.next.replace(parent) {
+//!             Some(child0) => SetParent::YesReplacedChild { child0 },
+//!             None => SetParent::Yes,
+//!         }
+//!     }
+//!
+//!     fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+//!     {
+//!         self.next.take()
+//!     }
+//!
+//!     fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+//!     {
+//!         None
+//!     }
+//! }
+//!
+//! impl Drop for List
+//! {
+//!     fn drop(&mut self)
+//!     {
+//!         deep_safe_drop(self);
+//!     }
+//! }
+//!
+//! struct BinaryTree
+//! {
+//!     left:  Option<Box<Self>>,
+//!     right: Option<Box<Self>>,
+//! }
+//!
+//! impl DeepSafeDropAssoc for BinaryTree
+//! {
+//!     type Link = Box<Self>;
+//!
+//!     fn set_parent_at_index_0(
+//!         &mut self,
+//!         parent: Box<Self>,
+//!     ) -> SetParent<Box<Self>>
+//!     {
+//!         match self.left.replace(parent) {
+//!             Some(child0) => SetParent::YesReplacedChild { child0 },
+//!             None => SetParent::Yes,
+//!         }
+//!     }
+//!
+//!     fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+//!     {
+//!         self.left.take()
+//!     }
+//!
+//!     fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+//!     {
+//!         self.right.take()
+//!     }
+//! }
+//!
+//! impl Drop for BinaryTree
+//! {
+//!     fn drop(&mut self)
+//!     {
+//!         deep_safe_drop(self);
+//!     }
+//! }
+//!
+//! let list = (0 .. 100_000).fold(List { next: None }, |acc, _| List {
+//!     next: Some(Box::new(acc)),
+//! });
+//! drop(list);
+//!
+//! let leaf = || Some(Box::new(BinaryTree { left: None, right: None }));
+//! let tree = (0 .. 100_000).fold(BinaryTree { left: None, right: None }, |acc, _| BinaryTree {
+//!     left:  Some(Box::new(acc)),
+//!     right: leaf(),
+//! });
+//! drop(tree);
+//! ```
+
+use crate::{
+    ParentSlot,
+    SetParent,
+    SiblingLinks,
+};
+
+
+/// Like [`DeepSafeDrop`](crate::DeepSafeDrop), but with the link type as an associated type.
+///
+/// See the [module-level documentation](self) for the trade-offs.
+pub trait DeepSafeDropAssoc
+{
+    /// Your tree link type that references or is `Self`.
+    type Link;
+
+    /// Like [`DeepSafeDrop::set_parent_at_index_0`](crate::DeepSafeDrop::set_parent_at_index_0).
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self::Link,
+    ) -> SetParent<Self::Link>;
+
+    /// Like [`DeepSafeDrop::take_child_at_index_0`](crate::DeepSafeDrop::take_child_at_index_0).
+    fn take_child_at_index_0(&mut self) -> Option<Self::Link>;
+
+    /// Like [`DeepSafeDrop::take_next_child_at_pos_index`][take_next].
+    ///
+    /// [take_next]: crate::DeepSafeDrop::take_next_child_at_pos_index
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self::Link>;
+}
+
+impl<T> ParentSlot for T
+where T: DeepSafeDropAssoc + ?Sized
+{
+    type Link = T::Link;
+
+    #[inline]
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: T::Link,
+    ) -> SetParent<T::Link>
+    {
+        DeepSafeDropAssoc::set_parent_at_index_0(self, parent)
+    }
+
+    #[inline]
+    fn take_child_at_index_0(&mut self) -> Option<T::Link>
+    {
+        DeepSafeDropAssoc::take_child_at_index_0(self)
+    }
+}
+
+impl<T> SiblingLinks for T
+where T: DeepSafeDropAssoc + ?Sized
+{
+    #[inline]
+    fn take_next_child_at_pos_index(&mut self) -> Option<T::Link>
+    {
+        DeepSafeDropAssoc::take_next_child_at_pos_index(self)
+    }
+}
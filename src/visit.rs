@@ -0,0 +1,77 @@
+use {
+    crate::ReadableChildren,
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+};
+
+
+/// Callbacks of [`visit_tree`], for each node, both before and after its descendants are
+/// visited.
+///
+/// The `depth` of the root is 0.  Both methods do nothing by default, so that only the needed
+/// one can be implemented.
+pub trait TreeVisitor<N: ?Sized>
+{
+    /// Called on `node` before any of its descendants, i.e. in pre-order.
+    #[inline]
+    fn pre_visit(
+        &mut self,
+        node: &N,
+        depth: usize,
+    )
+    {
+        let _: (&N, usize) = (node, depth);
+    }
+
+    /// Called on `node` after all of its descendants, i.e. in post-order.
+    #[inline]
+    fn post_visit(
+        &mut self,
+        node: &N,
+        depth: usize,
+    )
+    {
+        let _: (&N, usize) = (node, depth);
+    }
+}
+
+
+/// Visit the nodes of a deep tree, without modifying it, and without stack overflow, calling
+/// both the pre-order and the post-order callbacks of `visitor`, with the `alloc` feature.
+///
+/// Like [`pre_order`](crate::pre_order), this uses an explicit stack, which grows by the amount
+/// of children of each node along the deepest path, where each node is pushed once to be
+/// pre-visited and then again to be post-visited after all its children are popped.
+///
+/// See the tests for an example of a visitor that computes the height of a tree, by propagating
+/// the heights of the children upward in its `post_visit`.
+#[inline]
+pub fn visit_tree<L, N, V>(
+    root: &L,
+    visitor: &mut V,
+) where
+    L: Borrow<N>,
+    N: ReadableChildren<L> + ?Sized,
+    V: TreeVisitor<N> + ?Sized,
+{
+    // Whether each node has already been pre-visited, i.e. is ready to be post-visited.
+    let mut stack = Vec::from([(root, 0_usize, false)]);
+
+    while let Some((link, depth, visited)) = stack.pop() {
+        let node = link.borrow();
+        if visited {
+            visitor.post_visit(node, depth);
+        }
+        else {
+            visitor.pre_visit(node, depth);
+            stack.push((link, depth, true));
+            let start = stack.len();
+            let child_depth = depth.saturating_add(1);
+            stack.extend(node.children_of().map(|child| (child, child_depth, false)));
+            // So that the first child is popped next.
+            if let Some(children) = stack.get_mut(start ..) {
+                children.reverse();
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use {
+    super::{
+        nary::NaryBox,
+        node::Node,
+        *,
+    },
+    alloc::collections::BTreeMap,
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+/// Each node with the seeds of its children, which are the two previous Fibonacci numbers.
+fn fibonacci(n: u32) -> (NaryNode<NaryBox>, Vec<u32>)
+{
+    let children =
+        if n < 2 { Vec::new() } else { Vec::from([n.saturating_sub(1), n.saturating_sub(2)]) };
+    (NaryNode::default(), children)
+}
+
+
+#[test]
+fn fibonacci_tree()
+{
+    let tree: NaryBox = ana(20, fibonacci);
+    // Twice the 21st Fibonacci number, minus 1.
+    assert_eq!(count_nodes::<_, NaryNode<NaryBox>>(&tree), 21_891);
+}
+
+
+#[test]
+fn deep_list()
+{
+    let tree: NaryBox = ana(TREE_SIZE, |len: usize| {
+        let children = if len > 1 { Vec::from([len.saturating_sub(1)]) } else { Vec::new() };
+        (NaryNode::default(), children)
+    });
+    assert_eq!(count_nodes::<_, NaryNode<NaryBox>>(&tree), TREE_SIZE);
+}
+
+
+/// The first child is the left, and the second is the right.
+impl Extend<Box<Self>> for Node
+{
+    fn extend<I: IntoIterator<Item = Box<Self>>>(
+        &mut self,
+        iter: I,
+    )
+    {
+        let mut children = iter.into_iter();
+        self.left = children.next();
+        self.right = children.next();
+    }
+}
+
+
+#[test]
+fn balanced_from_range()
+{
+    let len = TREE_SIZE.saturating_sub(1);
+    let tree: Box<Node> = ana((0, len), |(lo, hi): (usize, usize)| {
+        let mid = lo.saturating_add(hi.saturating_sub(lo).div_euclid(2));
+        let node = Node::new(mid, None, None);
+        let halves = [(lo, mid), (mid.saturating_add(1), hi)];
+        (node, halves.into_iter().filter(|(start, end)| start < end).collect())
+    });
+
+    let (sum, height) = cata::<_, Node, _, _>(tree, |node, children: Vec<(usize, usize)>| {
+        children.into_iter().fold((node.id, 1), |(sum, height), (child_sum, child_height)| {
+            (sum.saturating_add(child_sum), height.max(child_height.saturating_add(1)))
+        })
+    });
+    assert_eq!(sum, (0 .. len).sum::<usize>());
+    assert_eq!(height, 20);
+}
+
+
+/// A trie in the left-child right-sibling representation.
+#[derive(DeepSafeDrop)]
+struct Trie
+{
+    #[child(index = 0)]
+    first_child:  Option<Box<Self>>,
+    #[child(index = 1)]
+    next_sibling: Option<Box<Self>>,
+    symbol:       char,
+    is_terminal:  bool,
+}
+
+impl Drop for Trie
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+/// Chains the children as siblings.
+impl Extend<Box<Self>> for Trie
+{
+    fn extend<I: IntoIterator<Item = Box<Self>>>(
+        &mut self,
+        iter: I,
+    )
+    {
+        let children: Vec<Box<Self>> = iter.into_iter().collect();
+        self.first_child = children.into_iter().rev().fold(None, |next_sibling, mut child| {
+            child.next_sibling = next_sibling;
+            Some(child)
+        });
+    }
+}
+
+
+#[test]
+fn trie_from_words()
+{
+    let words = Vec::from(["a", "an", "and", "ant", "bee"]);
+    let trie: Box<Trie> = ana(('^', words), |(symbol, suffixes): (char, Vec<&str>)| {
+        let is_terminal = suffixes.contains(&"");
+        let mut by_first: BTreeMap<char, Vec<&str>> = BTreeMap::new();
+        for suffix in suffixes {
+            let mut chars = suffix.chars();
+            if let Some(first) = chars.next() {
+                by_first.entry(first).or_default().push(chars.as_str());
+            }
+        }
+        let node = Trie { first_child: None, next_sibling: None, symbol, is_terminal };
+        (node, by_first.into_iter().collect())
+    });
+
+    let (nodes, terminals, symbols) =
+        cata::<_, Trie, _, _>(trie, |node, children: Vec<(usize, usize, String)>| {
+            let init = (1, usize::from(node.is_terminal), String::from(node.symbol));
+            children.into_iter().fold(init, |(nodes, terminals, mut symbols), child| {
+                symbols.push_str(&child.2);
+                (nodes.saturating_add(child.0), terminals.saturating_add(child.1), symbols)
+            })
+        });
+    assert_eq!(nodes, 8);
+    assert_eq!(terminals, 5);
+    assert_eq!(symbols, "^andtbee");
+}
@@ -0,0 +1,58 @@
+use {
+    super::*,
+    alloc::sync::Arc,
+    core::sync::atomic::{
+        AtomicUsize,
+        Ordering::Relaxed,
+    },
+    std::thread,
+};
+
+
+struct Counted(Arc<AtomicUsize>);
+
+impl Drop for Counted
+{
+    fn drop(&mut self)
+    {
+        let _prev = self.0.fetch_add(1, Relaxed);
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:     Option<ArcLink<Self>>,
+    _counted: Counted,
+}
+
+fn make_list(
+    len: usize,
+    tail: Option<ArcLink<Node>>,
+    drops: &Arc<AtomicUsize>,
+) -> Option<ArcLink<Node>>
+{
+    (0 .. len).fold(tail, |next, _| {
+        Some(ArcLink::new(Node { next, _counted: Counted(Arc::clone(drops)) }))
+    })
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn shared_across_threads()
+{
+    let drops = Arc::new(AtomicUsize::new(0));
+    let len = TREE_SIZE.div_euclid(4);
+    let shared = make_list(len, None, &drops);
+    let first = make_list(len, shared.clone(), &drops);
+    let second = make_list(len, shared, &drops);
+
+    thread::spawn(move || drop(first)).join().expect("join");
+    assert_eq!(drops.load(Relaxed), len);
+
+    thread::spawn(move || drop(second)).join().expect("join");
+    assert_eq!(drops.load(Relaxed), 3 * len);
+}
@@ -0,0 +1,78 @@
+use {
+    super::*,
+    alloc::sync::Arc,
+    core::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+
+/// Counts the nodes dropped.
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:  Option<Box<Self>>,
+    drops: Arc<AtomicUsize>,
+    panic: bool,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        let _prev = self.drops.fetch_add(1, Ordering::Relaxed);
+        assert!(!self.panic, "intentional");
+    }
+}
+
+#[allow(clippy::unnecessary_box_returns)] // The link type is what is sent.
+fn make_list(
+    drops: &Arc<AtomicUsize>,
+    panic: bool,
+) -> Box<Node>
+{
+    let node = |next| Node { next, drops: Arc::clone(drops), panic };
+    (1 .. TREE_SIZE).fold(Box::new(node(None)), |acc, _| Box::new(node(Some(acc))))
+}
+
+
+#[test]
+fn drops_all()
+{
+    let drops = Arc::new(AtomicUsize::new(0));
+    let queue = BackgroundDropQueue::<_, Node>::new();
+    for _ in 0 .. 3 {
+        assert_eq!(queue.send(make_list(&drops, false)).map_err(drop), Ok(()));
+    }
+    assert_eq!(queue.finish().map_err(drop), Ok(()));
+    assert_eq!(drops.load(Ordering::Relaxed), 3 * TREE_SIZE);
+}
+
+
+#[test]
+fn drop_waits()
+{
+    let drops = Arc::new(AtomicUsize::new(0));
+    {
+        let queue = BackgroundDropQueue::<_, Node>::new();
+        assert_eq!(queue.send(make_list(&drops, false)).map_err(drop), Ok(()));
+    }
+    assert_eq!(drops.load(Ordering::Relaxed), TREE_SIZE);
+}
+
+
+#[test]
+fn worker_panics()
+{
+    let drops = Arc::new(AtomicUsize::new(0));
+    let queue = BackgroundDropQueue::<_, Node>::new();
+    assert_eq!(
+        queue
+            .send(Box::new(Node { next: None, drops: Arc::clone(&drops), panic: true }))
+            .map_err(drop),
+        Ok(())
+    );
+    assert!(queue.finish().is_err());
+}
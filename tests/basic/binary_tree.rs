@@ -9,7 +9,7 @@ pub(super) struct BinaryTree<L>
 
 impl<L> BinaryTree<L>
 {
-    fn make_fan(depth: usize) -> Self
+    pub(super) fn make_fan(depth: usize) -> Self
     where L: NewLink<Self>
     {
         let mut fan = Self { left: None, right: None };
@@ -48,14 +48,32 @@ impl<L> DeepSafeDrop<L> for BinaryTree<L>
     {
         self.right.take()
     }
+
+    fn child_count_hint(&self) -> Option<usize>
+    {
+        Some([&self.left, &self.right].into_iter().flatten().count())
+    }
+}
+
+
+#[allow(clippy::arithmetic_side_effects)] // The `assert!` prevents overflows.
+pub(super) fn fan_depth(size: usize) -> usize
+{
+    use core::convert::TryInto as _;
+
+    fn log2(x: usize) -> u32
+    {
+        (usize::BITS - 1) - x.leading_zeros()
+    }
+    assert!(0 < size && size < usize::MAX);
+    #[allow(clippy::expect_used)]
+    (log2(size + 1) - 1).try_into().expect("impossible")
 }
 
 
 #[test]
 fn exercise()
 {
-    use core::convert::TryInto;
-
     struct BinaryTreeBox(Box<BinaryTree<Self>>);
 
     impl NewLink<BinaryTree<Self>> for BinaryTreeBox
@@ -82,17 +100,6 @@ fn exercise()
         }
     }
 
-    fn fan_depth(size: usize) -> usize
-    {
-        fn log2(x: usize) -> u32
-        {
-            (usize::BITS - 1) - x.leading_zeros()
-        }
-        assert!(0 < size && size < usize::MAX);
-        #[allow(clippy::expect_used)]
-        (log2(size + 1) - 1).try_into().expect("impossible")
-    }
-
     let fan = BinaryTree::<BinaryTreeBox>::make_fan(fan_depth(TREE_SIZE));
     drop(fan);
 }
@@ -0,0 +1,140 @@
+use super::*;
+
+
+/// A node type whose data is borrowed from outside the tree, which must outlive the tree.
+struct BorrowingNode<'a, L>
+{
+    data:  &'a str,
+    child: Option<L>,
+}
+
+impl<L> DeepSafeDrop<L> for BorrowingNode<'_, L>
+{
+    fn take_child_at_index_0(&mut self) -> Option<L>
+    {
+        self.child.take()
+    }
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: L,
+    ) -> SetParent<L>
+    {
+        if let Some(child) = self.child.take() {
+            self.child = Some(parent);
+            SetParent::YesReplacedChild { child0: child }
+        }
+        else {
+            SetParent::No { returned_parent: parent }
+        }
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<L>
+    {
+        None
+    }
+}
+
+struct BorrowingBox<'a>(Box<BorrowingNode<'a, Self>>);
+
+impl<'a> BorrowingBox<'a>
+{
+    fn new(
+        data: &'a str,
+        child: Option<Self>,
+    ) -> Self
+    {
+        Self(Box::new(BorrowingNode { data, child }))
+    }
+}
+
+impl<'a> Link<BorrowingNode<'a, Self>> for BorrowingBox<'a>
+{
+    fn get_mut(&mut self) -> &mut BorrowingNode<'a, Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for BorrowingBox<'_>
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<BorrowingNode<'_, Self>, Self, BorrowingNode<'_, Self>>(&mut *self.0);
+    }
+}
+
+
+/// A node type that owns its data, for other trees to borrow from.
+struct OwningNode
+{
+    text: String,
+    next: Option<Box<Self>>,
+}
+
+impl SimpleDeepSafeDrop for OwningNode
+{
+    type Link = Box<Self>;
+
+    fn swap_with_parent(
+        &mut self,
+        parent: Box<Self>,
+    ) -> Option<Box<Self>>
+    {
+        self.next.replace(parent)
+    }
+
+    fn take_sole_child(&mut self) -> Option<Box<Self>>
+    {
+        self.next.take()
+    }
+}
+
+impl Drop for OwningNode
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+fn borrowed_data_outlives_drop()
+{
+    let arena = String::from("borrowed");
+
+    let list = (0 .. TREE_SIZE)
+        .fold(BorrowingBox::new(&arena, None), |acc, _| BorrowingBox::new(&arena, Some(acc)));
+    assert_eq!(list.0.data, "borrowed");
+    drop(list);
+
+    // Still accessible, and unchanged, after the tree that borrowed it is dropped.
+    assert_eq!(arena, "borrowed");
+}
+
+
+/// Nodes can only borrow from nodes of another tree, that outlives theirs, because borrowing from
+/// nodes of the same tree would prevent moving them into the tree.
+#[test]
+fn borrowed_from_other_tree()
+{
+    let owner = (0 .. TREE_SIZE)
+        .fold(None, |next, i| Some(Box::new(OwningNode { text: i.to_string(), next })));
+
+    let mut borrower = None;
+    let mut cur = owner.as_deref();
+    while let Some(node) = cur {
+        borrower = Some(BorrowingBox::new(&node.text, borrower));
+        cur = node.next.as_deref();
+    }
+    // The last-borrowed is the first-made.
+    assert_eq!(borrower.as_ref().map(|b| b.0.data), Some("0"));
+    drop(borrower);
+
+    assert_eq!(
+        owner.as_ref().map(|o| o.text.as_str()),
+        Some((TREE_SIZE - 1).to_string().as_str())
+    );
+    drop(owner);
+}
@@ -0,0 +1,73 @@
+use {
+    super::{
+        node::RecordedNode,
+        *,
+    },
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+/// A complete binary tree of the given height, whose IDs are in pre-order from `next_id`.
+fn make_complete(
+    height: usize,
+    next_id: &mut usize,
+    dropped: &Rc<RefCell<Vec<usize>>>,
+) -> Option<Box<RecordedNode>>
+{
+    (height > 0).then(|| {
+        let id = *next_id;
+        *next_id = next_id.saturating_add(1);
+        let left = make_complete(height.saturating_sub(1), next_id, dropped);
+        let right = make_complete(height.saturating_sub(1), next_id, dropped);
+        Box::new(RecordedNode { left, right, id, dropped: Rc::clone(dropped) })
+    })
+}
+
+fn make_list(
+    len: usize,
+    dropped: &Rc<RefCell<Vec<usize>>>,
+) -> RecordedNode
+{
+    let node = |left, id| RecordedNode { left, right: None, id, dropped: Rc::clone(dropped) };
+    (1 .. len).fold(node(None, 0), |acc, id| node(Some(Box::new(acc)), id))
+}
+
+
+#[test]
+fn same_order_as_deep_safe_drop()
+{
+    let height = 20;
+
+    let bounded = Rc::new(RefCell::new(Vec::new()));
+    if let Some(root) = make_complete(height, &mut 0, &bounded) {
+        bounded_stack_drop::<_, RecordedNode, 32>(root);
+    }
+
+    let linked = Rc::new(RefCell::new(Vec::new()));
+    drop(make_complete(height, &mut 0, &linked));
+
+    assert_eq!(bounded.borrow().len(), (1 << height) - 1);
+    assert_eq!(*bounded.borrow(), *linked.borrow());
+}
+
+
+#[test]
+fn exact_depth()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+
+    bounded_stack_drop::<_, RecordedNode, 30>(Box::new(make_list(30, &dropped)));
+
+    assert!(dropped.borrow().iter().copied().eq(0 .. 30));
+}
+
+
+#[test]
+#[should_panic = "depth limit exceeded; increase STACK_SIZE"]
+fn depth_limit_exceeded()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+
+    bounded_stack_drop::<_, RecordedNode, 30>(Box::new(make_list(31, &dropped)));
+}
@@ -0,0 +1,107 @@
+use super::{
+    binary_tree::{
+        BinaryTree,
+        fan_depth,
+    },
+    list::List,
+    *,
+};
+
+
+/// Newtypes are needed so that the node types can refer to themselves as `Box<Self>`.
+struct ListNode(List<Box<Self>>);
+
+impl DeepSafeDrop<Box<Self>> for ListNode
+{
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.0.take_child_at_index_0()
+    }
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        self.0.set_parent_at_index_0(parent)
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.0.take_next_child_at_pos_index()
+    }
+}
+
+/// The simpler call, without turbofish.  Comment-out to cause stack overflow.
+impl Drop for ListNode
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+impl NewLink<List<Self>> for Box<ListNode>
+{
+    fn new(list: List<Self>) -> Self
+    {
+        Box::new(ListNode(list))
+    }
+}
+
+
+struct BinaryTreeNode(BinaryTree<Box<Self>>);
+
+impl DeepSafeDrop<Box<Self>> for BinaryTreeNode
+{
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.0.take_child_at_index_0()
+    }
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        self.0.set_parent_at_index_0(parent)
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.0.take_next_child_at_pos_index()
+    }
+}
+
+/// The simpler call, without turbofish.
+impl Drop for BinaryTreeNode
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+impl NewLink<BinaryTree<Self>> for Box<BinaryTreeNode>
+{
+    fn new(tree: BinaryTree<Self>) -> Self
+    {
+        Box::new(BinaryTreeNode(tree))
+    }
+}
+
+
+#[test]
+fn list()
+{
+    let list = ListNode(List::make(TREE_SIZE, None));
+    drop(list);
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let fan = BinaryTreeNode(BinaryTree::make_fan(fan_depth(TREE_SIZE)));
+    drop(fan);
+}
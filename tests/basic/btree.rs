@@ -0,0 +1,53 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::BTreeNode,
+};
+
+
+struct BTreeBox(Box<BTreeNode<Self, 4>>);
+
+impl Link<BTreeNode<Self, 4>> for BTreeBox
+{
+    fn get_mut(&mut self) -> &mut BTreeNode<Self, 4>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for BTreeBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<BTreeNode<Self, 4>, Self, BTreeNode<Self, 4>>(&mut *self.0);
+    }
+}
+
+
+/// All inserts go to the leftmost child, and the other children are leaves.
+fn make_skewed(depth: usize) -> BTreeNode<BTreeBox, 4>
+{
+    (0 .. depth).fold(BTreeNode::new(), |leftmost, _| {
+        let mut node = BTreeNode::<BTreeBox, 4>::new();
+        let [first, rest @ ..] = node.children_mut();
+        *first = Some(BTreeBox(Box::new(leftmost)));
+        for slot in rest {
+            *slot = Some(BTreeBox(Box::default()));
+        }
+        node
+    })
+}
+
+
+#[test]
+fn skewed()
+{
+    drop(make_skewed(1000));
+}
+
+
+#[test]
+fn deep()
+{
+    let tree = BTreeBox(Box::new(make_skewed(TREE_SIZE.div_euclid(4))));
+    assert_eq!(into_post_order(tree).count(), TREE_SIZE + 1);
+}
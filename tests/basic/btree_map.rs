@@ -0,0 +1,60 @@
+use {
+    super::*,
+    alloc::collections::BTreeMap,
+    deep_safe_drop::helpers::BTreeMapNode,
+};
+
+
+struct MapBox(Box<BTreeMapNode<String, Self>>);
+
+impl Link<BTreeMapNode<String, Self>> for MapBox
+{
+    fn get_mut(&mut self) -> &mut BTreeMapNode<String, Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for MapBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<BTreeMapNode<String, Self>, Self, BTreeMapNode<String, Self>>(
+            &mut *self.0,
+        );
+    }
+}
+
+
+/// Each node along a spine of `depth` has `degree` keyed children, one of which continues the
+/// spine.
+fn make_spine(
+    degree: usize,
+    depth: usize,
+) -> BTreeMapNode<String, MapBox>
+{
+    let leaf = || MapBox(Box::default());
+    (0 .. depth).fold(BTreeMapNode::default(), |spine, _| {
+        let mut children = BTreeMap::new();
+        drop(children.insert(String::from("spine"), MapBox(Box::new(spine))));
+        children.extend((1 .. degree).map(|i| (format!("child{i}"), leaf())));
+        BTreeMapNode::new(children)
+    })
+}
+
+
+#[test]
+fn wide()
+{
+    let tree = make_spine(1000, 1000);
+    assert_eq!(tree.children().len(), 1000);
+    drop(tree);
+}
+
+
+#[test]
+fn deep()
+{
+    let tree = make_spine(2, TREE_SIZE.div_euclid(2));
+    drop(tree);
+}
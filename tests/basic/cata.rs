@@ -0,0 +1,77 @@
+use {
+    super::{
+        node::{
+            Node,
+            make_list,
+        },
+        *,
+    },
+    serde_json::{
+        Value,
+        json,
+    },
+};
+
+
+fn small() -> Node
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    Node::new(1, Some(Node::new(2, leaf(4), None)), leaf(3))
+}
+
+
+#[test]
+fn height()
+{
+    let height =
+        cata::<_, Node, _, _>(Box::new(make_list(TREE_SIZE)), |_, children: Vec<usize>| {
+            children.into_iter().max().unwrap_or(0).saturating_add(1)
+        });
+    assert_eq!(height, TREE_SIZE);
+}
+
+
+#[test]
+fn sum()
+{
+    let len = u64::try_from(TREE_SIZE).unwrap_or(u64::MAX);
+    // Summed as `u64`, which does not overflow even where `usize` is 32 bits.
+    let sum =
+        cata::<_, Node, _, _>(Box::new(make_list(TREE_SIZE)), |node, children: Vec<u64>| {
+            let id = u64::try_from(node.id).unwrap_or(u64::MAX);
+            children.into_iter().fold(id, u64::saturating_add)
+        });
+    assert_eq!(sum, (0 .. len).sum::<u64>());
+}
+
+
+#[test]
+fn pretty_print()
+{
+    let printed = cata::<_, Node, _, _>(Box::new(small()), |node, children: Vec<String>| {
+        if children.is_empty() {
+            node.id.to_string()
+        }
+        else {
+            format!("({} {})", node.id, children.join(" "))
+        }
+    });
+    assert_eq!(printed, "(1 (2 4) 3)");
+}
+
+
+#[test]
+fn to_json()
+{
+    let value = cata::<_, Node, _, _>(
+        Box::new(small()),
+        |node, children: Vec<Value>| json!({ "value": node.id, "children": children }),
+    );
+    assert_eq!(
+        value,
+        json!({ "value": 1, "children": [
+            { "value": 2, "children": [{ "value": 4, "children": [] }] },
+            { "value": 3, "children": [] },
+        ] })
+    );
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+
+type Link = Box<CheckedNode<Node>>;
+
+#[derive(DeepSafeDrop, Default)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Link>,
+    #[child(index = 1)]
+    right: Option<Link>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Self, Link, CheckedNode<Self>>(self);
+    }
+}
+
+fn checked(
+    left: Option<Link>,
+    right: Option<Link>,
+) -> CheckedNode<Node>
+{
+    CheckedNode::new(Node { left, right })
+}
+
+fn make_complete(height: usize) -> Option<Link>
+{
+    let sub = || make_complete(height.saturating_sub(1));
+    (height > 0).then(|| Box::new(checked(sub(), sub())))
+}
+
+
+/// Its bugs are controlled by its flags.  It does not need `deep_safe_drop`, because its children
+/// have no children.
+#[derive(Default)]
+struct Buggy
+{
+    /// Does not replace the link at index 0 with a non-link when taking it.
+    keeps_child0:  bool,
+    /// Returns a child again after having returned `None`.
+    more_children: bool,
+    calls:         usize,
+}
+
+impl DeepSafeDrop<Box<CheckedNode<Self>>> for Buggy
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<CheckedNode<Self>>,
+    ) -> SetParent<Box<CheckedNode<Self>>>
+    {
+        SetParent::No { returned_parent: parent }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Box<CheckedNode<Self>>>
+    {
+        self.keeps_child0.then(Box::default)
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<CheckedNode<Self>>>
+    {
+        self.calls = self.calls.saturating_add(1);
+        (self.more_children && self.calls == 2).then(Box::default)
+    }
+}
+
+
+#[test]
+fn correct_impl()
+{
+    drop(make_complete(16));
+    drop(
+        (0 .. TREE_SIZE).fold(checked(None, None), |list, _| checked(Some(Box::new(list)), None)),
+    );
+}
+
+
+#[test]
+fn reset_by_mutation()
+{
+    let mut node = CheckedNode::new(Node::default());
+    assert!(node.take_next_child_at_pos_index().is_none());
+    node.right = Some(Box::new(checked(None, None)));
+    assert!(node.take_next_child_at_pos_index().is_some());
+    assert!(node.into_inner().right.is_none());
+}
+
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "set_parent_at_index_0 called twice without take_child_at_index_0"]
+fn parent_set_twice()
+{
+    let mut node = CheckedNode::new(Node::default());
+    let _first = node.set_parent_at_index_0(Box::new(checked(None, None)));
+    let _second = node.set_parent_at_index_0(Box::new(checked(None, None)));
+}
+
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "take_child_at_index_0 returned a link twice without set_parent_at_index_0"]
+fn child0_kept()
+{
+    let mut node = CheckedNode::new(Buggy { keeps_child0: true, ..Buggy::default() });
+    let _first = node.take_child_at_index_0();
+    let _second = node.take_child_at_index_0();
+}
+
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "take_next_child_at_pos_index returned a child after all children were taken"]
+fn child_after_exhausted()
+{
+    let mut node = CheckedNode::new(Buggy { more_children: true, ..Buggy::default() });
+    let _first = node.take_next_child_at_pos_index();
+    let _second = node.take_next_child_at_pos_index();
+}
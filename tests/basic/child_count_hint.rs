@@ -0,0 +1,72 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::{
+        MultiLink,
+        TrieNode,
+    },
+};
+
+
+/// The children are not linked to nodes of the same type, which the hint does not need.
+type Leaf = Box<u8>;
+
+/// Assert that the hint is the amount of children that are actually taken.
+fn assert_accurate<N: DeepSafeDrop<Leaf>>(mut node: N)
+{
+    let hint = node.child_count_hint();
+    let count = core::iter::from_fn(|| node.take_next_child_at_any_index()).count();
+    assert_eq!(hint, Some(count));
+    assert_eq!(node.child_count_hint(), Some(0));
+}
+
+
+#[test]
+fn list()
+{
+    assert_accurate(List::<Leaf>(None));
+    assert_accurate(List(Some(Box::new(1))));
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let leaf = || Some(Box::new(1));
+    assert_accurate(BinaryTree::<Leaf> { left: None, right: None });
+    assert_accurate(BinaryTree { left: leaf(), right: None });
+    assert_accurate(BinaryTree { left: None, right: leaf() });
+    assert_accurate(BinaryTree { left: leaf(), right: leaf() });
+}
+
+
+#[test]
+fn slots()
+{
+    let mut multi = MultiLink::<Leaf, 4>::new();
+    assert_accurate(MultiLink::<Leaf, 4>::new());
+    multi.links_mut()[1] = Some(Box::new(1));
+    multi.links_mut()[3] = Some(Box::new(3));
+    assert_eq!(multi.child_count_hint(), Some(2));
+    assert_accurate(multi);
+
+    let mut trie = TrieNode::<Leaf, 26>::new(true);
+    for slot in trie.children_mut().iter_mut().step_by(10) {
+        *slot = Some(Box::new(1));
+    }
+    assert_eq!(trie.child_count_hint(), Some(3));
+    assert_accurate(trie);
+}
+
+
+#[test]
+fn default_is_unknown()
+{
+    #[derive(DeepSafeDrop)]
+    struct Derived
+    {
+        #[child(index = 0)]
+        child: Option<Leaf>,
+    }
+
+    assert_eq!(Derived { child: None }.child_count_hint(), None);
+}
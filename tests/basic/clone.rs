@@ -0,0 +1,58 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+        make_numbered,
+    },
+    *,
+};
+
+
+/// Each child is attached to the first vacant of `left` or `right`, which preserves the shape
+/// only when a lone child is `left`, as in these tests.
+impl DeepSafeClone<Box<Self>> for Node
+{
+    fn clone_without_children(&self) -> Self
+    {
+        Self { id: self.id, left: None, right: None }
+    }
+
+    fn attach_children(
+        &mut self,
+        mut children: impl Iterator<Item = Box<Self>>,
+    )
+    {
+        self.left = children.next();
+        self.right = children.next();
+    }
+}
+
+#[allow(clippy::borrowed_box)] // The link type is what `deep_safe_eq_by` is given.
+fn same(
+    t1: &Box<Node>,
+    t2: &Box<Node>,
+) -> bool
+{
+    deep_safe_eq_by::<_, _, Node, Node, _>(t1, t2, |n1, n2| n1.id == n2.id)
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn balanced()
+{
+    // 2^17 - 1 nodes, i.e. more than 100 000.
+    let original = make_numbered(17, &mut 0).expect("non-empty");
+    let clone = deep_safe_clone::<Box<Node>, Node>(&original);
+    assert!(same(&clone, &original));
+    assert!(!core::ptr::eq(&raw const *clone, &raw const *original));
+}
+
+
+#[test]
+fn deep()
+{
+    let original = Box::new(make_list(TREE_SIZE));
+    let clone = deep_safe_clone::<Box<Node>, Node>(&original);
+    assert!(same(&clone, &original));
+}
@@ -0,0 +1,83 @@
+use {
+    super::{
+        node::{
+            Node,
+            make_balanced,
+        },
+        *,
+    },
+    alloc::sync::Arc,
+    core::{
+        future::{
+            Future as _,
+            poll_fn,
+        },
+        pin::Pin,
+        sync::atomic::{
+            AtomicUsize,
+            Ordering::Relaxed,
+        },
+    },
+};
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn tokio_steps()
+{
+    const DEPTH: usize = 20;
+    const BUDGET: usize = 1000;
+    let total = (1 << DEPTH) - 1;
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().expect("runtime");
+    let ticks = Arc::new(AtomicUsize::new(0));
+
+    let (dropped, polls) = runtime.block_on(async {
+        let ticker = tokio::spawn({
+            let ticks = Arc::clone(&ticks);
+            async move {
+                loop {
+                    let _prev = ticks.fetch_add(1, Relaxed);
+                    tokio::task::yield_now().await;
+                }
+            }
+        });
+
+        let mut future = CooperativeDrop::<_, Node>::new(Box::new(make_balanced(DEPTH)), BUDGET);
+        let mut polls: usize = 0;
+        let dropped = poll_fn(|cx| {
+            polls = polls.saturating_add(1);
+            Pin::new(&mut future).poll(cx)
+        })
+        .await;
+
+        ticker.abort();
+        (dropped, polls)
+    });
+
+    assert_eq!(dropped, total);
+    // Each poll but the last drops the whole budget, and the last also drops the root.
+    assert_eq!(polls, total.saturating_sub(1).div_euclid(BUDGET).saturating_add(1));
+    // The other task ran between the polls.
+    assert!(ticks.load(Relaxed) >= polls.saturating_sub(1));
+}
+
+
+#[test]
+fn zero_budget_completes()
+{
+    let runtime = tokio::runtime::Builder::new_current_thread().build();
+    #[allow(clippy::expect_used)]
+    let dropped = runtime
+        .expect("runtime")
+        .block_on(CooperativeDrop::<_, Node>::new(Box::new(make_balanced(4)), 0));
+    assert_eq!(dropped, 15);
+}
+
+
+#[test]
+fn is_send()
+{
+    fn assert_send<T: Send>() {}
+    assert_send::<CooperativeDrop<Box<Node>, Node>>();
+}
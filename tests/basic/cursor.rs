@@ -0,0 +1,103 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+        make_numbered,
+    },
+    *,
+};
+
+
+fn ids(root: &Node) -> Vec<usize>
+{
+    let mut ids = Vec::new();
+    let mut node = Some(root);
+    while let Some(cur) = node {
+        ids.push(cur.id);
+        node = cur.left.as_deref();
+    }
+    ids
+}
+
+
+#[test]
+fn move_around()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let tree = Box::new(Node::new(0, leaf(1), leaf(2)));
+    let mut cursor = TreeCursor::<_, Node>::new(tree);
+
+    assert!(cursor.is_root());
+    assert!(!cursor.move_up());
+    assert!(!cursor.move_down(2), "a binary node has no index 2");
+    assert!(cursor.move_down(0));
+    assert_eq!(cursor.current_node().id, 1);
+    assert_eq!(cursor.depth(), 1);
+    assert!(!cursor.is_root());
+    assert!(!cursor.move_down(0));
+
+    cursor.current_node_mut().id = 10;
+    assert!(cursor.move_up());
+    assert_eq!(cursor.current_node().id, 0);
+
+    assert!(cursor.move_down(1));
+    assert_eq!(cursor.current_node().id, 2);
+    assert!(!cursor.move_down(1));
+    assert!(cursor.move_up());
+
+    let root = cursor.into_root();
+    assert_eq!(root.left.as_ref().map(|left| left.id), Some(10));
+    assert_eq!(root.right.as_ref().map(|right| right.id), Some(2));
+    drop(root);
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn other_indices_restore_links()
+{
+    let mut next_id = 0;
+    let tree = make_numbered(4, &mut next_id).expect("has depth");
+    let expected: Vec<usize> = (0 .. next_id).collect();
+    let mut cursor = TreeCursor::<_, Node>::new(tree);
+
+    assert!(cursor.move_down(1));
+    assert!(cursor.move_down(0));
+    assert!(cursor.move_down(1));
+    assert_eq!(cursor.current_node().id, 11);
+    assert!(cursor.move_up());
+    assert!(cursor.move_down(0));
+    assert_eq!(cursor.current_node().id, 10);
+    assert_eq!(cursor.depth(), 3);
+
+    let root = cursor.into_root();
+    let ids: Vec<usize> = pre_order::<_, Node>(&root).map(|node| node.id).collect();
+    assert_eq!(ids, expected);
+    drop(root);
+}
+
+
+#[test]
+fn deep_restores_links()
+{
+    let mut cursor = TreeCursor::<_, Node>::new(Box::new(make_list(TREE_SIZE)));
+    while cursor.move_down(0) {}
+    assert_eq!(cursor.current_node().id, 0);
+    assert_eq!(cursor.depth(), TREE_SIZE.saturating_sub(1));
+
+    let root = cursor.into_root();
+    let expected: Vec<usize> = (0 .. TREE_SIZE).rev().collect();
+    assert_eq!(ids(&root), expected);
+    drop(root);
+}
+
+
+#[test]
+fn drop_while_deep()
+{
+    let mut cursor = TreeCursor::<_, Node>::new(Box::new(make_list(TREE_SIZE)));
+    for _ in 0 .. TREE_SIZE.div_euclid(2) {
+        assert!(cursor.move_down(0));
+    }
+    drop(cursor);
+}
@@ -0,0 +1,85 @@
+#![cfg(debug_assertions)]
+
+use {
+    super::*,
+    std::panic::catch_unwind,
+};
+
+
+/// Has an incorrect implementation that causes a cycle.
+struct Buggy
+{
+    left:  Option<Box<Self>>,
+    right: Option<Box<Self>>,
+}
+
+impl DeepSafeDrop<Box<Self>> for Buggy
+{
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.left.take()
+    }
+
+    /// The bug: the parent is stored at a positive index, so it is then supplied as a child.
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        self.right = Some(parent);
+        self.left.take().map_or(SetParent::Yes, |child0| SetParent::YesReplacedChild { child0 })
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.right.take()
+    }
+}
+
+impl Drop for Buggy
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn detected()
+{
+    let result = catch_unwind(|| {
+        let leaf = Buggy { left: None, right: None };
+        let branch = Buggy { left: Some(Box::new(leaf)), right: None };
+        let root = Buggy { left: Some(Box::new(branch)), right: None };
+        drop(root);
+    });
+
+    let payload = result.expect_err("must panic");
+    let msg = payload.downcast_ref::<String>().expect("must be formatted message");
+    assert!(msg.starts_with("deep_safe_drop: cycle detected at node 0x"), "{msg}");
+    assert!(msg.ends_with(" — check your set_parent_at_index_0 impl"), "{msg}");
+}
+
+
+#[test]
+fn reported_by_try()
+{
+    let leaf = Buggy { left: None, right: None };
+    let branch = Box::new(Buggy { left: Some(Box::new(leaf)), right: None });
+    let branch_ptr = DropTracer::address_of::<Buggy>(&branch);
+    let mut root = Buggy { left: Some(branch), right: None };
+
+    let result = try_deep_safe_drop::<_, Box<Buggy>, _>(&mut root);
+
+    let errors = result.err().unwrap_or_default();
+    assert!(
+        matches!(errors.as_slice(), [DropError::CycleDetectedAt { node_ptr }]
+                 if *node_ptr == branch_ptr),
+        "{errors:?}"
+    );
+    assert_eq!(errors.iter().map(ToString::to_string).collect::<Vec<_>>(), [format!(
+        "deep_safe_drop: cycle detected at node {branch_ptr:#x}"
+    )]);
+}
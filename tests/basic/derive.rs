@@ -0,0 +1,116 @@
+use super::*;
+
+
+#[derive(DeepSafeDrop)]
+struct BinaryTree
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+    #[allow(dead_code)]
+    data:  usize,
+}
+
+impl Drop for BinaryTree
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct List(#[allow(dead_code)] usize, #[child(index = 0)] Option<Box<Self>>);
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+fn tuple_struct()
+{
+    let list = (0 .. TREE_SIZE).fold(List(0, None), |acc, i| List(i, Some(Box::new(acc))));
+    drop(list);
+}
+
+
+#[test]
+fn named_struct()
+{
+    let branch = |len| {
+        (0 .. len)
+            .fold(None, |acc, data| Some(Box::new(BinaryTree { left: acc, right: None, data })))
+    };
+    let tree = BinaryTree {
+        left:  branch(TREE_SIZE.div_euclid(2)),
+        right: branch(TREE_SIZE.div_euclid(2)),
+        data:  0,
+    };
+    drop(tree);
+}
+
+
+#[derive(DeepSafeDrop)]
+enum Expr
+{
+    Num(#[allow(dead_code)] f64),
+    Add(#[child(index = 0)] Option<Box<Self>>, #[child(index = 1)] Option<Box<Self>>),
+    Neg
+    {
+        #[child(index = 0)]
+        operand: Option<Box<Self>>,
+    },
+}
+
+impl Drop for Expr
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+fn enum_add_chain()
+{
+    let depth = 10_000;
+    let num = || Some(Box::new(Expr::Num(1.0)));
+    let expr = (0 .. depth).fold(Expr::Num(0.0), |acc, _| Expr::Add(Some(Box::new(acc)), num()));
+    drop(expr);
+}
+
+
+#[test]
+fn enum_mixed()
+{
+    let list = (0 .. TREE_SIZE).fold(Expr::Num(0.0), |acc, i| {
+        if i.rem_euclid(2) == 0 {
+            Expr::Neg { operand: Some(Box::new(acc)) }
+        }
+        else {
+            Expr::Add(None, Some(Box::new(acc)))
+        }
+    });
+    drop(list);
+}
+
+
+#[test]
+fn enum_swap_child_at_index_0_with()
+{
+    let neg = Some(Box::new(Expr::Neg { operand: None }));
+    let mut add = Expr::Add(Some(Box::new(Expr::Num(1.0))), neg);
+
+    assert!(add.swap_child_at_index_0_with(1));
+    assert!(matches!(add.take_child_at_index_0().as_deref(), Some(Expr::Neg { .. })));
+    assert!(!add.swap_child_at_index_0_with(2));
+    assert!(!Expr::Num(0.0).swap_child_at_index_0_with(1));
+}
@@ -0,0 +1,105 @@
+use {
+    super::*,
+    core::{
+        iter::Chain,
+        option,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+    data:  char,
+}
+
+impl ReadableChildren<Box<Self>> for Node
+{
+    type Children<'n> = Chain<option::Iter<'n, Box<Self>>, option::Iter<'n, Box<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
+
+fn node(
+    data: char,
+    left: Option<Node>,
+    right: Option<Node>,
+) -> Node
+{
+    Node { left: left.map(Box::new), right: right.map(Box::new), data }
+}
+
+
+#[test]
+fn golden()
+{
+    let leaf = |data| Some(node(data, None, None));
+    let root = Box::new(node(
+        'a',
+        Some(node('b', leaf('d'), leaf('e'))),
+        Some(node('c', leaf('f'), leaf('"'))),
+    ));
+
+    let dot = to_dot::<_, Node, _>(&root, |node| node.data.to_string());
+
+    assert_eq!(
+        dot,
+        r#"digraph {
+    n0 [label="a"];
+    n0 -> n1;
+    n0 -> n2;
+    n1 [label="b"];
+    n1 -> n3;
+    n1 -> n4;
+    n3 [label="d"];
+    n4 [label="e"];
+    n2 [label="c"];
+    n2 -> n5;
+    n2 -> n6;
+    n5 [label="f"];
+    n6 [label="\""];
+}"#
+    );
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Shared
+{
+    #[child(index = 0)]
+    left:  Option<RcLink<Self>>,
+    #[child(index = 1)]
+    right: Option<RcLink<Self>>,
+}
+
+impl ReadableChildren<RcLink<Self>> for Shared
+{
+    type Children<'n> = Chain<option::Iter<'n, RcLink<Self>>, option::Iter<'n, RcLink<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
+
+
+#[test]
+fn shared_once()
+{
+    let leaf = RcLink::new(Shared { left: None, right: None });
+    let root = RcLink::new(Shared { left: Some(leaf.clone()), right: Some(leaf) });
+
+    let dot = to_dot::<_, Shared, _>(&root, |_| String::new());
+
+    assert_eq!(
+        dot,
+        "digraph {\n    n0 [label=\"\"];\n    n0 -> n1;\n    n0 -> n1;\n    n1 [label=\"\"];\n}"
+    );
+}
@@ -0,0 +1,49 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::DoublyLinkedNode,
+};
+
+
+#[derive(Default)]
+struct DlBox(Box<DoublyLinkedNode<Self>>);
+
+impl Link<DoublyLinkedNode<Self>> for DlBox
+{
+    fn get_mut(&mut self) -> &mut DoublyLinkedNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for DlBox
+{
+    fn drop(&mut self)
+    {
+        drop(self.0.take_prev());
+        deep_safe_drop::<DoublyLinkedNode<Self>, Self, DoublyLinkedNode<Self>>(&mut *self.0);
+    }
+}
+
+/// Each `prev` is a non-null stand-in for a backward link, which must never be followed.
+fn make_list(len: usize) -> DlBox
+{
+    (1 .. len).fold(DlBox::default(), |next, _| {
+        DlBox(Box::new(DoublyLinkedNode::new(Some(DlBox::default()), Some(next))))
+    })
+}
+
+
+#[test]
+fn exercise()
+{
+    drop(make_list(TREE_SIZE));
+}
+
+
+#[test]
+fn never_follows_prev()
+{
+    let list = make_list(TREE_SIZE);
+    assert!(list.0.prev().is_some());
+    assert_eq!(into_post_order(list).count(), TREE_SIZE);
+}
@@ -0,0 +1,93 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        cell::Cell,
+        panic::AssertUnwindSafe,
+    },
+    std::panic::catch_unwind,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Counted
+{
+    #[child(index = 0)]
+    next:   Option<Box<Self>>,
+    drops:  Rc<Cell<usize>>,
+    panics: bool,
+}
+
+impl Drop for Counted
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+        self.drops.set(self.drops.get().saturating_add(1));
+        assert!(!self.panics, "intentional");
+    }
+}
+
+const LIST_LEN: usize = TREE_SIZE.div_euclid(4);
+
+#[allow(clippy::unnecessary_box_returns)] // The link type is what is pushed.
+fn make_list(
+    len: usize,
+    drops: &Rc<Cell<usize>>,
+    panics: bool,
+) -> Box<Counted>
+{
+    let node = |next| Box::new(Counted { next, drops: Rc::clone(drops), panics: false });
+    let list = (1 .. len).fold(None, |acc, _| Some(node(acc)));
+    Box::new(Counted { next: list, drops: Rc::clone(drops), panics })
+}
+
+
+#[test]
+fn flush_drops_all()
+{
+    let drops = Rc::new(Cell::new(0));
+    let mut buffer = DropBuffer::new();
+    for _ in 0 .. 1000 {
+        buffer.push(make_list(1000, &drops, false));
+    }
+    assert_eq!(buffer.len(), 1000);
+    assert_eq!(drops.get(), 0);
+
+    buffer.flush();
+    assert!(buffer.is_empty());
+    assert_eq!(drops.get(), 1000 * 1000);
+
+    // Still usable after flushing.
+    buffer.push(make_list(LIST_LEN, &drops, false));
+    buffer.flush();
+    assert_eq!(drops.get(), 1000 * 1000 + LIST_LEN);
+}
+
+
+#[test]
+fn drop_flushes()
+{
+    let drops = Rc::new(Cell::new(0));
+    let mut buffer = DropBuffer::<_, Counted>::with_capacity(3);
+    for _ in 0 .. 3 {
+        buffer.push(make_list(LIST_LEN, &drops, false));
+    }
+    drop(buffer);
+    assert_eq!(drops.get(), 3 * LIST_LEN);
+}
+
+
+#[test]
+fn flushes_rest_after_panic()
+{
+    let drops = Rc::new(Cell::new(0));
+    let mut buffer = DropBuffer::<_, Counted>::new();
+    for i in 0 .. 3 {
+        buffer.push(make_list(LIST_LEN, &drops, i == 1));
+    }
+    let result = catch_unwind(AssertUnwindSafe(|| buffer.flush()));
+    assert!(result.is_err());
+    assert!(buffer.is_empty());
+    assert_eq!(drops.get(), 3 * LIST_LEN);
+}
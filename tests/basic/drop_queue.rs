@@ -0,0 +1,83 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::Cell,
+};
+
+
+/// Counts the nodes dropped.
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:  Option<Box<Self>>,
+    drops: Rc<Cell<usize>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        self.drops.set(self.drops.get().saturating_add(1));
+    }
+}
+
+/// Defers the dropping of its list.
+struct Owner
+{
+    root: Option<Box<Node>>,
+}
+
+impl Drop for Owner
+{
+    fn drop(&mut self)
+    {
+        if let Some(root) = self.root.take() {
+            DropQueueGuard::defer::<_, Node>(root);
+        }
+    }
+}
+
+fn make_owner(drops: &Rc<Cell<usize>>) -> Owner
+{
+    let node = |next| Node { next, drops: Rc::clone(drops) };
+    let list = (1 .. TREE_SIZE).fold(node(None), |acc, _| node(Some(Box::new(acc))));
+    Owner { root: Some(Box::new(list)) }
+}
+
+
+#[test]
+fn at_scope_exit()
+{
+    let drops = Rc::new(Cell::new(0));
+    {
+        let _guard = DropQueueGuard::new();
+        {
+            let owner = make_owner(&drops);
+            drop(owner);
+            assert_eq!(DropQueueGuard::pending(), 1);
+        }
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(DropQueueGuard::pending(), 0);
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn nested()
+{
+    let outer_drops = Rc::new(Cell::new(0));
+    let inner_drops = Rc::new(Cell::new(0));
+    {
+        let _outer = DropQueueGuard::new();
+        drop(make_owner(&outer_drops));
+        {
+            let _inner = DropQueueGuard::new();
+            drop(make_owner(&inner_drops));
+        }
+        assert_eq!(inner_drops.get(), TREE_SIZE);
+        assert_eq!(outer_drops.get(), 0);
+    }
+    assert_eq!(outer_drops.get(), TREE_SIZE);
+}
@@ -0,0 +1,44 @@
+use {
+    super::{
+        nary::{
+            NaryBox,
+            make_spine,
+        },
+        *,
+    },
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+fn assert_same_as_stats(mut root: NaryNode<NaryBox>)
+{
+    let dry = dry_run_deep_safe_drop::<_, NaryBox, NaryNode<NaryBox>>(&root);
+    let stats = deep_safe_drop_stats::<_, NaryBox, NaryNode<NaryBox>>(&mut root);
+    assert_eq!(dry, stats);
+}
+
+
+#[test]
+fn wide()
+{
+    let root = make_spine(100, 100);
+    let stats = dry_run_deep_safe_drop::<_, NaryBox, NaryNode<NaryBox>>(&root);
+    assert_eq!(stats.nodes_dropped, 100 * 100);
+    assert_eq!(stats.max_depth_reached, 100);
+    assert_eq!(stats.internal_nodes, 99);
+    assert_same_as_stats(root);
+}
+
+
+#[test]
+fn deep()
+{
+    assert_same_as_stats(make_spine(3, TREE_SIZE.div_euclid(3)));
+}
+
+
+#[test]
+fn leaf()
+{
+    assert_same_as_stats(NaryNode::default());
+}
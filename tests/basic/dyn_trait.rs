@@ -5,62 +5,32 @@ use super::{
 };
 
 
-/// Used as both the `Link` and the `Node` types.
-struct DynBox(Box<dyn DeepSafeDrop<Self>>);
+// Used as both the `Link` and the `Node` types.
+dyn_link!(DynBox; List<DynBox>, BinaryTree<DynBox>);
 
-/// Needed because it's used as the `Node` type.
-impl DeepSafeDrop<Self> for DynBox
+impl<N> NewLink<N> for DynBox
+where Self: From<N>
 {
-    fn take_child_at_index_0(&mut self) -> Option<Self>
+    fn new(node: N) -> Self
     {
-        self.0.take_child_at_index_0()
-    }
-
-    fn set_parent_at_index_0(
-        &mut self,
-        parent: Self,
-    ) -> SetParent<Self>
-    {
-        self.0.set_parent_at_index_0(parent)
-    }
-
-    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
-    {
-        self.0.take_next_child_at_pos_index()
-    }
-}
-
-/// Needed because it's also used as the `Link` type.
-impl Link<Self> for DynBox
-{
-    fn get_mut(&mut self) -> &mut Self
-    {
-        self
+        node.into()
     }
 }
 
-/// Comment-out to cause stack overflow.
-impl Drop for DynBox
-{
-    fn drop(&mut self)
-    {
-        deep_safe_drop(self);
-    }
-}
 
-impl NewLink<List<Self>> for DynBox
+impl NewLink<List<Self>> for Box<dyn DynDeepSafeDrop>
 {
     fn new(node: List<Self>) -> Self
     {
-        Self(Box::new(node))
+        Box::new(node)
     }
 }
 
-impl NewLink<BinaryTree<Self>> for DynBox
+impl NewLink<BinaryTree<Self>> for Box<dyn DynDeepSafeDrop>
 {
     fn new(node: BinaryTree<Self>) -> Self
     {
-        Self(Box::new(node))
+        Box::new(node)
     }
 }
 
@@ -78,8 +48,17 @@ fn no_stack_overflow()
 }
 
 
+/// Without any wrapper type, nor `Drop` implementations.
+#[test]
+fn dyn_deep_safe_drop()
+{
+    let fan: Box<dyn DynDeepSafeDrop> = make_stretched_fan(FAN_DEGREE, STRETCH_LEN);
+    drop_dyn(fan);
+}
+
+
 #[test]
-#[ignore]
+#[ignore = "causes stack overflow, to demonstrate the need for `deep_safe_drop`"]
 fn stack_overflow()
 {
     struct DynBox(#[allow(dead_code)] Box<dyn DeepSafeDrop<Self>>);
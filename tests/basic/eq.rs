@@ -0,0 +1,147 @@
+use {
+    super::{
+        node::Node as Binary,
+        *,
+    },
+    core::option,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+    data: usize,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+impl ReadableChildren<Box<Self>> for Node
+{
+    type Children<'n> = option::Iter<'n, Box<Self>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.next.iter()
+    }
+}
+
+/// Only compares the node itself, as required by `deep_safe_eq`.
+impl PartialEq for Node
+{
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool
+    {
+        self.data == other.data
+    }
+}
+
+const LEN: usize = 100_000;
+
+/// Boxed, because that is the link type that `deep_safe_eq` is given.
+#[allow(clippy::unnecessary_box_returns)]
+fn make_list(len: usize) -> Box<Node>
+{
+    let list = (1 .. len)
+        .fold(Node { next: None, data: 0 }, |acc, data| Node { next: Some(Box::new(acc)), data });
+    Box::new(list)
+}
+
+
+#[test]
+fn equal()
+{
+    assert!(deep_safe_eq::<_, _, Node, Node>(&make_list(LEN), &make_list(LEN)));
+    assert!(deep_safe_eq::<_, _, Node, Node>(&make_list(TREE_SIZE), &make_list(TREE_SIZE)));
+}
+
+
+#[test]
+fn different_data()
+{
+    let t1 = make_list(LEN);
+    let mut t2 = make_list(LEN);
+    if let Some(second) = t2.next.as_mut() {
+        second.data = usize::MAX;
+    }
+
+    let mut compared: usize = 0;
+    let eq = deep_safe_eq_by::<_, _, Node, Node, _>(&t1, &t2, |n1, n2| {
+        compared = compared.saturating_add(1);
+        n1 == n2
+    });
+    assert!(!eq);
+    assert_eq!(compared, 2);
+}
+
+
+#[test]
+fn different_shape()
+{
+    assert!(!deep_safe_eq::<_, _, Node, Node>(
+        &make_list(LEN),
+        &make_list(LEN.saturating_sub(1))
+    ));
+    let leaf = Box::new(Node { next: None, data: LEN.saturating_sub(1) });
+    assert!(!deep_safe_eq::<_, _, Node, Node>(&make_list(LEN), &leaf));
+}
+
+
+/// A complete binary tree of `len` nodes, whose ids are given by `data` of each level-order
+/// index.  Boxed, like `make_list`.
+#[allow(clippy::unnecessary_box_returns)]
+fn make_balanced(
+    len: usize,
+    data: impl Fn(usize) -> usize,
+) -> Box<Binary>
+{
+    let node = |i| Some(Box::new(Binary::new(data(i), None, None)));
+    let mut nodes: Vec<Option<Box<Binary>>> = (0 .. len).map(node).collect();
+    for child in (1 .. len).rev() {
+        let link = nodes.get_mut(child).and_then(Option::take);
+        if let Some(Some(parent)) = nodes.get_mut(child.saturating_sub(1).div_euclid(2)) {
+            if child & 1 == 1 {
+                parent.left = link;
+            }
+            else {
+                parent.right = link;
+            }
+        }
+    }
+    nodes.swap_remove(0).unwrap_or_else(|| Box::new(Binary::default()))
+}
+
+
+#[test]
+fn isomorphic()
+{
+    let t1 = make_balanced(LEN, |i| i);
+    let t2 = make_balanced(LEN, |i| i.wrapping_mul(7));
+    assert!(!deep_safe_eq_by::<_, _, Binary, Binary, _>(&t1, &t2, |n1, n2| n1.id == n2.id));
+    assert!(are_isomorphic::<_, _, Binary, Binary>(&t1, &t2));
+    assert!(are_isomorphic::<_, _, Node, Node>(&make_list(LEN), &make_list(LEN)));
+}
+
+
+#[test]
+fn not_isomorphic()
+{
+    let t1 = make_balanced(LEN, |i| i);
+    let t2 = make_balanced(LEN.saturating_add(1), |i| i);
+    assert!(!are_isomorphic::<_, _, Binary, Binary>(&t1, &t2));
+    assert!(!are_isomorphic::<_, _, Binary, Binary>(&t2, &t1));
+
+    let leaf = make_balanced(1, |i| i);
+    let pair = make_balanced(2, |i| i);
+    assert!(!are_isomorphic::<_, _, Binary, Binary>(&leaf, &pair));
+    assert!(are_isomorphic::<_, _, Binary, Node>(&leaf, &make_list(1)));
+}
@@ -0,0 +1,75 @@
+use {
+    super::{
+        node::RecordedNode,
+        *,
+    },
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+/// A complete binary tree, whose ids are numbered in level-order from 1 at the root, so that the
+/// children of `id` are `2 * id` and `2 * id + 1`.
+fn make_complete(
+    id: usize,
+    depth: usize,
+    dropped: &Rc<RefCell<Vec<usize>>>,
+) -> RecordedNode
+{
+    let child =
+        |child_id| (depth > 1).then(|| make_complete(child_id, depth.saturating_sub(1), dropped));
+    let left_id = id.saturating_mul(2);
+    RecordedNode::new(id, child(left_id), child(left_id | 1), dropped)
+}
+
+
+#[test]
+fn keep_odd()
+{
+    const DEPTH: usize = 10;
+
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let mut root = make_complete(1, DEPTH, &dropped);
+
+    let kept = filter_drop::<_, Box<RecordedNode>, _, _>(&mut root, |node| node.id & 1 == 1);
+
+    // Only the left-most spine has even ids that are reached, and each has a kept right child,
+    // except the last that is a leaf.  In pre-order, the deeper ones are first.
+    let expected: Vec<usize> = (1 .. DEPTH).rev().map(|level| (1 << level) | 1).collect();
+    assert_eq!(kept.iter().map(|node| node.id).collect::<Vec<_>>(), expected);
+    assert_eq!(dropped.borrow().len(), DEPTH - 1);
+
+    let kept_sizes =
+        (1 .. DEPTH).map(|level| (1_usize << DEPTH.saturating_sub(level)).saturating_sub(1));
+    drop(kept);
+    assert_eq!(dropped.borrow().len(), kept_sizes.fold(DEPTH - 1, usize::saturating_add));
+    drop(root);
+    assert_eq!(dropped.borrow().len(), (1 << DEPTH) - 1);
+}
+
+
+#[test]
+fn keep_none()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let node = |id, left| RecordedNode::new(id, left, None, &dropped);
+    let mut root = (1 .. TREE_SIZE).fold(node(0, None), |acc, id| node(id, Some(acc)));
+
+    let kept = filter_drop::<_, Box<RecordedNode>, _, _>(&mut root, |_| false);
+
+    assert!(kept.is_empty());
+    assert_eq!(dropped.borrow().len(), TREE_SIZE - 1);
+}
+
+
+#[test]
+fn keep_all()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let mut root = make_complete(1, 5, &dropped);
+
+    let kept = filter_drop::<_, Box<RecordedNode>, _, _>(&mut root, |_| true);
+
+    assert_eq!(kept.iter().map(|node| node.id).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(dropped.borrow().len(), 0);
+}
@@ -0,0 +1,131 @@
+use {
+    super::*,
+    core::{
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+        iter::Chain,
+        option,
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+    data:  usize,
+}
+
+impl ReadableChildren<Box<Self>> for Node
+{
+    type Children<'n> = Chain<option::Iter<'n, Box<Self>>, option::Iter<'n, Box<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
+
+/// Only formats the node itself.
+impl Display for Node
+{
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "node {}", self.data)
+    }
+}
+
+fn node(
+    data: usize,
+    left: Option<Node>,
+    right: Option<Node>,
+) -> Node
+{
+    Node { left: left.map(Box::new), right: right.map(Box::new), data }
+}
+
+
+#[test]
+fn indented()
+{
+    let leaf = |data| Some(node(data, None, None));
+    let tree = Tree::new(Box::new(node(0, Some(node(1, leaf(3), None)), leaf(2))));
+    assert_eq!(tree.to_string(), "node 0\n  node 1\n    node 3\n  node 2");
+}
+
+
+#[test]
+fn custom_format()
+{
+    struct Custom(Box<Node>);
+
+    impl Display for Custom
+    {
+        fn fmt(
+            &self,
+            f: &mut Formatter<'_>,
+        ) -> fmt::Result
+        {
+            deep_safe_fmt(&self.0, f, |f, node: &Node| write!(f, "<{}>", node.data))
+        }
+    }
+
+    let tree = Custom(Box::new(node(0, None, Some(node(1, None, None)))));
+    assert_eq!(tree.to_string(), "<0>\n  <1>");
+    drop(Tree::new(tree.0));
+}
+
+
+#[test]
+fn deep()
+{
+    const DEPTH: usize = 10_000;
+    let list = (1 .. DEPTH).fold(node(0, None, None), |acc, data| node(data, Some(acc), None));
+    let string = Tree::new(Box::new(list)).to_string();
+    assert_eq!(string.lines().count(), DEPTH);
+    assert_eq!(string.lines().last().map(str::trim_start), Some("node 0"));
+}
+
+
+#[test]
+fn print_tree_connectors()
+{
+    let leaf = |data| Some(node(data, None, None));
+    let tree = Box::new(node(0, Some(node(1, None, Some(node(3, leaf(4), leaf(5))))), leaf(2)));
+
+    let mut out = String::new();
+    let result = print_tree::<_, Node, _, _>(&tree, &mut out, |node| node.data.to_string());
+    drop(Tree::new(tree));
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(out, "0\n├── 1\n│   └── 3\n│       ├── 4\n│       └── 5\n└── 2\n");
+}
+
+
+#[test]
+fn print_tree_deep()
+{
+    const DEPTH: usize = 10_000;
+    let list = (1 .. DEPTH).fold(node(0, None, None), |acc, data| node(data, Some(acc), None));
+    let list = Box::new(list);
+
+    let mut out = String::new();
+    let result = print_tree::<_, Node, _, _>(&list, &mut out, |node| node.data.to_string());
+    drop(Tree::new(list));
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(out.lines().count(), DEPTH);
+    assert_eq!(
+        out.lines().last().map(|line| line.trim_start_matches([' ', '└', '─'])),
+        Some("0")
+    );
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:  Option<Box<Self>>,
+    value: u64,
+    name:  String,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+fn make_list(len: u64) -> Node
+{
+    let node = |next, value: u64| Node { next, value, name: value.to_string() };
+    (1 .. len).fold(node(None, 0), |acc, value| node(Some(Box::new(acc)), value))
+}
+
+
+#[test]
+fn sum()
+{
+    let len = u64::try_from(TREE_SIZE).unwrap_or(u64::MAX);
+    let mut root = make_list(len);
+
+    let sum = fold_drop::<_, Box<Node>, _, _, _>(&mut root, 0_u64, |acc, node| {
+        acc.saturating_add(node.value)
+    });
+
+    // The root is not folded.
+    assert_eq!(sum, (0 .. len.saturating_sub(1)).sum::<u64>());
+    assert!(root.next.is_none());
+}
+
+
+#[test]
+fn move_out()
+{
+    let mut root = make_list(5);
+
+    let names = fold_drop::<_, Box<Node>, _, _, _>(&mut root, Vec::new(), |mut acc, node| {
+        acc.push(core::mem::take(&mut node.name));
+        acc
+    });
+
+    assert_eq!(names, ["0", "1", "2", "3"]);
+}
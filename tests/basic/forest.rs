@@ -0,0 +1,91 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        cell::Cell,
+        panic::AssertUnwindSafe,
+    },
+    std::panic::catch_unwind,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Counted
+{
+    #[child(index = 0)]
+    next:   Option<Box<Self>>,
+    drops:  Rc<Cell<usize>>,
+    panics: bool,
+}
+
+impl Drop for Counted
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+        self.drops.set(self.drops.get().saturating_add(1));
+        assert!(!self.panics, "intentional");
+    }
+}
+
+const LIST_LEN: usize = TREE_SIZE.div_euclid(4);
+
+fn make_list(
+    drops: &Rc<Cell<usize>>,
+    panics: bool,
+) -> Counted
+{
+    let node = |next| Box::new(Counted { next, drops: Rc::clone(drops), panics: false });
+    let list = (1 .. LIST_LEN).fold(None, |acc, _| Some(node(acc)));
+    Counted { next: list, drops: Rc::clone(drops), panics }
+}
+
+fn make_forest(
+    drops: &Rc<Cell<usize>>,
+    panicking: usize,
+) -> Forest<Box<Counted>, Counted>
+{
+    let mut forest = Forest::new();
+    for i in 0 .. 3 {
+        forest.push_root(Box::new(make_list(drops, i == panicking)));
+    }
+    forest
+}
+
+
+#[test]
+fn drops_all()
+{
+    let drops = Rc::new(Cell::new(0));
+    let forest = make_forest(&drops, usize::MAX);
+    assert_eq!(forest.len(), 3);
+    drop(forest);
+    assert_eq!(drops.get(), 3 * LIST_LEN);
+}
+
+
+#[test]
+fn drops_rest_after_panic()
+{
+    let drops = Rc::new(Cell::new(0));
+    let forest = make_forest(&drops, 1);
+    let result = catch_unwind(AssertUnwindSafe(|| drop(forest)));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 3 * LIST_LEN);
+}
+
+
+#[test]
+fn into_iter_does_not_drop()
+{
+    let drops = Rc::new(Cell::new(0));
+    let mut forest = make_forest(&drops, usize::MAX);
+    let last = forest.pop_root();
+    assert!(last.is_some());
+    let roots: Vec<_> = forest.into_iter().collect();
+    assert_eq!(drops.get(), 0);
+    assert_eq!(roots.len(), 2);
+    drop(roots);
+    drop(last);
+    assert_eq!(drops.get(), 3 * LIST_LEN);
+}
@@ -0,0 +1,129 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        cell::Cell,
+        panic::AssertUnwindSafe,
+    },
+    std::panic::{
+        catch_unwind,
+        resume_unwind,
+    },
+};
+
+
+struct Counter(Rc<Cell<usize>>);
+
+impl Drop for Counter
+{
+    fn drop(&mut self)
+    {
+        self.0.set(self.0.get().saturating_add(1));
+    }
+}
+
+/// Intentionally does not implement `Drop` with `deep_safe_drop`.
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:    Option<Box<Self>>,
+    _count:  Counter,
+    payload: u8,
+}
+
+fn make_list(drops: &Rc<Cell<usize>>) -> Node
+{
+    let node = |next| Node { next, _count: Counter(Rc::clone(drops)), payload: 0 };
+    (1 .. TREE_SIZE).fold(node(None), |acc, _| node(Some(Box::new(acc))))
+}
+
+
+#[test]
+fn drops_on_scope_exit()
+{
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut guard = SafeDropGuard::new(Box::new(make_list(&drops)));
+        guard.payload = 1;
+        assert_eq!(guard.payload, 1);
+    }
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn drops_on_panic()
+{
+    let drops = Rc::new(Cell::new(0));
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _guard = SafeDropGuard::new(Box::new(make_list(&drops)));
+        resume_unwind(Box::new("intentional"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn take_and_drop_now()
+{
+    let drops = Rc::new(Cell::new(0));
+    let guard = SafeDropGuard::new(Box::new(make_list(&drops)));
+    let list = guard.take();
+    assert_eq!(drops.get(), 0);
+
+    SafeDropGuard::new(list).drop_now();
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn tree()
+{
+    let drops = Rc::new(Cell::new(0));
+    let mut tree = Tree::new(Box::new(make_list(&drops)));
+    tree.root_mut().payload = 2;
+    assert_eq!(tree.root().payload, 2);
+    assert!(tree.root().next.is_some());
+    drop(tree);
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn tree_into_inner()
+{
+    let drops = Rc::new(Cell::new(0));
+    let tree = Tree::new(Box::new(make_list(&drops)));
+    let mut root = tree.into_inner();
+    assert_eq!(drops.get(), 0);
+    root.payload = 3;
+    SafeDropGuard::new(root).drop_now();
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn drop_handle()
+{
+    let drops = Rc::new(Cell::new(0));
+    let handle = DropHandle::new(Box::new(make_list(&drops)));
+    assert_eq!(drops.get(), 0);
+    handle.drop_now();
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn drop_handle_forget()
+{
+    let drops = Rc::new(Cell::new(0));
+    let handle = DropHandle::new(Box::new(make_list(&drops)));
+    let mut link = handle.forget();
+    assert_eq!(drops.get(), 0);
+    link.payload = 4;
+    assert!(link.next.is_some());
+    SafeDropGuard::new(link).drop_now();
+    assert_eq!(drops.get(), TREE_SIZE);
+}
@@ -0,0 +1,60 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::HashMapNode,
+    std::collections::HashMap,
+};
+
+
+struct MapBox(Box<HashMapNode<String, Self>>);
+
+impl Link<HashMapNode<String, Self>> for MapBox
+{
+    fn get_mut(&mut self) -> &mut HashMapNode<String, Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for MapBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<HashMapNode<String, Self>, Self, HashMapNode<String, Self>>(
+            &mut *self.0,
+        );
+    }
+}
+
+
+/// Each node along a spine of `depth` has `degree` children, labeled like XML attribute names,
+/// one of which continues the spine.
+fn make_spine(
+    degree: usize,
+    depth: usize,
+) -> HashMapNode<String, MapBox>
+{
+    let leaf = || MapBox(Box::default());
+    (0 .. depth).fold(HashMapNode::default(), |spine, _| {
+        let mut children = HashMap::with_capacity(degree);
+        drop(children.insert(String::from("spine"), MapBox(Box::new(spine))));
+        children.extend((1 .. degree).map(|i| (format!("attr-{i}"), leaf())));
+        HashMapNode::new(children)
+    })
+}
+
+
+#[test]
+fn wide()
+{
+    let tree = make_spine(500, 500);
+    assert_eq!(tree.children().len(), 500);
+    drop(tree);
+}
+
+
+#[test]
+fn deep()
+{
+    let tree = make_spine(2, TREE_SIZE.div_euclid(2));
+    drop(tree);
+}
@@ -0,0 +1,91 @@
+use {
+    super::*,
+    core::mem::size_of,
+    deep_safe_drop::helpers::{
+        LeftChildRightSiblingNode,
+        NaryNode,
+    },
+    nary::{
+        NaryBox,
+        make_spine,
+    },
+};
+
+
+type Node = LeftChildRightSiblingNode<LcrsBox>;
+
+struct LcrsBox(Box<Node>);
+
+impl Link<Node> for LcrsBox
+{
+    fn get_mut(&mut self) -> &mut Node
+    {
+        &mut self.0
+    }
+}
+
+impl core::borrow::Borrow<Node> for LcrsBox
+{
+    fn borrow(&self) -> &Node
+    {
+        &self.0
+    }
+}
+
+impl Drop for LcrsBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Node, Self, Node>(&mut *self.0);
+    }
+}
+
+fn make_link(node: Node) -> LcrsBox
+{
+    LcrsBox(Box::new(node))
+}
+
+/// The siblings from `first` onward.
+fn siblings(first: Option<&LcrsBox>) -> impl Iterator<Item = &LcrsBox>
+{
+    core::iter::successors(first, |sibling| sibling.0.next_sibling())
+}
+
+/// The amounts of children of the children of `node`, in order.
+fn grandchild_counts(node: &Node) -> Vec<usize>
+{
+    siblings(node.first_child()).map(|child| siblings(child.0.first_child()).count()).collect()
+}
+
+#[test]
+fn exercise()
+{
+    let nary = make_spine(3, TREE_SIZE);
+    let root = LcrsBox(Box::new(Node::from_nary(NaryBox::from(nary), make_link)));
+
+    assert_eq!(count_nodes::<_, Node>(&root), 1 + 3 * TREE_SIZE);
+    drop(root);
+}
+
+
+#[test]
+fn keeps_order()
+{
+    let leaf = || NaryBox::from(NaryNode::default());
+    let with_children =
+        |count| NaryBox::from(NaryNode::new(core::iter::repeat_with(leaf).take(count).collect()));
+    let nary = NaryNode::new(Vec::from([with_children(1), with_children(0), with_children(3)]));
+
+    let root = Node::from_nary(NaryBox::from(nary), make_link);
+
+    assert_eq!(grandchild_counts(&root), [1, 0, 3]);
+    assert!(root.next_sibling().is_none());
+    assert_eq!(root.data(), 0);
+}
+
+
+#[test]
+fn fewer_bytes_than_nary()
+{
+    assert!(size_of::<Node>() < size_of::<NaryNode<NaryBox>>());
+}
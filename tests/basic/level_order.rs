@@ -0,0 +1,77 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+        make_numbered,
+    },
+    *,
+};
+
+
+/// Reference implementation, which recurses once per level.
+fn recursive(
+    level: &[&Node],
+    out: &mut Vec<usize>,
+)
+{
+    if level.is_empty() {
+        return;
+    }
+    out.extend(level.iter().map(|node| node.id));
+    let next_level: Vec<&Node> = level
+        .iter()
+        .flat_map(|node| [&node.left, &node.right])
+        .filter_map(|child| child.as_deref())
+        .collect();
+    recursive(&next_level, out);
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn matches_recursive()
+{
+    let depth = 12;
+    let tree = make_numbered(depth, &mut 0).expect("non-empty");
+
+    let mut reference = Vec::new();
+    recursive(&[&tree], &mut reference);
+
+    let iterative: Vec<usize> = into_level_order::<_, Node>(tree).map(|node| node.id).collect();
+
+    assert_eq!(iterative.len(), (1 << depth) - 1);
+    assert_eq!(iterative, reference);
+}
+
+
+#[test]
+fn supplies_leaves()
+{
+    let list = Box::new(make_list(TREE_SIZE));
+
+    let mut iter = into_level_order(list);
+    let first = iter.next();
+    assert!(first.is_some_and(|node| node.left.is_none() && node.right.is_none()));
+    drop(iter);
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn bfs_peak_is_width()
+{
+    let depth = 12;
+    let tree = make_numbered(depth, &mut 0).expect("non-empty");
+    assert_eq!(deep_safe_drop_bfs::<_, Node>(tree), 1 << (depth - 1));
+}
+
+
+#[test]
+fn bfs_long_list()
+{
+    let list = (1 .. TREE_SIZE)
+        .fold(Box::new(Node { id: 0, left: None, right: None }), |acc, id| {
+            Box::new(Node { id, left: None, right: Some(acc) })
+        });
+    assert_eq!(deep_safe_drop_bfs::<_, Node>(list), 1);
+}
@@ -0,0 +1,81 @@
+use {
+    super::{
+        node::make_balanced,
+        *,
+    },
+    alloc::string::String,
+    core::cell::RefCell,
+    log::{
+        LevelFilter,
+        Log,
+        Metadata,
+        Record,
+    },
+    std::sync::Once,
+};
+
+
+std::thread_local! {
+    /// Only the messages of the current thread, because other tests run concurrently.
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+struct Capture;
+
+impl Log for Capture
+{
+    fn enabled(
+        &self,
+        _metadata: &Metadata<'_>,
+    ) -> bool
+    {
+        true
+    }
+
+    fn log(
+        &self,
+        record: &Record<'_>,
+    )
+    {
+        CAPTURED.with(|captured| {
+            if let Some(messages) = captured.borrow_mut().as_mut() {
+                messages.push(record.args().to_string());
+            }
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+#[allow(clippy::expect_used)]
+fn capture(f: impl FnOnce()) -> Vec<String>
+{
+    static INIT: Once = Once::new();
+    static CAPTURE: Capture = Capture;
+    INIT.call_once(|| {
+        log::set_logger(&CAPTURE).expect("no other logger");
+        log::set_max_level(LevelFilter::Trace);
+    });
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::new()));
+    f();
+    CAPTURED.with(|captured| captured.borrow_mut().take().unwrap_or_default())
+}
+
+
+#[test]
+fn leaf_messages()
+{
+    let depth = 5;
+    let tree = make_balanced(depth + 1);
+    let size = 2 * ((1 << depth) - 1);
+
+    let messages = capture(|| drop(tree));
+
+    let count = |action: &str| {
+        let prefix = format!("deep_safe_drop: {action} ");
+        messages.iter().filter(|message| message.starts_with(&prefix)).count()
+    };
+    assert_eq!(count("leaf"), size);
+    assert_eq!(count("descend"), size - 2);
+    assert!(messages.iter().all(|message| message.contains(" depth=")));
+}
@@ -0,0 +1,132 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+type Order = Rc<RefCell<Vec<usize>>>;
+
+struct Recorded(usize, Order);
+
+impl Drop for Recorded
+{
+    fn drop(&mut self)
+    {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct List
+{
+    #[child(index = 0)]
+    next:      Option<Box<Self>>,
+    _recorded: Recorded,
+}
+
+impl Drop for List
+{
+    deep_safe_drop::deep_safe_drop!(; self.next);
+}
+
+
+#[derive(DeepSafeDrop)]
+struct BinaryTree
+{
+    #[child(index = 0)]
+    left:      Option<Box<Self>>,
+    #[child(index = 1)]
+    right:     Option<Box<Self>>,
+    _recorded: Recorded,
+}
+
+impl Drop for BinaryTree
+{
+    deep_safe_drop::deep_safe_drop!(; self.left, self.right);
+}
+
+impl BinaryTree
+{
+    fn new(
+        id: usize,
+        order: &Order,
+        left: Option<Self>,
+        right: Option<Self>,
+    ) -> Self
+    {
+        Self {
+            left:      left.map(Box::new),
+            right:     right.map(Box::new),
+            _recorded: Recorded(id, Rc::clone(order)),
+        }
+    }
+}
+
+
+struct Trees
+{
+    first:  Option<Box<List>>,
+    second: Option<Box<List>>,
+}
+
+impl Drop for Trees
+{
+    deep_safe_drop::deep_safe_drop!(self.first, self.second);
+}
+
+
+fn make_list(
+    ids: core::ops::Range<usize>,
+    order: &Order,
+) -> Option<List>
+{
+    ids.fold(None, |acc, id| {
+        Some(List { next: acc.map(Box::new), _recorded: Recorded(id, Rc::clone(order)) })
+    })
+}
+
+
+#[test]
+fn list()
+{
+    let order = Order::default();
+    drop(make_list(0 .. TREE_SIZE, &order));
+    assert!(order.borrow().iter().copied().eq(0 .. TREE_SIZE));
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let order = Order::default();
+    let leaf = |id| Some(BinaryTree::new(id, &order, None, None));
+    let tree = BinaryTree::new(
+        0,
+        &order,
+        Some(BinaryTree::new(
+            1,
+            &order,
+            leaf(3),
+            Some(BinaryTree::new(4, &order, leaf(7), None)),
+        )),
+        Some(BinaryTree::new(2, &order, leaf(5), leaf(6))),
+    );
+    drop(tree);
+    assert_eq!(*order.borrow(), [3, 7, 4, 1, 5, 6, 2, 0]);
+}
+
+
+#[test]
+fn roots()
+{
+    let order = Order::default();
+    let half = TREE_SIZE.div_euclid(2);
+    let trees = Trees {
+        first:  make_list(0 .. half, &order).map(Box::new),
+        second: make_list(half .. TREE_SIZE, &order).map(Box::new),
+    };
+    drop(trees);
+    assert!(order.borrow().iter().copied().eq(0 .. TREE_SIZE));
+}
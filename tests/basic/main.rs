@@ -1,11 +1,92 @@
-#![cfg(test)] // Satisfy the `clippy::tests_outside_test_module` lint.
+//! Tests of basic usage with various shapes and types of trees.
+
+#![cfg(test)]
+// Satisfy the `clippy::tests_outside_test_module` lint.
+// The dependencies of the library are also given to the tests, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+
+extern crate alloc;
 
 use deep_safe_drop::*;
 
 
 mod list;
 mod binary_tree;
+mod node;
+mod bounded_stack;
+mod unordered;
 mod dyn_trait;
+mod derive;
+mod boxed;
+mod visitor;
+mod fold;
+mod cata;
+mod ana;
+mod nary;
+mod left_child_right_sibling;
+mod cycle;
+mod checked;
+mod forest;
+mod filter;
+#[cfg(not(target_arch = "wasm32"))]
+mod pre_order;
+mod post_order;
+mod profile;
+mod level_order;
+mod stats;
+mod visit_tree;
+mod stack_size;
+mod guard;
+mod macros;
+mod cursor;
+mod rc;
+mod shared_node;
+#[cfg(not(target_arch = "wasm32"))]
+mod arc;
+mod weak;
+mod doubly_linked;
+mod skip_list;
+mod trie;
+mod rope;
+mod segment_tree;
+mod btree;
+mod multi_link;
+mod btree_map;
+mod hash_map;
+mod try_drop;
+mod two_phase;
+mod partial;
+mod real_time;
+#[cfg(not(target_arch = "wasm32"))]
+mod cooperative;
+mod pinned;
+mod panics;
+mod logging;
+mod tracing_events;
+#[cfg(not(target_arch = "wasm32"))]
+mod properties;
+mod split;
+mod v2;
+mod simple;
+mod subtree;
+mod child_count_hint;
+mod dry_run;
+mod tracer;
+mod order;
+mod clone;
+mod eq;
+mod fmt;
+mod dot;
+mod serialize;
+mod drop_queue;
+mod drop_buffer;
+#[cfg(not(target_arch = "wasm32"))]
+mod background;
+#[cfg(not(target_arch = "wasm32"))]
+mod parallel;
+mod borrowing;
+mod zst;
+mod random_tree;
 
 
 /// This results in tree depths that are enough to cause stack overflows when `deep_safe_drop` is
@@ -0,0 +1,83 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::MultiLink,
+};
+
+
+struct MultiBox<const N: usize>(Box<MultiLink<Self, N>>);
+
+impl<const N: usize> Link<MultiLink<Self, N>> for MultiBox<N>
+{
+    fn get_mut(&mut self) -> &mut MultiLink<Self, N>
+    {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> core::borrow::Borrow<MultiLink<Self, N>> for MultiBox<N>
+{
+    fn borrow(&self) -> &MultiLink<Self, N>
+    {
+        &self.0
+    }
+}
+
+impl<const N: usize> Drop for MultiBox<N>
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<MultiLink<Self, N>, Self, MultiLink<Self, N>>(&mut *self.0);
+    }
+}
+
+
+/// Each node along a spine of `depth` has `N` children, the first of which continues the spine,
+/// and the rest are leaves.
+fn make_spine<const N: usize>(depth: usize) -> MultiBox<N>
+{
+    (0 .. depth).fold(MultiBox(Box::default()), |spine, _| {
+        let mut node = MultiLink::new();
+        if let [first, rest @ ..] = node.links_mut().as_mut_slice() {
+            *first = Some(spine);
+            for slot in rest {
+                *slot = Some(MultiBox(Box::default()));
+            }
+        }
+        MultiBox(Box::new(node))
+    })
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn exercise<const N: usize>()
+{
+    const DEPTH: usize = 1000;
+
+    let tree = make_spine::<N>(DEPTH);
+    assert_eq!(count_nodes::<_, MultiLink<MultiBox<N>, N>>(&tree), 1 + N * DEPTH);
+
+    let stats = deep_safe_drop_stats::<_, MultiBox<N>, _>(&mut *make_spine::<N>(DEPTH).0);
+    assert_eq!(stats.nodes_dropped, N * DEPTH);
+    assert_eq!(stats.max_depth_reached, DEPTH);
+    drop(tree);
+}
+
+
+#[test]
+fn one_through_eight()
+{
+    exercise::<1>();
+    exercise::<2>();
+    exercise::<3>();
+    exercise::<4>();
+    exercise::<5>();
+    exercise::<6>();
+    exercise::<7>();
+    exercise::<8>();
+}
+
+
+#[test]
+fn long_list()
+{
+    drop(make_spine::<1>(TREE_SIZE));
+}
@@ -0,0 +1,74 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub(super) struct NaryBox(pub(super) Box<NaryNode<Self>>);
+
+impl Link<NaryNode<Self>> for NaryBox
+{
+    fn get_mut(&mut self) -> &mut NaryNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl From<NaryNode<Self>> for NaryBox
+{
+    fn from(node: NaryNode<Self>) -> Self
+    {
+        Self(Box::new(node))
+    }
+}
+
+impl core::borrow::Borrow<NaryNode<Self>> for NaryBox
+{
+    fn borrow(&self) -> &NaryNode<Self>
+    {
+        &self.0
+    }
+}
+
+/// Comment-out to cause stack overflow.
+impl Drop for NaryBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<NaryNode<Self>, Self, NaryNode<Self>>(&mut *self.0);
+    }
+}
+
+
+/// Each node along a spine of `depth` has `degree` children, one of which continues the spine.
+pub(super) fn make_spine(
+    degree: usize,
+    depth: usize,
+) -> NaryNode<NaryBox>
+{
+    let leaf = || NaryBox(Box::default());
+    (0 .. depth).fold(NaryNode::default(), |spine, _| {
+        let mut children = Vec::with_capacity(degree);
+        children.push(NaryBox(Box::new(spine)));
+        children.extend(core::iter::repeat_with(leaf).take(degree.saturating_sub(1)));
+        NaryNode::new(children)
+    })
+}
+
+
+#[test]
+fn wide()
+{
+    let tree = make_spine(500, 500);
+    drop(tree);
+}
+
+
+#[test]
+fn deep()
+{
+    let tree = make_spine(3, TREE_SIZE.div_euclid(3));
+    drop(tree);
+}
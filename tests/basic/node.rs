@@ -0,0 +1,132 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        cell::RefCell,
+        iter::Chain,
+        option,
+    },
+};
+
+
+/// The binary tree node that is shared by the tests that do not need a particular node type.
+#[derive(DeepSafeDrop, Default)]
+pub(super) struct Node
+{
+    pub(super) id:    usize,
+    #[child(index = 0)]
+    pub(super) left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    pub(super) right: Option<Box<Self>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+impl ReadableChildren<Box<Self>> for Node
+{
+    type Children<'n> = Chain<option::Iter<'n, Box<Self>>, option::Iter<'n, Box<Self>>>;
+
+    fn children_of(&self) -> Self::Children<'_>
+    {
+        self.left.iter().chain(&self.right)
+    }
+}
+
+impl Node
+{
+    pub(super) fn new(
+        id: usize,
+        left: Option<Self>,
+        right: Option<Self>,
+    ) -> Self
+    {
+        Self { id, left: left.map(Box::new), right: right.map(Box::new) }
+    }
+}
+
+
+/// Like `Node`, but records its id in `dropped` when it is dropped, which is after its children.
+#[derive(DeepSafeDrop)]
+pub(super) struct RecordedNode
+{
+    pub(super) id:      usize,
+    #[child(index = 0)]
+    pub(super) left:    Option<Box<Self>>,
+    #[child(index = 1)]
+    pub(super) right:   Option<Box<Self>>,
+    pub(super) dropped: Rc<RefCell<Vec<usize>>>,
+}
+
+impl Drop for RecordedNode
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+        self.dropped.borrow_mut().push(self.id);
+    }
+}
+
+impl RecordedNode
+{
+    pub(super) fn new(
+        id: usize,
+        left: Option<Self>,
+        right: Option<Self>,
+        dropped: &Rc<RefCell<Vec<usize>>>,
+    ) -> Self
+    {
+        let (left, right) = (left.map(Box::new), right.map(Box::new));
+        Self { id, left, right, dropped: Rc::clone(dropped) }
+    }
+}
+
+
+/// Balanced, with `depth` levels, at least 1, so that the amount of nodes is `2^depth - 1`.  All
+/// the ids are 0.
+pub(super) fn make_balanced(depth: usize) -> Node
+{
+    let sub = || make_complete(depth.saturating_sub(1));
+    Node { id: 0, left: sub(), right: sub() }
+}
+
+/// Like `make_balanced`, but with no node when `depth` is 0.
+pub(super) fn make_complete(depth: usize) -> Option<Box<Node>>
+{
+    (depth > 0).then(|| Box::new(make_balanced(depth)))
+}
+
+/// Like `make_complete`, but with ids assigned in pre-order, from `next_id`.
+pub(super) fn make_numbered(
+    depth: usize,
+    next_id: &mut usize,
+) -> Option<Box<Node>>
+{
+    (depth > 0).then(|| {
+        let id = *next_id;
+        *next_id = id.saturating_add(1);
+        let left = make_numbered(depth.saturating_sub(1), next_id);
+        let right = make_numbered(depth.saturating_sub(1), next_id);
+        Box::new(Node { id, left, right })
+    })
+}
+
+/// A list along the left, of `len` nodes, at least 1, with ids from `len - 1` at the root to 0
+/// at the end.
+pub(super) fn make_list(len: usize) -> Node
+{
+    (1 .. len).fold(Node::new(0, None, None), |acc, id| Node::new(id, Some(acc), None))
+}
+
+/// A list along the left, of `len` nodes, at least 1, with a leaf on the right of each node.
+/// All the ids are 0.
+pub(super) fn make_comb(len: usize) -> Node
+{
+    let leaf = || Some(Node::default());
+    (1 .. len).fold(Node::new(0, None, leaf()), |acc, _| Node::new(0, Some(acc), leaf()))
+}
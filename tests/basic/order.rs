@@ -0,0 +1,73 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+/// Records its `id` when dropped, and intentionally does not use `deep_safe_drop`.
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:  Option<Box<Self>>,
+    #[child(index = 1)]
+    right: Option<Box<Self>>,
+    id:    usize,
+    log:   Rc<RefCell<Vec<usize>>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        self.log.borrow_mut().push(self.id);
+    }
+}
+
+fn make_tree(log: &Rc<RefCell<Vec<usize>>>) -> Node
+{
+    let node = |id, left: Option<Node>, right: Option<Node>| Node {
+        left: left.map(Box::new),
+        right: right.map(Box::new),
+        id,
+        log: Rc::clone(log),
+    };
+    let leaf = |id| Some(node(id, None, None));
+    node(0, Some(node(1, leaf(3), Some(node(4, leaf(7), None)))), Some(node(2, leaf(5), leaf(6))))
+}
+
+
+#[test]
+fn left_to_right()
+{
+    let log = Rc::default();
+    let mut tree = make_tree(&log);
+    deep_safe_drop_ordered::<_, Box<Node>, _, LeftToRight>(&mut tree);
+    assert_eq!(*log.borrow(), [3, 7, 4, 1, 5, 6, 2]);
+}
+
+
+#[test]
+fn right_to_left()
+{
+    let log = Rc::default();
+    let mut tree = make_tree(&log);
+    deep_safe_drop_ordered::<_, Box<Node>, _, RightToLeft>(&mut tree);
+    assert_eq!(*log.borrow(), [6, 5, 2, 7, 4, 3, 1]);
+}
+
+
+#[test]
+fn deep()
+{
+    let log = Rc::default();
+    let mut list = (1 .. TREE_SIZE).fold(make_tree(&log), |acc, id| Node {
+        left: None,
+        right: Some(Box::new(acc)),
+        id,
+        log: Rc::clone(&log),
+    });
+    deep_safe_drop_ordered::<_, Box<Node>, _, RightToLeft>(&mut list);
+    assert_eq!(log.borrow().len(), TREE_SIZE.saturating_add(6));
+}
@@ -0,0 +1,93 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        cell::Cell,
+        panic::AssertUnwindSafe,
+    },
+    std::panic::catch_unwind,
+};
+
+
+const SIZE: usize = 1000;
+const PANICKING: usize = 500;
+
+/// Counts how many nodes were dropped.
+struct DropCounter(Rc<Cell<usize>>);
+
+impl Drop for DropCounter
+{
+    fn drop(&mut self)
+    {
+        self.0.set(self.0.get().saturating_add(1));
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:     Option<Box<Self>>,
+    #[child(index = 1)]
+    right:    Option<Box<Self>>,
+    id:       usize,
+    _counter: DropCounter,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+        assert!(self.id != PANICKING, "intentional");
+    }
+}
+
+/// A tree of `SIZE` nodes, with ids in level-order.
+fn make_tree(
+    id: usize,
+    drops: &Rc<Cell<usize>>,
+) -> Node
+{
+    let child = |offset: usize| {
+        let child_id = id.saturating_mul(2).saturating_add(offset);
+        (child_id < SIZE).then(|| Box::new(make_tree(child_id, drops)))
+    };
+    Node { left: child(1), right: child(2), id, _counter: DropCounter(Rc::clone(drops)) }
+}
+
+
+/// The panic propagates, but all the nodes are still dropped, during the unwinding.
+#[test]
+fn remaining_are_dropped()
+{
+    let drops = Rc::new(Cell::new(0));
+    let tree = make_tree(0, &drops);
+
+    let result = catch_unwind(AssertUnwindSafe(|| drop(tree)));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), SIZE);
+}
+
+
+/// The visitor is not called on the remaining nodes.
+#[test]
+fn visitor_stops()
+{
+    let drops = Rc::new(Cell::new(0));
+    let visits = Cell::new(0_usize);
+    let mut tree = make_tree(0, &drops);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        deep_safe_drop_with::<_, Box<Node>, _, _>(&mut tree, |_: &mut Node| {
+            visits.set(visits.get().saturating_add(1));
+        });
+    }));
+
+    assert!(result.is_err());
+    assert!(visits.get() < SIZE.saturating_sub(1));
+    drop(tree);
+    assert_eq!(drops.get(), SIZE);
+}
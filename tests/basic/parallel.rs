@@ -0,0 +1,31 @@
+use {
+    super::{
+        nary::{
+            NaryBox,
+            make_spine,
+        },
+        *,
+    },
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+#[test]
+fn fan_of_chains()
+{
+    const WIDTH: usize = 1000;
+    let chain = || NaryBox(Box::new(make_spine(1, TREE_SIZE.div_euclid(WIDTH))));
+    let mut root = NaryNode::new(core::iter::repeat_with(chain).take(WIDTH).collect());
+
+    deep_safe_drop_parallel::<_, NaryBox, NaryNode<NaryBox>>(&mut root);
+    assert!(root.children().is_empty());
+}
+
+
+#[test]
+fn chain()
+{
+    let mut root = make_spine(2, TREE_SIZE.div_euclid(2));
+    deep_safe_drop_parallel::<_, NaryBox, NaryNode<NaryBox>>(&mut root);
+    assert!(root.children().is_empty());
+}
@@ -0,0 +1,195 @@
+use {
+    super::{
+        binary_tree::fan_depth,
+        nary::{
+            NaryBox,
+            make_spine,
+        },
+        node::{
+            Node,
+            make_balanced,
+            make_complete,
+            make_list,
+        },
+        *,
+    },
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+/// Returns the amounts dropped by each call, until done.
+fn drop_in_parts(
+    root: &mut Node,
+    max_nodes: usize,
+) -> Vec<usize>
+{
+    let amount = || deep_safe_drop_partial::<_, Box<Node>, _>(root, max_nodes);
+    core::iter::repeat_with(amount).take_while(|&dropped| dropped > 0).collect()
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let depth = fan_depth(TREE_SIZE);
+    let mut root = make_balanced(depth + 1);
+    let size = 2 * ((1 << depth) - 1);
+
+    let parts = drop_in_parts(&mut root, 1000);
+
+    assert_eq!(parts.iter().sum::<usize>(), size);
+    assert_eq!(parts.len(), size.div_ceil(1000));
+    assert!(parts.iter().all(|&dropped| dropped <= 1000));
+    assert!(root.left.is_none() && root.right.is_none());
+}
+
+
+/// Each call continues where the previous one stopped, without working back down to it, which
+/// would otherwise make this quadratic.
+#[test]
+fn list()
+{
+    let len = 1_000_000;
+    let mut root = make_list(len + 1);
+
+    let parts = drop_in_parts(&mut root, 1000);
+
+    assert_eq!(parts, [1000; 1000]);
+    assert_eq!(deep_safe_drop_partial::<_, Box<Node>, _>(&mut root, 1000), 0);
+}
+
+
+#[test]
+fn exactly_n()
+{
+    let mut root = make_balanced(4);
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 14), (14, true));
+    assert!(root.left.is_none() && root.right.is_none());
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 14), (0, true));
+}
+
+
+#[test]
+fn n_resumes()
+{
+    let depth = 10;
+    let size = 2 * ((1 << depth) - 1);
+    let mut root = make_balanced(depth + 1);
+
+    let mut parts = Vec::new();
+    loop {
+        let (dropped, done) = deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 100);
+        parts.push(dropped);
+        if done {
+            break;
+        }
+        assert_eq!(dropped, 100);
+    }
+
+    assert_eq!(parts.iter().sum::<usize>(), size);
+    assert_eq!(parts.len(), size.div_ceil(100));
+    assert!(root.left.is_none() && root.right.is_none());
+}
+
+
+#[test]
+fn n_more_than_remaining()
+{
+    let mut root = make_list(11);
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 0), (0, false));
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 9), (9, false));
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut root, 9), (1, true));
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, _>(&mut Node::default(), 0), (0, true));
+}
+
+
+/// A root without a link at index 0, where the rest would be stored, and so whose children are
+/// all at the positive indices.
+#[allow(clippy::vec_box)] // The link type is what is taken.
+struct Fan(Vec<Box<Node>>);
+
+impl DeepSafeDrop<Box<Node>> for Fan
+{
+    fn take_child_at_index_0(&mut self) -> Option<Box<Node>>
+    {
+        None
+    }
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Node>,
+    ) -> SetParent<Box<Node>>
+    {
+        SetParent::No { returned_parent: parent }
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Node>>
+    {
+        self.0.pop()
+    }
+}
+
+
+#[test]
+fn n_without_index_0()
+{
+    let subtrees = || [3, 2].into_iter().filter_map(make_complete).collect();
+
+    // After the 3 of the last subtree, 4 of the first, whose 3 others then cannot be stored.
+    let mut root = Fan(subtrees());
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, Node>(&mut root, 7), (10, true));
+    assert!(root.0.is_empty());
+
+    // Exactly the last subtree, and then the first cannot be stored.
+    let mut exact = Fan(subtrees());
+    assert_eq!(deep_safe_drop_n::<_, Box<Node>, Node>(&mut exact, 3), (10, true));
+}
+
+
+#[test]
+fn within()
+{
+    let depth = 10;
+    let size = 2 * ((1 << depth) - 1);
+    let mut root = make_balanced(depth + 1);
+
+    let stopped = deep_safe_drop_within::<_, Box<Node>, _>(&mut root, 1000);
+    assert!(
+        matches!(stopped, Err(DropError::PartialDropAborted { nodes_remaining })
+                 if nodes_remaining == size - 1000),
+        "{stopped:?}"
+    );
+
+    let finished = deep_safe_drop_within::<_, Box<Node>, _>(&mut root, size);
+    assert!(matches!(finished, Ok(dropped) if dropped == size - 1000), "{finished:?}");
+    assert!(root.left.is_none() && root.right.is_none());
+}
+
+
+/// The stored rest is in the link at index 0, which is not one of the `children_of` a
+/// `NaryNode`, and so it must be counted from the link that was stored.
+#[test]
+fn within_nary()
+{
+    let size = 10;
+    let mut root = NaryNode::new(vec![NaryBox::from(make_spine(3, 3))]);
+
+    let stopped = deep_safe_drop_within::<_, NaryBox, _>(&mut root, 3);
+    assert!(
+        matches!(stopped, Err(DropError::PartialDropAborted { nodes_remaining })
+                 if nodes_remaining == size - 3),
+        "{stopped:?}"
+    );
+
+    let parts: Vec<usize> = core::iter::repeat_with(|| {
+        deep_safe_drop_partial::<_, NaryBox, NaryNode<NaryBox>>(&mut root, 3)
+    })
+    .take_while(|&dropped| dropped > 0)
+    .collect();
+    assert_eq!(parts, [3, 3, 1]);
+
+    let mut whole = NaryNode::new(vec![NaryBox::from(make_spine(3, 3))]);
+    let finished = deep_safe_drop_within::<_, NaryBox, _>(&mut whole, size);
+    assert!(matches!(finished, Ok(dropped) if dropped == size), "{finished:?}");
+    assert!(whole.children().is_empty());
+}
@@ -0,0 +1,87 @@
+use {
+    super::*,
+    core::pin::Pin,
+};
+
+
+/// A list node that is `Unpin`, linked via pinned boxes.
+struct Node
+{
+    next:      Option<PinnedLink<Self>>,
+    deep_safe: bool,
+}
+
+impl Node
+{
+    fn make_list(
+        len: usize,
+        deep_safe: bool,
+    ) -> Pin<Box<Self>>
+    {
+        let tail =
+            (1 .. len).fold(None, |next, _| Some(PinnedLink::new(Self { next, deep_safe })));
+        Box::pin(Self { next: tail, deep_safe })
+    }
+}
+
+impl DeepSafeDrop<PinnedLink<Self>> for Node
+{
+    fn take_child_at_index_0(&mut self) -> Option<PinnedLink<Self>>
+    {
+        self.next.take()
+    }
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: PinnedLink<Self>,
+    ) -> SetParent<PinnedLink<Self>>
+    {
+        if let Some(child0) = self.next.replace(parent) {
+            SetParent::YesReplacedChild { child0 }
+        }
+        else {
+            SetParent::Yes
+        }
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<PinnedLink<Self>>
+    {
+        None
+    }
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        if self.deep_safe {
+            deep_safe_drop::<Self, PinnedLink<Self>, Self>(self);
+        }
+    }
+}
+
+
+#[test]
+fn drop_impl()
+{
+    let list = Node::make_list(TREE_SIZE, true);
+    drop(list);
+}
+
+
+/// The `Drop` implementation does nothing, so `drop_pinned` is what avoids stack overflow.
+#[test]
+fn drop_pinned_fn()
+{
+    let list = Node::make_list(TREE_SIZE, false);
+    drop_pinned(list);
+}
+
+
+#[test]
+fn into_pin()
+{
+    let link = PinnedLink::from(Node::make_list(3, true));
+    let pinned = link.into_pin();
+    assert!(pinned.next.is_some());
+}
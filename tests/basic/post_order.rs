@@ -0,0 +1,70 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+    },
+    *,
+};
+
+
+#[test]
+fn order()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let tree = Node::new(
+        0,
+        Some(Node::new(1, leaf(3), Some(Node::new(4, leaf(7), None)))),
+        Some(Node::new(2, leaf(5), leaf(6))),
+    );
+
+    let order: Vec<usize> =
+        into_post_order::<_, Node>(Box::new(tree)).map(|node| node.id).collect();
+
+    assert_eq!(order, [3, 7, 4, 1, 5, 6, 2, 0]);
+}
+
+
+#[test]
+fn recycle()
+{
+    let list = make_list(TREE_SIZE);
+
+    let pool: Vec<Box<Node>> = into_post_order(Box::new(list)).collect();
+
+    assert_eq!(pool.len(), TREE_SIZE);
+    assert!(pool.iter().all(|node| node.left.is_none() && node.right.is_none()));
+    assert_eq!(pool.first().map(|node| node.id), Some(0));
+}
+
+
+#[test]
+fn drop_unfinished()
+{
+    let list = make_list(TREE_SIZE);
+
+    let mut iter = into_post_order(Box::new(list));
+    assert_eq!(iter.next().map(|node| node.id), Some(0));
+    drop(iter);
+}
+
+
+#[test]
+fn topological()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let tree = Node::new(
+        0,
+        Some(Node::new(1, leaf(3), Some(Node::new(4, leaf(7), None)))),
+        Some(Node::new(2, leaf(5), leaf(6))),
+    );
+    let edges = [(0, 1), (0, 2), (1, 3), (1, 4), (4, 7), (2, 5), (2, 6)];
+
+    let order: Vec<usize> =
+        topological_order::<_, Node>(Box::new(tree)).iter().map(|node| node.id).collect();
+
+    assert_eq!(order.len(), 8);
+    let index = |id| order.iter().position(|&other| other == id);
+    for (parent, child) in edges {
+        assert!(index(parent) < index(child), "{parent} must be before {child}");
+    }
+}
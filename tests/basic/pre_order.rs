@@ -0,0 +1,57 @@
+use {
+    super::{
+        nary::{
+            NaryBox,
+            make_spine,
+        },
+        *,
+    },
+    core::borrow::Borrow as _,
+    deep_safe_drop::helpers::NaryNode,
+    std::thread,
+};
+
+
+const DEPTH: usize = 10_000;
+
+
+/// Reference implementation, which uses the call-stack.
+fn recursive<'n>(
+    node: &'n NaryNode<NaryBox>,
+    out: &mut Vec<&'n NaryNode<NaryBox>>,
+)
+{
+    out.push(node);
+    for child in node.children_of() {
+        recursive(child.borrow(), out);
+    }
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn matches_recursive()
+{
+    let root = NaryBox(Box::new(make_spine(3, DEPTH)));
+
+    let iterative: Vec<&NaryNode<NaryBox>> = pre_order(&root).collect();
+
+    // Enough stack for the recursion.
+    let stack_size = 1 << 26;
+    let reference = thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(stack_size)
+            .spawn_scoped(scope, || {
+                let mut out = Vec::new();
+                recursive(root.borrow(), &mut out);
+                out
+            })
+            .expect("spawn")
+            .join()
+            .expect("join")
+    });
+
+    assert_eq!(iterative.len(), 3 * DEPTH + 1);
+    assert_eq!(iterative.len(), reference.len());
+    assert!(iterative.iter().zip(&reference).all(|(a, b)| core::ptr::eq(*a, *b)));
+}
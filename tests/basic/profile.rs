@@ -0,0 +1,66 @@
+use super::{
+    binary_tree::fan_depth,
+    node::{
+        Node,
+        make_balanced,
+        make_list,
+    },
+    *,
+};
+
+
+fn assert_ordered(profile: &ProfiledDrop)
+{
+    for step_type in [StepType::Descent, StepType::Ascent, StepType::Drop] {
+        let percentiles =
+            [Percentile::Min, Percentile::P50, Percentile::P90, Percentile::P99, Percentile::Max]
+                .map(|which| profile.percentile(which, step_type));
+        let is_sorted = percentiles.iter().zip(&percentiles[1 ..]).all(|(a, b)| a <= b);
+        assert!(is_sorted, "{step_type:?}: {percentiles:?}");
+        let max = profile.times_ns(step_type).iter().copied().max().unwrap_or(0);
+        assert_eq!(percentiles.last(), Some(&max));
+    }
+}
+
+
+#[test]
+fn list()
+{
+    let len = 10_000;
+    let mut root = make_list(len + 1);
+
+    let profile = deep_safe_drop_profiled::<_, Box<Node>, _>(&mut root);
+
+    // Each node but the top of the traversal is descended into.
+    assert_eq!(profile.times_ns(StepType::Descent).len(), len - 1);
+    assert_eq!(profile.times_ns(StepType::Drop).len(), len);
+    assert_ordered(&profile);
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let depth = fan_depth(TREE_SIZE);
+    let mut root = make_balanced(depth + 1);
+    let size = 2 * ((1 << depth) - 1);
+
+    let profile = deep_safe_drop_profiled::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(profile.times_ns(StepType::Descent).len(), size - 2);
+    assert_eq!(profile.times_ns(StepType::Drop).len(), size);
+    assert!(!profile.times_ns(StepType::Ascent).is_empty());
+    assert_ordered(&profile);
+}
+
+
+#[test]
+fn empty()
+{
+    let mut root = Node::default();
+
+    let profile = deep_safe_drop_profiled::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(profile, ProfiledDrop::default());
+    assert_eq!(profile.percentile(Percentile::P99, StepType::Drop), 0);
+}
@@ -0,0 +1,265 @@
+use {
+    super::{
+        node::RecordedNode,
+        *,
+    },
+    alloc::rc::Rc,
+    core::{
+        cell::RefCell,
+        sync::atomic::{
+            AtomicUsize,
+            Ordering::Relaxed,
+        },
+    },
+    deep_safe_drop::helpers::NaryNode,
+    nary::NaryBox,
+    proptest::{
+        collection::vec,
+        prelude::*,
+    },
+    rand::SeedableRng as _,
+    rand_xorshift::XorShiftRng,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    core::time::Duration,
+    std::{
+        sync::mpsc,
+        thread,
+    },
+};
+
+
+/// The shape of a tree of at most binary nodes, independent of any dropping.
+#[derive(Clone, Debug)]
+struct Shape(Vec<Shape>);
+
+fn list(depth: usize) -> Shape
+{
+    (1 .. depth).fold(Shape(Vec::new()), |child, _| Shape(Vec::from([child])))
+}
+
+fn binary(max_size: u32) -> impl Strategy<Value = Shape>
+{
+    Just(Shape(Vec::new()))
+        .prop_recursive(10, max_size, 2, |inner| vec(inner, 0 ..= 2).prop_map(Shape))
+}
+
+/// Each node has either 0 or 2 children.
+fn full_binary() -> impl Strategy<Value = Shape>
+{
+    Just(Shape(Vec::new())).prop_recursive(10, 512, 2, |inner| {
+        prop_oneof![
+            Just(Shape(Vec::new())),
+            (inner.clone(), inner).prop_map(|(left, right)| Shape(Vec::from([left, right])))
+        ]
+    })
+}
+
+/// `List`-shaped and `BinaryTree`-shaped trees, uniformly.
+fn shapes(max_list_depth: usize) -> impl Strategy<Value = Shape>
+{
+    prop_oneof![(1 ..= max_list_depth).prop_map(list), binary(512)]
+}
+
+impl Shape
+{
+    /// Uses the call-stack, which is fine for the depths generated.
+    fn size(&self) -> usize
+    {
+        self.0.iter().map(Self::size).fold(1, usize::saturating_add)
+    }
+
+    /// The ids, assigned in pre-order by `build`, in post-order.
+    fn post_order(
+        &self,
+        next_id: &mut usize,
+        out: &mut Vec<usize>,
+    )
+    {
+        let id = *next_id;
+        *next_id = next_id.saturating_add(1);
+        for child in &self.0 {
+            child.post_order(next_id, out);
+        }
+        out.push(id);
+    }
+
+    /// The `(parent, child)` pairs of the ids, assigned in pre-order by `build`.
+    fn parent_child_pairs(
+        &self,
+        next_id: &mut usize,
+        out: &mut Vec<(usize, usize)>,
+    )
+    {
+        let id = *next_id;
+        *next_id = next_id.saturating_add(1);
+        for child in &self.0 {
+            out.push((id, *next_id));
+            child.parent_child_pairs(next_id, out);
+        }
+    }
+
+    fn build_sequenced(
+        &self,
+        next_id: &mut usize,
+        sequences: &Rc<RefCell<Vec<Option<usize>>>>,
+    ) -> SequencedNode
+    {
+        let id = *next_id;
+        *next_id = next_id.saturating_add(1);
+        let mut children =
+            self.0.iter().map(|child| Box::new(child.build_sequenced(next_id, sequences)));
+        SequencedNode {
+            left:      children.next(),
+            right:     children.next(),
+            sequencer: DropSequencer { id, sequences: Rc::clone(sequences) },
+        }
+    }
+
+    fn build(
+        &self,
+        next_id: &mut usize,
+        dropped: &Rc<RefCell<Vec<usize>>>,
+    ) -> RecordedNode
+    {
+        let id = *next_id;
+        *next_id = next_id.saturating_add(1);
+        let mut children = self.0.iter().map(|child| child.build(next_id, dropped));
+        RecordedNode::new(id, children.next(), children.next(), dropped)
+    }
+}
+
+
+/// Global, so that the numbers only ever increase, even across the tests' threads.
+static SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+
+/// Records, when dropped, the next number of the global sequence as that of its node's id.
+struct DropSequencer
+{
+    id:        usize,
+    sequences: Rc<RefCell<Vec<Option<usize>>>>,
+}
+
+impl Drop for DropSequencer
+{
+    fn drop(&mut self)
+    {
+        let seq = SEQUENCE.fetch_add(1, Relaxed);
+        if let Some(slot) = self.sequences.borrow_mut().get_mut(self.id) {
+            *slot = Some(seq);
+        }
+    }
+}
+
+/// The `sequencer` is dropped after the `Drop::drop`, i.e. after the children are dropped.
+#[derive(DeepSafeDrop)]
+struct SequencedNode
+{
+    #[child(index = 0)]
+    left:      Option<Box<Self>>,
+    #[child(index = 1)]
+    right:     Option<Box<Self>>,
+    #[allow(dead_code)]
+    sequencer: DropSequencer,
+}
+
+impl Drop for SequencedNode
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+proptest! {
+    #[test]
+    fn drops_every_node(shape in shapes(500))
+    {
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        drop(shape.build(&mut 0, &dropped));
+        prop_assert_eq!(dropped.borrow().len(), shape.size());
+    }
+
+    #[test]
+    fn post_order(shape in shapes(100))
+    {
+        let mut expected = Vec::new();
+        shape.post_order(&mut 0, &mut expected);
+        let root_id = expected.pop();
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        let mut root = shape.build(&mut 0, &dropped);
+        let mut order = Vec::new();
+        let visitor = |node: &mut RecordedNode| order.push(node.id);
+        deep_safe_drop_with::<_, Box<RecordedNode>, _, _>(&mut root, visitor);
+
+        prop_assert_eq!(order, expected);
+        prop_assert_eq!(Some(root.id), root_id);
+    }
+
+    /// The formal statement of post-order: every child is dropped before its parent.
+    #[test]
+    fn children_before_parents(shape in binary(1000))
+    {
+        let mut pairs = Vec::new();
+        shape.parent_child_pairs(&mut 0, &mut pairs);
+
+        let sequences = Rc::new(RefCell::new(vec![None; shape.size()]));
+        drop(shape.build_sequenced(&mut 0, &sequences));
+        let sequences = sequences.borrow();
+
+        prop_assert!(sequences.iter().all(Option::is_some));
+        for (parent, child) in pairs {
+            let seq = |id: usize| sequences.get(id).copied().flatten();
+            prop_assert!(seq(child) < seq(parent), "child {} after parent {}", child, parent);
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn terminates(shape in shapes(500))
+    {
+        let (sender, receiver) = mpsc::channel();
+        let _detached = thread::spawn(move || {
+            drop(shape.build(&mut 0, &Rc::new(RefCell::new(Vec::new()))));
+            sender.send(()).ok()
+        });
+        prop_assert!(receiver.recv_timeout(Duration::from_secs(10)).is_ok());
+    }
+
+    /// For full binary trees, there is one more leaf than internal nodes.
+    #[test]
+    fn handshaking(shape in full_binary())
+    {
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        let subtree = shape.build(&mut 1, &dropped);
+        let mut root = RecordedNode::new(0, Some(subtree), None, &dropped);
+
+        let stats = deep_safe_drop_stats::<_, Box<RecordedNode>, _>(&mut root);
+
+        prop_assert_eq!(stats.internal_nodes.saturating_add(1), stats.leaf_nodes);
+        prop_assert_eq!(stats.nodes_dropped, shape.size());
+    }
+
+    /// Varying arity and depth, including depth 1, breadth 1, and trees that use all the budget.
+    #[test]
+    fn random_shapes(
+        max_depth in 1_usize ..= 1000,
+        max_breadth in 1_usize ..= 8,
+        budget in 1_usize ..= 5000,
+        seed in any::<u64>(),
+    )
+    {
+        let builder = RandomTreeBuilder::new(max_depth, max_breadth, budget);
+        let mut root = NaryBox::from(builder.build_random(&mut XorShiftRng::seed_from_u64(seed)));
+        let size = count_nodes::<_, NaryNode<NaryBox>>(&root);
+
+        let stats = deep_safe_drop_stats::<_, NaryBox, _>(&mut *root.0);
+
+        prop_assert!(size <= budget);
+        prop_assert_eq!(stats.nodes_dropped, size.saturating_sub(1));
+        prop_assert!(stats.max_depth_reached < max_depth);
+    }
+}
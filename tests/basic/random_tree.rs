@@ -0,0 +1,60 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::NaryNode,
+    nary::NaryBox,
+    rand::SeedableRng as _,
+    rand_xorshift::XorShiftRng,
+};
+
+
+fn count(node: NaryNode<NaryBox>) -> usize
+{
+    count_nodes::<_, NaryNode<NaryBox>>(&NaryBox::from(node))
+}
+
+
+#[test]
+fn list()
+{
+    assert_eq!(count(RandomTreeBuilder::build_list(0)), 1);
+    assert_eq!(count(RandomTreeBuilder::build_list(1)), 1);
+
+    let mut root = NaryBox::from(RandomTreeBuilder::build_list(TREE_SIZE));
+    let stats = deep_safe_drop_stats::<_, NaryBox, _>(&mut *root.0);
+    assert_eq!(stats.nodes_dropped, TREE_SIZE - 1);
+    assert_eq!(stats.max_depth_reached, TREE_SIZE - 1);
+}
+
+
+#[test]
+fn balanced_binary()
+{
+    assert_eq!(count(RandomTreeBuilder::build_balanced_binary(0)), 1);
+    assert_eq!(count(RandomTreeBuilder::build_balanced_binary(1)), 1);
+    assert_eq!(count(RandomTreeBuilder::build_balanced_binary(16)), (1 << 16) - 1);
+
+    let mut root = NaryBox::from(RandomTreeBuilder::build_balanced_binary(10));
+    let stats = deep_safe_drop_stats::<_, NaryBox, _>(&mut *root.0);
+    assert_eq!(stats.max_depth_reached, 9);
+    assert_eq!(stats.leaf_nodes, 1 << 9);
+}
+
+
+#[test]
+fn random_limits()
+{
+    let mut rng = XorShiftRng::seed_from_u64(0);
+
+    let depth_1 = RandomTreeBuilder::new(1, 8, TREE_SIZE);
+    assert_eq!(count(depth_1.build_random(&mut rng)), 1);
+
+    let breadth_0 = RandomTreeBuilder::new(TREE_SIZE, 0, TREE_SIZE);
+    assert_eq!(count(breadth_0.build_random(&mut rng)), 1);
+
+    let budget_0 = RandomTreeBuilder::new(TREE_SIZE, 8, 0);
+    assert_eq!(count(budget_0.build_random(&mut rng)), 1);
+
+    // Wide enough that the budget is used up before the tree stops growing.
+    let full = RandomTreeBuilder::new(TREE_SIZE, 8, 10_000);
+    assert_eq!(count(full.build_random(&mut rng)), 10_000);
+}
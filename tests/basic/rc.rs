@@ -0,0 +1,73 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+type Drops = Rc<RefCell<Vec<usize>>>;
+
+struct Recorded(usize, Drops);
+
+impl Drop for Recorded
+{
+    fn drop(&mut self)
+    {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:      Option<RcLink<Self>>,
+    #[child(index = 1)]
+    right:     Option<RcLink<Self>>,
+    _recorded: Recorded,
+}
+
+fn node(
+    id: usize,
+    drops: &Drops,
+    left: Option<RcLink<Node>>,
+    right: Option<RcLink<Node>>,
+) -> RcLink<Node>
+{
+    RcLink::new(Node { left, right, _recorded: Recorded(id, Rc::clone(drops)) })
+}
+
+
+#[test]
+fn diamond()
+{
+    let drops = Drops::default();
+    let d = node(3, &drops, None, None);
+    let b = node(1, &drops, Some(d.clone()), None);
+    let c = node(2, &drops, Some(d), None);
+    let a = node(0, &drops, Some(b), Some(c));
+
+    drop(a);
+
+    let mut dropped = drops.borrow().clone();
+    dropped.sort_unstable();
+    assert_eq!(dropped, [0, 1, 2, 3]);
+}
+
+
+#[test]
+fn shared_tail()
+{
+    let drops = Drops::default();
+    let len = TREE_SIZE.div_euclid(2);
+    let tail = (0 .. len).fold(None, |acc, id| Some(node(id, &drops, acc, None)));
+    let shared = tail.clone();
+    let head = (len .. TREE_SIZE).fold(tail, |acc, id| Some(node(id, &drops, acc, None)));
+
+    drop(head);
+    assert_eq!(drops.borrow().len(), TREE_SIZE - len);
+
+    drop(shared);
+    assert_eq!(drops.borrow().len(), TREE_SIZE);
+}
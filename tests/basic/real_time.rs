@@ -0,0 +1,67 @@
+use super::{
+    node::{
+        Node,
+        make_comb,
+    },
+    *,
+};
+
+
+#[test]
+fn steps()
+{
+    let len = TREE_SIZE;
+    let total = len.saturating_mul(2);
+    let mut real_time = RealTimeDrop::<_, Node>::new(Box::new(make_comb(len)));
+    // The derived `child_count_hint` is unknown, and so only the root is certain.
+    assert_eq!(real_time.status(), RealTimeDropStatus::Pending(1));
+    // The root, its two children, and the two children of its left child.
+    assert_eq!(real_time.total_remaining_estimate(), 5);
+
+    let max_nodes = 1000;
+    let mut steps: usize = 0;
+    let mut prev = 0;
+    let total_dropped = loop {
+        steps = steps.saturating_add(1);
+        match real_time.step(max_nodes) {
+            RealTimeDropStatus::Pending(estimate) => {
+                let dropped = real_time.dropped_count();
+                assert_eq!(dropped.saturating_sub(prev), max_nodes);
+                assert!(0 < estimate && estimate <= total - dropped);
+                assert!(real_time.total_remaining_estimate() <= total - dropped);
+                prev = dropped;
+            },
+            RealTimeDropStatus::Complete(dropped) => break dropped,
+        }
+    };
+
+    assert_eq!(total_dropped, total);
+    // The last step drops the root, and fewer than `max_nodes` of the others.
+    assert_eq!(steps, total.saturating_sub(1).div_euclid(max_nodes).saturating_add(1));
+    assert_eq!(real_time.total_remaining_estimate(), 0);
+    assert_eq!(real_time.step(max_nodes), RealTimeDropStatus::Complete(total));
+}
+
+
+#[test]
+fn drop_before_complete()
+{
+    let mut real_time = RealTimeDrop::<_, Node>::new(Box::new(make_comb(TREE_SIZE)));
+    assert!(matches!(real_time.step(10), RealTimeDropStatus::Pending(_)));
+    assert_eq!(real_time.dropped_count(), 10);
+    drop(real_time);
+}
+
+
+/// A budget of 0 is the same as 1, so that this completes.
+#[test]
+fn zero_budget()
+{
+    let mut real_time = RealTimeDrop::<_, Node>::new(Box::new(make_comb(3)));
+    let mut steps: usize = 0;
+    while let RealTimeDropStatus::Pending(_) = real_time.step(0) {
+        steps = steps.saturating_add(1);
+    }
+    assert_eq!(steps, 5);
+    assert_eq!(real_time.status(), RealTimeDropStatus::Complete(6));
+}
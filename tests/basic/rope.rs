@@ -0,0 +1,66 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::RopeNode,
+};
+
+
+struct RopeBox(Box<RopeNode<Self>>);
+
+impl Link<RopeNode<Self>> for RopeBox
+{
+    fn get_mut(&mut self) -> &mut RopeNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl core::borrow::Borrow<RopeNode<Self>> for RopeBox
+{
+    fn borrow(&self) -> &RopeNode<Self>
+    {
+        &self.0
+    }
+}
+
+impl Drop for RopeBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<RopeNode<Self>, Self, RopeNode<Self>>(&mut *self.0);
+    }
+}
+
+fn leaf(piece: &str) -> RopeBox
+{
+    RopeBox(Box::new(RopeNode::Leaf(piece.into())))
+}
+
+
+#[test]
+fn prepended()
+{
+    let rope = (1 .. TREE_SIZE)
+        .fold(leaf("a"), |rope, _| RopeBox(Box::new(RopeNode::concat(leaf("a"), rope))));
+    assert_eq!(rope.0.len(), TREE_SIZE);
+    assert_eq!(count_nodes::<_, RopeNode<RopeBox>>(&rope), 2 * TREE_SIZE - 1);
+    drop(rope);
+}
+
+
+#[test]
+fn appended()
+{
+    let rope = (1 .. TREE_SIZE)
+        .fold(leaf("ab"), |rope, _| RopeBox(Box::new(RopeNode::concat(rope, leaf("ab")))));
+    assert_eq!(rope.0.len(), 2 * TREE_SIZE);
+    drop(rope);
+}
+
+
+#[test]
+fn empty()
+{
+    let rope = RopeNode::concat(leaf(""), leaf(""));
+    assert!(rope.is_empty());
+    assert_eq!(rope.children_of().count(), 2);
+}
@@ -0,0 +1,83 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::SegmentTreeNode,
+    rand::{
+        RngExt as _,
+        SeedableRng as _,
+    },
+    rand_xorshift::XorShiftRng,
+};
+
+
+struct SegBox(Box<SegmentTreeNode<Self>>);
+
+impl Link<SegmentTreeNode<Self>> for SegBox
+{
+    fn get_mut(&mut self) -> &mut SegmentTreeNode<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl core::borrow::Borrow<SegmentTreeNode<Self>> for SegBox
+{
+    fn borrow(&self) -> &SegmentTreeNode<Self>
+    {
+        &self.0
+    }
+}
+
+impl Drop for SegBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<SegmentTreeNode<Self>, Self, SegmentTreeNode<Self>>(&mut *self.0);
+    }
+}
+
+fn make_link(node: SegmentTreeNode<SegBox>) -> SegBox
+{
+    SegBox(Box::new(node))
+}
+
+
+/// Updates at random indices of the range of almost all of `usize`, whose paths are each about as
+/// deep as its bits, until the tree has at least 1M nodes.
+#[test]
+fn sparse()
+{
+    const RANGE: usize = usize::MAX;
+    const MID: usize = 1 << (usize::BITS - 1);
+
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    let mut root = SegBox(Box::new(SegmentTreeNode::new(0, RANGE)));
+    let mut updates = 0;
+    let mut size = 1;
+    while size < 1_000_000 {
+        for _ in 0 .. 1000 {
+            root.0.add(rng.random_range(0 .. RANGE), 1, make_link);
+        }
+        updates += 1000;
+        size = count_nodes::<_, SegmentTreeNode<SegBox>>(&root);
+    }
+
+    assert_eq!(root.0.value(), updates);
+    assert_eq!(root.0.sum(0, MID) + root.0.sum(MID, RANGE), updates);
+    drop(root);
+}
+
+
+#[test]
+fn dense()
+{
+    let mut root = SegmentTreeNode::<SegBox>::new(0, 100);
+    for index in 0 .. 100 {
+        root.add(index, i64::try_from(index).unwrap_or(0), make_link);
+    }
+    root.add(100, 1, make_link);
+
+    assert_eq!(root.value(), (0 .. 100).sum::<i64>());
+    assert_eq!(root.sum(10, 20), (10 .. 20).sum::<i64>());
+    assert_eq!(root.sum(90, 1000), (90 .. 100).sum::<i64>());
+    assert_eq!(root.range(), 0 .. 100);
+}
@@ -0,0 +1,48 @@
+use {
+    super::{
+        nary::{
+            NaryBox,
+            make_spine,
+        },
+        *,
+    },
+    deep_safe_drop::helpers::NaryNode,
+};
+
+
+/// Within the default recursion limit of `serde_json`, which counts both the objects and the
+/// arrays of the nesting.
+const DEPTH: usize = 50;
+
+
+#[test]
+fn nary_roundtrip()
+{
+    let tree = NaryBox(Box::new(make_spine(10, DEPTH)));
+    let json = serde_json::to_string(&tree).unwrap_or_default();
+    let back: Option<NaryBox> = serde_json::from_str(&json).ok();
+
+    let same = back.as_ref().is_some_and(|back| {
+        deep_safe_eq_by::<_, _, NaryNode<NaryBox>, NaryNode<NaryBox>, _>(&tree, back, |_, _| true)
+    });
+    assert!(same);
+}
+
+
+#[test]
+fn leaf()
+{
+    let json = serde_json::to_string(&NaryBox(Box::default())).unwrap_or_default();
+    assert_eq!(json, r#"{"children":[]}"#);
+}
+
+
+#[test]
+fn statistics()
+{
+    let mut root = make_spine(3, 5);
+    let stats = deep_safe_drop_stats::<_, NaryBox, NaryNode<NaryBox>>(&mut root);
+    let json = serde_json::to_string(&stats).unwrap_or_default();
+    let back: Option<DropStatistics> = serde_json::from_str(&json).ok();
+    assert_eq!(back, Some(stats));
+}
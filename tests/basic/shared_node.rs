@@ -0,0 +1,83 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+type Drops = Rc<RefCell<Vec<usize>>>;
+
+struct Recorded(usize, Drops);
+
+impl Drop for Recorded
+{
+    fn drop(&mut self)
+    {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:      Option<SharedNode<Self>>,
+    #[child(index = 1)]
+    right:     Option<SharedNode<Self>>,
+    value:     usize,
+    _recorded: Recorded,
+}
+
+fn node(
+    id: usize,
+    drops: &Drops,
+    left: Option<SharedNode<Node>>,
+    right: Option<SharedNode<Node>>,
+) -> SharedNode<Node>
+{
+    SharedNode::new(Node { left, right, value: id, _recorded: Recorded(id, Rc::clone(drops)) })
+}
+
+
+#[test]
+fn diamond()
+{
+    const A: usize = 0;
+    const B: usize = 1;
+    const C: usize = 2;
+    const D: usize = 3;
+
+    let drops = Drops::default();
+    let d = node(D, &drops, None, None);
+    let b = node(B, &drops, Some(d.clone()), None);
+    let c = node(C, &drops, Some(d.clone()), None);
+    let a = node(A, &drops, Some(b.clone()), Some(c.clone()));
+
+    // Mutating through one of the shared references is seen through the others.
+    d.borrow_mut().value = 42;
+    assert_eq!(b.borrow().left.as_ref().map(|child| child.borrow().value), Some(42));
+    assert_eq!(c.borrow().left.as_ref().map(|child| child.borrow().value), Some(42));
+    drop(d);
+
+    drop(a);
+    assert_eq!(*drops.borrow(), [A]);
+
+    drop(b);
+    assert_eq!(*drops.borrow(), [A, B]);
+
+    drop(c);
+    let mut dropped = drops.borrow().clone();
+    dropped.sort_unstable();
+    assert_eq!(dropped, [A, B, C, D]);
+}
+
+
+#[test]
+fn long_list()
+{
+    let drops = Drops::default();
+    let head = (0 .. TREE_SIZE).fold(None, |acc, id| Some(node(id, &drops, acc, None)));
+    drop(head);
+    assert_eq!(drops.borrow().len(), TREE_SIZE);
+}
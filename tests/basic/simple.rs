@@ -0,0 +1,65 @@
+use super::*;
+
+
+struct Node<L>
+{
+    next: Option<L>,
+    id:   usize,
+}
+
+impl<L> SimpleDeepSafeDrop for Node<L>
+{
+    type Link = L;
+
+    fn swap_with_parent(
+        &mut self,
+        parent: L,
+    ) -> Option<L>
+    {
+        self.next.replace(parent)
+    }
+
+    fn take_sole_child(&mut self) -> Option<L>
+    {
+        self.next.take()
+    }
+}
+
+struct NodeBox(Box<Node<Self>>);
+
+impl Link<Node<Self>> for NodeBox
+{
+    fn get_mut(&mut self) -> &mut Node<Self>
+    {
+        &mut self.0
+    }
+}
+
+impl Drop for NodeBox
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<Node<Self>, Self, Node<Self>>(&mut *self.0);
+    }
+}
+
+
+#[test]
+fn no_stack_overflow()
+{
+    let list = (0 .. TREE_SIZE).fold(NodeBox(Box::new(Node { next: None, id: 0 })), |acc, id| {
+        NodeBox(Box::new(Node { next: Some(acc), id }))
+    });
+    drop(list);
+}
+
+
+#[test]
+fn post_order()
+{
+    let list = (1 ..= 5).fold(NodeBox(Box::new(Node { next: None, id: 0 })), |acc, id| {
+        NodeBox(Box::new(Node { next: Some(acc), id }))
+    });
+    let ids: Vec<usize> = into_post_order(list).map(|node| node.0.id).collect();
+    assert_eq!(ids, [0, 1, 2, 3, 4, 5]);
+}
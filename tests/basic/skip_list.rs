@@ -0,0 +1,113 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::Cell,
+    deep_safe_drop::helpers::SkipListNode,
+};
+
+
+const LEVELS: usize = 4;
+
+/// The node type, which must be a distinct type, to not be infinitely recursive.
+struct Skip
+{
+    node:  SkipListNode<SharedNode<Self>, LEVELS>,
+    drops: Rc<Cell<usize>>,
+}
+
+impl DeepSafeDrop<SharedNode<Self>> for Skip
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: SharedNode<Self>,
+    ) -> SetParent<SharedNode<Self>>
+    {
+        self.node.set_parent_at_index_0(parent)
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<SharedNode<Self>>
+    {
+        self.node.take_child_at_index_0()
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<SharedNode<Self>>
+    {
+        self.node.take_next_child_at_pos_index()
+    }
+}
+
+impl Drop for Skip
+{
+    fn drop(&mut self)
+    {
+        self.drops.set(self.drops.get().saturating_add(1));
+    }
+}
+
+/// Owns the list, and nils out all the upper-level links before the list is dropped.
+struct SkipList
+{
+    head: SharedNode<Skip>,
+}
+
+impl Drop for SkipList
+{
+    fn drop(&mut self)
+    {
+        let mut cur = Some(self.head.clone());
+        while let Some(node) = cur {
+            let mut skip = node.borrow_mut();
+            skip.node.clear_upper_levels();
+            cur = skip.node.forward(0).cloned();
+        }
+    }
+}
+
+/// A perfect skip list, where the height of the node at position `i` is one more than the
+/// trailing zeros of `i`, up to `LEVELS`.
+#[allow(clippy::expect_used)]
+fn make_skip_list(
+    len: usize,
+    drops: &Rc<Cell<usize>>,
+) -> SkipList
+{
+    let mut nexts: [Option<SharedNode<Skip>>; LEVELS] = Default::default();
+    for i in (0 .. len).rev() {
+        let height =
+            usize::try_from(i.trailing_zeros()).unwrap_or(0).saturating_add(1).min(LEVELS);
+        let mut node = SkipListNode::new(i64::try_from(i).unwrap_or(i64::MAX));
+        for (level, next) in nexts.iter().enumerate().take(height) {
+            if let Some(slot) = node.forward_mut(level) {
+                slot.clone_from(next);
+            }
+        }
+        let link = SharedNode::new(Skip { node, drops: Rc::clone(drops) });
+        for next in nexts.iter_mut().take(height) {
+            *next = Some(link.clone());
+        }
+    }
+    let [head, ..] = nexts;
+    SkipList { head: head.expect("non-empty") }
+}
+
+#[test]
+fn exercise()
+{
+    let drops = Rc::new(Cell::new(0));
+    let list = make_skip_list(TREE_SIZE, &drops);
+
+    assert_eq!(list.head.borrow().node.key(), 0);
+    assert!(list.head.borrow().node.forward(LEVELS - 1).is_some());
+
+    drop(list);
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn no_levels()
+{
+    let mut node = SkipListNode::<Box<()>, 0>::new(1);
+    assert!(node.forward_mut(0).is_none());
+    assert!(matches!(node.set_parent_at_index_0(Box::new(())), SetParent::No { .. }));
+}
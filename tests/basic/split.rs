@@ -0,0 +1,107 @@
+use super::*;
+
+
+/// A list node that only implements the sub-traits.
+struct Node
+{
+    next: Option<Box<Self>>,
+}
+
+impl ParentSlot for Node
+{
+    type Link = Box<Self>;
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        match self.next.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.next.take()
+    }
+}
+
+impl SiblingLinks for Node {}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+fn no_stack_overflow()
+{
+    let list =
+        (0 .. TREE_SIZE).fold(Node { next: None }, |acc, _| Node { next: Some(Box::new(acc)) });
+    drop(list);
+}
+
+
+/// A binary-tree node that only implements the sub-traits.
+struct Branch
+{
+    left:  Option<Box<Self>>,
+    right: Option<Box<Self>>,
+}
+
+impl ParentSlot for Branch
+{
+    type Link = Box<Self>;
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        match self.left.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.left.take()
+    }
+}
+
+impl SiblingLinks for Branch
+{
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.right.take()
+    }
+}
+
+impl Drop for Branch
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[test]
+fn siblings()
+{
+    let leaf = || Some(Box::new(Branch { left: None, right: None }));
+    let mut tree =
+        (0 .. TREE_SIZE.div_euclid(2)).fold(Branch { left: None, right: None }, |acc, _| {
+            Branch { left: leaf(), right: Some(Box::new(acc)) }
+        });
+    let stats = deep_safe_drop_stats::<Branch, Box<Branch>, Branch>(&mut tree);
+    assert_eq!(stats.nodes_dropped, TREE_SIZE);
+    drop(tree);
+}
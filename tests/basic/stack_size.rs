@@ -0,0 +1,19 @@
+use {
+    super::*,
+    core::mem::size_of,
+};
+
+
+const _: () = assert!(!would_overflow_default_stack(1000, size_of::<Box<u8>>()));
+const _: () = assert!(would_overflow_default_stack(TREE_SIZE, size_of::<Box<u8>>()));
+
+
+#[test]
+fn per_level()
+{
+    assert!(required_stack_bytes_per_level(8) > 8);
+    assert!(required_stack_bytes_per_level(16) > required_stack_bytes_per_level(8));
+    assert_eq!(required_stack_bytes_per_level(usize::MAX), usize::MAX);
+    assert!(would_overflow_default_stack(usize::MAX, 0));
+    assert!(!would_overflow_default_stack(0, usize::MAX));
+}
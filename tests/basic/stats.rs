@@ -0,0 +1,40 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+    },
+    *,
+};
+
+
+#[test]
+fn list()
+{
+    let mut root = make_list(TREE_SIZE.saturating_add(1));
+
+    let stats = deep_safe_drop_stats::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(stats.nodes_dropped, TREE_SIZE);
+    assert_eq!(stats.max_depth_reached, TREE_SIZE);
+    assert_eq!(stats.leaf_nodes, 1);
+    assert_eq!(stats.internal_nodes, TREE_SIZE.saturating_sub(1));
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let leaf = || Some(Node::default());
+    let mut root = Node::new(
+        0,
+        Some(Node::new(0, leaf(), Some(Node::new(0, None, leaf())))),
+        Some(Node::new(0, leaf(), leaf())),
+    );
+
+    let stats = deep_safe_drop_stats::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(stats.nodes_dropped, 7);
+    assert_eq!(stats.max_depth_reached, 3);
+    assert_eq!(stats.leaf_nodes, 4);
+    assert_eq!(stats.internal_nodes, 3);
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next: Option<Box<Self>>,
+    id:   usize,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+fn make_list(len: usize) -> Node
+{
+    (1 .. len).fold(Node { next: None, id: 0 }, |acc, id| Node { next: Some(Box::new(acc)), id })
+}
+
+
+#[test]
+fn move_subtree()
+{
+    let mut from = make_list(TREE_SIZE);
+    let mut to = Node { next: None, id: 0 };
+
+    let subtree = detach_subtree_at_index_0(&mut from);
+    assert!(from.next.is_none());
+    let replaced = subtree.and_then(|subtree| attach_subtree_at_index_0(&mut to, subtree));
+    assert!(replaced.is_none());
+    assert_eq!(to.next.as_ref().map(|next| next.id), Some(TREE_SIZE.saturating_sub(2)));
+
+    drop(from);
+    drop(to);
+}
+
+
+#[test]
+fn attach_replaces()
+{
+    let mut node = make_list(2);
+    let replaced = attach_subtree_at_index_0(&mut node, Box::new(make_list(3)));
+    assert_eq!(replaced.map(|replaced| replaced.id), Some(0));
+    assert_eq!(node.next.as_ref().map(|next| next.id), Some(2));
+}
+
+
+#[derive(DeepSafeDrop)]
+struct Ternary
+{
+    #[child(index = 0)]
+    first:  Option<Box<Self>>,
+    #[child(index = 1)]
+    second: Option<Box<Self>>,
+    #[child(index = 2)]
+    third:  Option<Box<Self>>,
+    id:     usize,
+}
+
+impl Drop for Ternary
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+fn make_ternary() -> Ternary
+{
+    let leaf = |id| Some(Box::new(Ternary { first: None, second: None, third: None, id }));
+    Ternary { first: leaf(1), second: leaf(2), third: leaf(3), id: 0 }
+}
+
+
+#[test]
+fn by_index_default()
+{
+    let mut node = make_ternary();
+    let third = detach_subtree_at_index(&mut node, 2);
+    assert_eq!(third.map(|third| third.id), Some(3));
+    // The default takes and drops the children before.
+    assert!(node.second.is_none());
+    assert!(node.first.is_some());
+
+    let mut other = make_ternary();
+    assert_eq!(detach_subtree_at_index(&mut other, 0).map(|first| first.id), Some(1));
+    assert!(detach_subtree_at_index(&mut other, 3).is_none());
+}
+
+
+#[test]
+fn by_index_random_access()
+{
+    use deep_safe_drop::helpers::MultiLink;
+
+    let mut node = MultiLink::<Box<u8>, 3>::new();
+    *node.links_mut() = [Some(Box::new(1)), Some(Box::new(2)), Some(Box::new(3))];
+
+    assert_eq!(detach_subtree_at_index(&mut node, 2).as_deref(), Some(&3));
+    // The override does not take the others.
+    assert_eq!(node.links()[.. 2], [Some(Box::new(1)), Some(Box::new(2))]);
+    assert!(detach_subtree_at_index(&mut node, 3).is_none());
+}
@@ -0,0 +1,56 @@
+use super::{
+    node::Node,
+    *,
+};
+
+
+fn boxed(
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+    addresses: &mut Vec<usize>,
+) -> Box<Node>
+{
+    let node = Box::new(Node { id: 0, left, right });
+    addresses.push(DropTracer::address_of(&*node));
+    node
+}
+
+
+#[test]
+fn list()
+{
+    let mut built = Vec::new();
+    let mut root = Node::default();
+    for _ in 0 .. TREE_SIZE {
+        root.left = Some(boxed(root.left.take(), None, &mut built));
+    }
+
+    let mut tracer = DropTracer::new();
+    deep_safe_drop_traced::<Node, Box<Node>, Node>(&mut root, &mut tracer);
+    // The deepest, i.e. first built, is dropped first.
+    tracer.assert_matches_post_order(&built);
+}
+
+
+#[test]
+fn binary_tree()
+{
+    let mut built = Vec::new();
+    let a = boxed(None, None, &mut built);
+    let b = boxed(None, None, &mut built);
+    let c = boxed(Some(a), Some(b), &mut built);
+    let d = boxed(None, None, &mut built);
+    let mut root = Node { id: 0, left: Some(c), right: Some(d) };
+
+    let mut tracer = DropTracer::new();
+    deep_safe_drop_traced::<Node, Box<Node>, Node>(&mut root, &mut tracer);
+    assert_eq!(tracer.sequence(), built);
+}
+
+
+#[test]
+fn is_send()
+{
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send(&DropTracer::new());
+}
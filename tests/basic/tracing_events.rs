@@ -0,0 +1,152 @@
+use {
+    super::{
+        node::Node,
+        *,
+    },
+    alloc::{
+        string::String,
+        sync::Arc,
+    },
+    core::fmt::Debug,
+    std::sync::Mutex,
+    tracing::{
+        Event,
+        Id,
+        Subscriber,
+        field::{
+            Field,
+            Visit,
+        },
+        span::Attributes,
+    },
+    tracing_subscriber::{
+        Layer,
+        Registry,
+        layer::{
+            Context,
+            SubscriberExt as _,
+        },
+    },
+};
+
+
+#[derive(Default)]
+struct Recorded
+{
+    spans:  Vec<String>,
+    /// The `action` and `node_ptr` fields of each event.
+    events: Vec<(String, String)>,
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Recorded>>);
+
+#[derive(Default)]
+struct Fields
+{
+    action:   String,
+    node_ptr: String,
+}
+
+impl Visit for Fields
+{
+    fn record_str(
+        &mut self,
+        field: &Field,
+        value: &str,
+    )
+    {
+        if field.name() == "action" {
+            self.action = value.into();
+        }
+    }
+
+    fn record_debug(
+        &mut self,
+        field: &Field,
+        value: &dyn Debug,
+    )
+    {
+        if field.name() == "node_ptr" {
+            self.node_ptr = format!("{value:?}");
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Capture
+{
+    fn on_new_span(
+        &self,
+        attrs: &Attributes<'_>,
+        _id: &Id,
+        _ctx: Context<'_, S>,
+    )
+    {
+        if let Ok(mut recorded) = self.0.lock() {
+            recorded.spans.push(attrs.metadata().name().into());
+        }
+    }
+
+    fn on_event(
+        &self,
+        event: &Event<'_>,
+        _ctx: Context<'_, S>,
+    )
+    {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        if let Ok(mut recorded) = self.0.lock() {
+            recorded.events.push((fields.action, fields.node_ptr));
+        }
+    }
+}
+
+
+const SIZE: usize = 100;
+
+/// Has ids in level-order, from 1 to `SIZE`.
+fn make_tree(id: usize) -> Node
+{
+    let child = |offset| {
+        let child_id = id.saturating_mul(2).saturating_add(offset);
+        (child_id <= SIZE).then(|| Box::new(make_tree(child_id)))
+    };
+    Node { id, left: child(0), right: child(1) }
+}
+
+/// Reference implementation, which uses the call-stack.
+#[allow(clippy::pointer_format)] // The addresses are what identify the nodes.
+fn post_order_addresses(
+    node: &Node,
+    out: &mut Vec<String>,
+)
+{
+    for child in [&node.left, &node.right].into_iter().flatten() {
+        post_order_addresses(child, out);
+        let ptr: *const Node = &raw const **child;
+        out.push(format!("{ptr:p}"));
+    }
+}
+
+
+#[test]
+#[allow(clippy::expect_used)]
+fn post_order_events()
+{
+    let tree = make_tree(1);
+    let mut expected = Vec::new();
+    post_order_addresses(&tree, &mut expected);
+
+    let capture = Capture::default();
+    let subscriber = Registry::default().with(capture.clone());
+    tracing::subscriber::with_default(subscriber, || drop(tree));
+
+    let recorded = capture.0.lock().map(|recorded| {
+        let leaves = recorded.events.iter().filter(|(action, _)| action == "leaf");
+        (recorded.spans.clone(), leaves.map(|(_, node_ptr)| node_ptr.clone()).collect::<Vec<_>>())
+    });
+    let (spans, leaves) = recorded.expect("not poisoned");
+    assert_eq!(spans, ["deep_safe_drop"]);
+    assert_eq!(expected.len(), SIZE - 1);
+    assert_eq!(leaves, expected);
+}
@@ -0,0 +1,75 @@
+use {
+    super::*,
+    deep_safe_drop::helpers::TrieNode,
+};
+
+
+struct TrieBox<const ALPHA: usize>(Box<TrieNode<Self, ALPHA>>);
+
+impl<const ALPHA: usize> Link<TrieNode<Self, ALPHA>> for TrieBox<ALPHA>
+{
+    fn get_mut(&mut self) -> &mut TrieNode<Self, ALPHA>
+    {
+        &mut self.0
+    }
+}
+
+impl<const ALPHA: usize> Drop for TrieBox<ALPHA>
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop::<TrieNode<Self, ALPHA>, Self, TrieNode<Self, ALPHA>>(&mut *self.0);
+    }
+}
+
+#[allow(clippy::expect_used)]
+fn insert<const ALPHA: usize>(
+    root: &mut TrieNode<TrieBox<ALPHA>, ALPHA>,
+    key: impl IntoIterator<Item = usize>,
+)
+{
+    let mut node = root;
+    for symbol in key {
+        let slot = node.children_mut().get_mut(symbol).expect("symbol in alphabet");
+        node = &mut slot.get_or_insert_with(|| TrieBox(Box::default())).0;
+    }
+    node.set_terminal(true);
+}
+
+/// Deterministic pseudo-random symbols, via xorshift.
+fn symbols(
+    seed: u64,
+    alpha: usize,
+) -> impl Iterator<Item = usize>
+{
+    let mut state = seed.max(1);
+    core::iter::repeat_with(move || {
+        state ^= state << 13_u8;
+        state ^= state >> 7_u8;
+        state ^= state << 17_u8;
+        usize::try_from(state).unwrap_or_default().rem_euclid(alpha)
+    })
+}
+
+
+/// A 256-link array per node is large, so the amount of keys is limited.
+#[test]
+fn byte_trie()
+{
+    let mut trie = TrieNode::<TrieBox<256>, 256>::default();
+    for seed in 1 ..= 200 {
+        insert(&mut trie, symbols(seed, 256).take(100));
+    }
+    assert!(trie.children().iter().any(Option::is_some));
+    drop(trie);
+}
+
+
+#[test]
+fn deep()
+{
+    let mut trie = TrieNode::<TrieBox<4>, 4>::default();
+    insert(&mut trie, symbols(1, 4).take(TREE_SIZE));
+    insert(&mut trie, symbols(2, 4).take(TREE_SIZE));
+    drop(trie);
+}
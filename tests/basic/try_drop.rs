@@ -0,0 +1,87 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::Cell,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    next:     Option<Box<Self>>,
+    id:       usize,
+    attempts: Rc<Cell<usize>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+        self.attempts.set(self.attempts.get().saturating_add(1));
+        assert!(self.id.rem_euclid(3) != 0, "intentional {}", self.id);
+    }
+}
+
+
+fn make_list(
+    ids: impl DoubleEndedIterator<Item = usize>,
+    attempts: &Rc<Cell<usize>>,
+) -> Node
+{
+    let node = |next, id| Node { next, id, attempts: Rc::clone(attempts) };
+    // The root itself does not panic.
+    node(ids.rev().fold(None, |next, id| Some(Box::new(node(next, id)))), 1)
+}
+
+
+#[test]
+fn collects_panics()
+{
+    let len = 3000;
+    let attempts = Rc::new(Cell::new(0));
+    let mut root = make_list(1 ..= len, &attempts);
+
+    let result = try_deep_safe_drop::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(attempts.get(), len);
+    let errors = result.err().unwrap_or_default();
+    assert_eq!(errors.len(), len.div_euclid(3));
+    // The depth of each node is its ID, and the deepest is dropped first.
+    let depths = errors.iter().map(|error| {
+        if let DropError::NodePanickedAt { depth, .. } = error { *depth } else { 0 }
+    });
+    assert!(depths.eq((1 ..= len.div_euclid(3)).rev().map(|i| i.saturating_mul(3))));
+}
+
+
+#[test]
+fn error_messages()
+{
+    let attempts = Rc::new(Cell::new(0));
+    let mut root = make_list(1 ..= 3, &attempts);
+
+    let errors = try_deep_safe_drop::<_, Box<Node>, _>(&mut root).err().unwrap_or_default();
+
+    let msgs: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(msgs, ["deep_safe_drop: dropping of node at depth 3 panicked: intentional 3"]);
+    let source = errors.first().and_then(|error| core::error::Error::source(error));
+    assert!(source.is_none());
+}
+
+
+#[test]
+fn ok_without_panics()
+{
+    let attempts = Rc::new(Cell::new(0));
+    let mut root = make_list(core::iter::repeat_n(1, TREE_SIZE), &attempts);
+
+    let result = try_deep_safe_drop::<_, Box<Node>, _>(&mut root);
+
+    assert_eq!(
+        result.map(|stats| stats.nodes_dropped).map_err(|errors| errors.len()),
+        Ok(TREE_SIZE)
+    );
+    assert_eq!(attempts.get(), TREE_SIZE);
+}
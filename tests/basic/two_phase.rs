@@ -0,0 +1,67 @@
+use {
+    super::{
+        node::RecordedNode,
+        *,
+    },
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+/// A root with a long branch of `len` nodes, where every fifth also has a leaf at its right.
+fn make_tree(
+    len: usize,
+    dropped: &Rc<RefCell<Vec<usize>>>,
+) -> RecordedNode
+{
+    let node = |id, left, right| RecordedNode { left, right, id, dropped: Rc::clone(dropped) };
+    let mut root = node(0, None, None);
+    for id in (1 .. len).rev() {
+        root = if id.rem_euclid(5) == 0 {
+            node(id, Some(Box::new(root)), Some(Box::new(node(id, None, None))))
+        }
+        else {
+            node(id, Some(Box::new(root)), None)
+        };
+    }
+    node(len, Some(Box::new(root)), None)
+}
+
+
+#[test]
+fn same_as_deep_safe_drop()
+{
+    let len = 100_000;
+    let combined = Rc::new(RefCell::new(Vec::new()));
+    let mut combined_root = make_tree(len, &combined);
+    deep_safe_drop::<_, Box<RecordedNode>, _>(&mut combined_root);
+
+    let separated = Rc::new(RefCell::new(Vec::new()));
+    let mut separated_root = make_tree(len, &separated);
+    let links = phase1_extract_all::<_, Box<RecordedNode>, _>(&mut separated_root);
+    // Between the phases, the nodes are all leaves, and none are dropped yet.
+    assert!(links.iter().all(|node| node.left.is_none() && node.right.is_none()));
+    assert!(separated.borrow().is_empty());
+    let addresses: Vec<usize> =
+        links.iter().map(|node| DropTracer::address_of(&**node)).collect();
+    assert_eq!(addresses.len(), len.saturating_add(len.saturating_sub(1).div_euclid(5)));
+    phase2_drop_vec(links);
+
+    assert_eq!(*separated.borrow(), *combined.borrow());
+    assert_eq!(separated.borrow().len(), addresses.len());
+}
+
+
+#[test]
+fn empty()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let mut leaf =
+        RecordedNode { left: None, right: None, id: 0, dropped: Rc::clone(&dropped) };
+
+    let links = phase1_extract_all::<_, Box<RecordedNode>, _>(&mut leaf);
+
+    assert!(links.is_empty());
+    phase2_drop_vec(links);
+    assert!(dropped.borrow().is_empty());
+}
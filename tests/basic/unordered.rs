@@ -0,0 +1,90 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::cell::RefCell,
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:    Option<Box<Self>>,
+    #[child(index = 1)]
+    right:   Option<Box<Self>>,
+    id:      usize,
+    dropped: Rc<RefCell<Vec<usize>>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop_unordered::<_, Box<Self>, Self>(self);
+        self.dropped.borrow_mut().push(self.id);
+    }
+}
+
+fn node(
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+    id: usize,
+    dropped: &Rc<RefCell<Vec<usize>>>,
+) -> Node
+{
+    Node { left, right, id, dropped: Rc::clone(dropped) }
+}
+
+
+#[test]
+fn long_list()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+
+    let list = (1 .. TREE_SIZE).fold(node(None, None, 0, &dropped), |acc, id| {
+        node(Some(Box::new(acc)), None, id, &dropped)
+    });
+    drop(list);
+
+    // Head first, except the root which is last.
+    let dropped = dropped.borrow();
+    assert_eq!(dropped.len(), TREE_SIZE);
+    assert!(dropped.iter().copied().eq((0 .. TREE_SIZE - 1).rev().chain([TREE_SIZE - 1])));
+}
+
+
+#[test]
+fn long_right_list()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+
+    let list = (1 .. TREE_SIZE).fold(node(None, None, 0, &dropped), |acc, id| {
+        node(None, Some(Box::new(acc)), id, &dropped)
+    });
+    drop(list);
+
+    // Only children at index 1, which are dropped in post-order.
+    assert!(dropped.borrow().iter().copied().eq(0 .. TREE_SIZE));
+}
+
+
+#[test]
+fn mixed()
+{
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let leaf = |id| Some(Box::new(node(None, None, id, &dropped)));
+
+    //       0
+    //      /
+    //     1
+    //    / \
+    //   2   3
+    //  /   / \
+    // 4   5   6
+    let three = node(leaf(5), leaf(6), 3, &dropped);
+    let two = node(leaf(4), None, 2, &dropped);
+    let one = node(Some(Box::new(two)), Some(Box::new(three)), 1, &dropped);
+    drop(node(Some(Box::new(one)), None, 0, &dropped));
+
+    assert_eq!(*dropped.borrow(), [5, 6, 3, 1, 2, 4, 0]);
+}
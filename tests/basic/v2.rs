@@ -0,0 +1,60 @@
+use {
+    super::*,
+    deep_safe_drop::v2::DeepSafeDropAssoc,
+};
+
+
+/// A binary-tree node that only implements the trait with the associated link type.
+struct Branch
+{
+    left:  Option<Box<Self>>,
+    right: Option<Box<Self>>,
+}
+
+impl DeepSafeDropAssoc for Branch
+{
+    type Link = Box<Self>;
+
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Box<Self>,
+    ) -> SetParent<Box<Self>>
+    {
+        match self.left.replace(parent) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Box<Self>>
+    {
+        self.left.take()
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Box<Self>>
+    {
+        self.right.take()
+    }
+}
+
+impl Drop for Branch
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop(self);
+    }
+}
+
+
+#[test]
+fn inferred_link_type()
+{
+    let leaf = || Some(Box::new(Branch { left: None, right: None }));
+    let mut tree =
+        (0 .. TREE_SIZE.div_euclid(2)).fold(Branch { left: None, right: None }, |acc, _| {
+            Branch { left: Some(Box::new(acc)), right: leaf() }
+        });
+    let stats = deep_safe_drop_stats(&mut tree);
+    assert_eq!(stats.nodes_dropped, TREE_SIZE);
+    drop(tree);
+}
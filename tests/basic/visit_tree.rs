@@ -0,0 +1,99 @@
+use super::{
+    node::Node,
+    *,
+};
+
+
+/// Computes the height of a tree, i.e. the amount of nodes along its longest path, by
+/// propagating the heights of the children upward.
+#[derive(Default)]
+struct Height
+{
+    /// The maximum height of the children, so far, of each node along the current path.
+    children: Vec<usize>,
+    height:   usize,
+}
+
+impl TreeVisitor<Node> for Height
+{
+    fn pre_visit(
+        &mut self,
+        _node: &Node,
+        _depth: usize,
+    )
+    {
+        self.children.push(0);
+    }
+
+    fn post_visit(
+        &mut self,
+        _node: &Node,
+        _depth: usize,
+    )
+    {
+        let height = self.children.pop().unwrap_or(0).saturating_add(1);
+        match self.children.last_mut() {
+            Some(parent) => *parent = (*parent).max(height),
+            None => self.height = height,
+        }
+    }
+}
+
+
+/// Records the order of the callbacks.
+#[derive(Default)]
+struct Recorder(Vec<(&'static str, usize, usize)>);
+
+impl TreeVisitor<Node> for Recorder
+{
+    fn pre_visit(
+        &mut self,
+        node: &Node,
+        depth: usize,
+    )
+    {
+        self.0.push(("pre", node.id, depth));
+    }
+
+    fn post_visit(
+        &mut self,
+        node: &Node,
+        depth: usize,
+    )
+    {
+        self.0.push(("post", node.id, depth));
+    }
+}
+
+
+#[test]
+fn callbacks_order()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let tree = Box::new(Node::new(0, Some(Node::new(1, leaf(3), None)), leaf(2)));
+    let mut recorder = Recorder::default();
+    visit_tree::<_, Node, _>(&tree, &mut recorder);
+    assert_eq!(recorder.0, [
+        ("pre", 0, 0),
+        ("pre", 1, 1),
+        ("pre", 3, 2),
+        ("post", 3, 2),
+        ("post", 1, 1),
+        ("pre", 2, 1),
+        ("post", 2, 1),
+        ("post", 0, 0),
+    ]);
+}
+
+
+#[test]
+fn height_of_deep()
+{
+    // A long list, with a short branch at each node, which does not affect the height.
+    let tree = Box::new((1 .. TREE_SIZE).fold(Node::new(0, None, None), |acc, id| {
+        Node::new(id, Some(acc), Some(Node::new(id, None, None)))
+    }));
+    let mut height = Height::default();
+    visit_tree::<_, Node, _>(&tree, &mut height);
+    assert_eq!(height.height, TREE_SIZE);
+}
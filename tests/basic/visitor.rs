@@ -0,0 +1,126 @@
+use super::{
+    node::{
+        Node,
+        make_list,
+    },
+    *,
+};
+
+
+#[test]
+fn post_order()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let mut tree = Node::new(
+        0,
+        Some(Node::new(1, leaf(3), Some(Node::new(4, leaf(7), None)))),
+        Some(Node::new(2, leaf(5), leaf(6))),
+    );
+
+    let mut order = Vec::new();
+    deep_safe_drop_with::<_, Box<Node>, _, _>(&mut tree, |node: &mut Node| order.push(node.id));
+
+    assert_eq!(order, [3, 7, 4, 1, 5, 6, 2]);
+    assert!(tree.left.is_none() && tree.right.is_none());
+}
+
+
+#[test]
+fn every_node()
+{
+    let mut list = make_list(TREE_SIZE);
+
+    let mut count: usize = 0;
+    deep_safe_drop_with::<_, Box<Node>, _, _>(&mut list, |_: &mut Node| {
+        count = count.saturating_add(1);
+    });
+
+    assert_eq!(count, TREE_SIZE.saturating_sub(1));
+}
+
+
+#[test]
+fn pre_hook()
+{
+    let leaf = |id| Some(Node::new(id, None, None));
+    let mut tree = Node::new(
+        0,
+        Some(Node::new(1, leaf(3), Some(Node::new(4, leaf(7), None)))),
+        Some(Node::new(2, leaf(5), leaf(6))),
+    );
+
+    let mut order = Vec::new();
+    deep_safe_drop_with_pre_hook::<_, Box<Node>, _, _>(&mut tree, |node: &mut Node| {
+        order.push((node.id, node.left.is_some(), node.right.is_some()));
+    });
+
+    assert_eq!(order, [
+        (1, true, true),
+        (3, false, false),
+        (4, true, false),
+        (7, false, false),
+        (2, true, true),
+        (5, false, false),
+        (6, false, false),
+    ]);
+    assert!(tree.left.is_none() && tree.right.is_none());
+}
+
+
+#[test]
+fn pre_hook_every_node()
+{
+    let mut list = make_list(TREE_SIZE);
+
+    let mut ids = Vec::with_capacity(TREE_SIZE);
+    deep_safe_drop_with_pre_hook::<_, Box<Node>, _, _>(&mut list, |node: &mut Node| {
+        ids.push(node.id);
+    });
+
+    assert!(ids.iter().rev().copied().eq(0 .. TREE_SIZE.saturating_sub(1)));
+}
+
+
+#[test]
+fn depths()
+{
+    fn make_balanced(depth: usize) -> Node
+    {
+        let sub = || (depth > 1).then(|| make_balanced(depth.saturating_sub(1)));
+        Node::new(depth, sub(), sub())
+    }
+
+    const LEVELS: usize = 11;
+    let mut tree = make_balanced(LEVELS);
+
+    let mut visited = 0_usize;
+    deep_safe_drop_with_depth::<_, Box<Node>, _, _>(&mut tree, |node: &mut Node, depth| {
+        // Each `id` is the height, which is the complement of the depth.
+        assert_eq!(node.id.saturating_add(depth), LEVELS);
+        visited = visited.saturating_add(1);
+    });
+
+    assert_eq!(visited, (1 << LEVELS) - 2);
+}
+
+
+#[test]
+fn context()
+{
+    use core::cell::RefCell;
+
+    let leaf = |id| Some(Node::new(id, None, None));
+    let mut tree = Node::new(
+        0,
+        Some(Node::new(1, leaf(3), Some(Node::new(4, leaf(7), None)))),
+        Some(Node::new(2, leaf(5), leaf(6))),
+    );
+
+    let ids = RefCell::new(Vec::<u64>::new());
+    deep_safe_drop_with_context::<_, Box<Node>, _, _, _>(&mut tree, &ids, |node: &Node, ids| {
+        ids.borrow_mut().push(u64::try_from(node.id).unwrap_or(u64::MAX));
+    });
+
+    assert_eq!(ids.into_inner(), [3, 7, 4, 1, 5, 6, 2]);
+    assert!(tree.left.is_none() && tree.right.is_none());
+}
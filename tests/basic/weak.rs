@@ -0,0 +1,91 @@
+use {
+    super::*,
+    alloc::rc::Rc,
+    core::{
+        borrow::Borrow as _,
+        cell::{
+            Cell,
+            RefCell,
+        },
+    },
+};
+
+
+#[derive(DeepSafeDrop)]
+struct Node
+{
+    #[child(index = 0)]
+    left:   Option<RcLink<Self>>,
+    #[child(index = 1)]
+    right:  Option<RcLink<Self>>,
+    parent: RefCell<Option<WeakLink<Self>>>,
+    drops:  Rc<Cell<usize>>,
+}
+
+impl Drop for Node
+{
+    fn drop(&mut self)
+    {
+        self.drops.set(self.drops.get().saturating_add(1));
+    }
+}
+
+fn node(
+    drops: &Rc<Cell<usize>>,
+    left: Option<RcLink<Node>>,
+    right: Option<RcLink<Node>>,
+) -> RcLink<Node>
+{
+    let link =
+        RcLink::new(Node { left, right, parent: RefCell::new(None), drops: Rc::clone(drops) });
+    let this: &Node = link.borrow();
+    for child in this.left.iter().chain(&this.right) {
+        let child: &Node = child.borrow();
+        drop(child.parent.replace(Some(link.downgrade())));
+    }
+    link
+}
+
+
+#[test]
+fn back_references()
+{
+    let drops = Rc::default();
+    let leaf = node(&drops, None, None);
+    let root = node(&drops, Some(leaf), None);
+
+    let parent = {
+        let this: &Node = root.borrow();
+        this.left.as_ref().map(|child| {
+            let child: &Node = child.borrow();
+            child.parent.borrow().clone()
+        })
+    };
+    let parent = parent.flatten();
+    assert!(parent.as_ref().and_then(WeakLink::upgrade).is_some());
+
+    drop(root);
+    assert_eq!(drops.get(), 2);
+    assert!(parent.as_ref().and_then(WeakLink::upgrade).is_none());
+}
+
+
+#[test]
+fn deep_list_with_back_references()
+{
+    let drops = Rc::default();
+    let head = (0 .. TREE_SIZE).fold(None, |acc, _| Some(node(&drops, acc, None)));
+    drop(head);
+    assert_eq!(drops.get(), TREE_SIZE);
+}
+
+
+#[test]
+fn deep_binary_tree_with_back_references()
+{
+    let drops = Rc::default();
+    let root = (0 .. TREE_SIZE.div_euclid(2))
+        .fold(None, |acc, _| Some(node(&drops, Some(node(&drops, None, None)), acc)));
+    drop(root);
+    assert_eq!(drops.get(), TREE_SIZE.div_euclid(2).saturating_mul(2));
+}
@@ -0,0 +1,104 @@
+use super::*;
+
+
+/// A zero-sized node type that is its own link type, which has no storage for links, and so it
+/// can only ever be a leaf.
+#[derive(Clone, Copy)]
+struct ZstNode;
+
+impl DeepSafeDrop<Self> for ZstNode
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        SetParent::No { returned_parent: parent }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        None
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        None
+    }
+}
+
+impl Link<Self> for ZstNode
+{
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+
+/// A root node type that has many children of the zero-sized type.
+struct Fan
+{
+    remaining: usize,
+}
+
+impl DeepSafeDrop<ZstNode> for Fan
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: ZstNode,
+    ) -> SetParent<ZstNode>
+    {
+        SetParent::No { returned_parent: parent }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<ZstNode>
+    {
+        None
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<ZstNode>
+    {
+        self.remaining.checked_sub(1).map(|remaining| {
+            self.remaining = remaining;
+            ZstNode
+        })
+    }
+}
+
+
+#[test]
+fn no_stack_overflow()
+{
+    let mut fan = Fan { remaining: TREE_SIZE };
+    deep_safe_drop::<Fan, ZstNode, ZstNode>(&mut fan);
+    assert_eq!(fan.remaining, 0);
+}
+
+
+#[test]
+fn every_node_is_leaf()
+{
+    let mut visited = 0_usize;
+    let mut fan = Fan { remaining: TREE_SIZE };
+    deep_safe_drop_with::<Fan, ZstNode, ZstNode, _>(&mut fan, |_| {
+        visited = visited.saturating_add(1);
+    });
+    assert_eq!(visited, TREE_SIZE);
+
+    let stats = deep_safe_drop_stats::<Fan, ZstNode, ZstNode>(&mut Fan { remaining: TREE_SIZE });
+    assert_eq!(stats.nodes_dropped, TREE_SIZE);
+    assert_eq!(stats.leaf_nodes, TREE_SIZE);
+    assert_eq!(stats.internal_nodes, 0);
+    assert_eq!(stats.max_depth_reached, 1);
+}
+
+
+#[test]
+fn set_parent_is_always_no()
+{
+    let mut node = ZstNode;
+    assert!(matches!(node.set_parent_at_index_0(ZstNode), SetParent::No { .. }));
+    assert!(node.store_child_at_index_0(ZstNode).is_some());
+    assert!(node.take_next_child_at_any_index().is_none());
+}
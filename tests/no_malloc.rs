@@ -0,0 +1,119 @@
+//! Tests that `deep_safe_drop` does not allocate nor free memory when the nodes do not, as is
+//! needed for it to be async-signal-safe.
+//!
+//! This is its own test binary, because it counts every allocation of the process via its global
+//! allocator, and so it has only a single test, so that no other tests run concurrently.  It does
+//! not call `deep_safe_drop` from an actual signal handler, because installing one requires
+//! `unsafe` code, which this crate forbids in all of its targets.
+
+#![cfg(unix)]
+// Satisfy the `clippy::tests_outside_test_module` lint.
+#![cfg(test)]
+// The dependencies of the library are also given to the tests, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+
+use {
+    core::sync::atomic::{
+        AtomicUsize,
+        Ordering::Relaxed,
+    },
+    deep_safe_drop::{
+        DeepSafeDrop,
+        Link,
+        SetParent,
+        deep_safe_drop,
+    },
+    stats_alloc::{
+        INSTRUMENTED_SYSTEM,
+        Region,
+        StatsAlloc,
+    },
+    std::alloc::System,
+};
+
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+
+const LEN: usize = 1_000_000;
+
+/// The index that is not a link.
+const NONE: usize = usize::MAX;
+
+/// Preallocated before the dropping, and never freed, like a static arena would be.
+type Arena = &'static [AtomicUsize];
+
+
+/// A link to a node of a list, by its index in the arena, whose element is the index of the next
+/// node.  This is also the node type of the traversal, like `RcLink` is, and so it is stored
+/// inline within its link and is not recorded by cycle detection.
+struct ArenaLink
+{
+    index: usize,
+    arena: Arena,
+}
+
+impl ArenaLink
+{
+    /// Replace the index of the next node, and return the previous one.
+    fn swap_next(
+        &self,
+        next: usize,
+    ) -> Option<Self>
+    {
+        let prev = self.arena.get(self.index).map_or(NONE, |node| node.swap(next, Relaxed));
+        (prev != NONE).then_some(Self { index: prev, arena: self.arena })
+    }
+}
+
+impl Link<Self> for ArenaLink
+{
+    fn get_mut(&mut self) -> &mut Self
+    {
+        self
+    }
+}
+
+impl DeepSafeDrop<Self> for ArenaLink
+{
+    fn set_parent_at_index_0(
+        &mut self,
+        parent: Self,
+    ) -> SetParent<Self>
+    {
+        match self.swap_next(parent.index) {
+            Some(child0) => SetParent::YesReplacedChild { child0 },
+            None => SetParent::Yes,
+        }
+    }
+
+    fn take_child_at_index_0(&mut self) -> Option<Self>
+    {
+        self.swap_next(NONE)
+    }
+
+    fn take_next_child_at_pos_index(&mut self) -> Option<Self>
+    {
+        None
+    }
+}
+
+
+#[test]
+fn no_malloc()
+{
+    let arena: Arena = Vec::leak(
+        (1 ..= LEN).map(|next| AtomicUsize::new(if next < LEN { next } else { NONE })).collect(),
+    );
+    let mut head = ArenaLink { index: 0, arena };
+
+    let region = Region::new(GLOBAL);
+    deep_safe_drop::<_, ArenaLink, ArenaLink>(&mut head);
+    let stats = region.change();
+
+    assert_eq!(stats.allocations, 0);
+    assert_eq!(stats.deallocations, 0);
+    assert_eq!(stats.reallocations, 0);
+    assert!(arena.iter().all(|next| next.load(Relaxed) == NONE), "must all be unlinked");
+}
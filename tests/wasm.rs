@@ -0,0 +1,52 @@
+//! Tests of running on the `wasm32` target, whose stack is small, typically 64KB to 1MB.
+//!
+//! Run with `wasm-pack test --node`.
+
+// The dependencies of the library are also given to the tests, which don't use all of them.
+#![allow(unused_crate_dependencies)]
+#![cfg(all(test, target_arch = "wasm32"))]
+
+use {
+    deep_safe_drop::{
+        SimpleDeepSafeDrop,
+        drop_boxed,
+    },
+    wasm_bindgen_test::wasm_bindgen_test,
+};
+
+
+struct List(Option<Box<Self>>);
+
+impl SimpleDeepSafeDrop for List
+{
+    type Link = Box<Self>;
+
+    fn swap_with_parent(
+        &mut self,
+        parent: Box<Self>,
+    ) -> Option<Box<Self>>
+    {
+        self.0.replace(parent)
+    }
+
+    fn take_sole_child(&mut self) -> Option<Box<Self>>
+    {
+        self.0.take()
+    }
+}
+
+impl Drop for List
+{
+    fn drop(&mut self)
+    {
+        drop_boxed(self);
+    }
+}
+
+
+#[wasm_bindgen_test]
+fn no_stack_overflow()
+{
+    let list = (0 .. 500_000).fold(List(None), |tail, _| List(Some(Box::new(tail))));
+    drop(list);
+}